@@ -1,26 +1,37 @@
 //! Main application and UI
 
 use eframe::egui;
-use primes::generate_primes;
+use primes::{
+    generate_twin_primes_up_to, segmented_sieve_cancellable_pooled, SievePool, DEFAULT_SEGMENT_SIZE,
+};
 use series::{
-    generate_catalan_up_to, generate_collatz_times_up_to, generate_fibonacci_up_to,
-    generate_happy_up_to, generate_hexagonal_up_to, generate_lucas_up_to,
-    generate_powers_of_2_up_to, generate_triangular_up_to,
+    classify, generate_amicable_up_to, generate_collatz_times_up_to, generate_emirps_up_to,
+    generate_happy_up_to, generate_harshad_up_to, generate_palindromes_up_to,
+    generate_sigma_series, generate_stern, generate_thue_morse_positions, is_happy_base,
 };
 use std::collections::HashSet;
-use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::thread::JoinHandle;
 
 use crate::config::{
-    PerVisualizationConfig, VisualizerConfig, ERROR_BOX_HEIGHT, FONT_SIZE_DEFAULT,
-    HOVER_TEXT_OFFSET_Y, MAX_NUMBER_MAX, MAX_NUMBER_MIN, SIDE_PANEL_MIN_WIDTH, UI_MARGIN,
+    PerVisualizationConfig, VisualizerConfig, CAMERA_DISTANCE_MAX, ERROR_BOX_HEIGHT,
+    FONT_SIZE_DEFAULT, HAPPY_BASE_MAX, HAPPY_BASE_MIN, HIGHLIGHT_SIZE_MAX, HIGHLIGHT_SIZE_MIN,
+    HOVER_TEXT_OFFSET_Y, MAX_NUMBER_MAX, MAX_NUMBER_MIN, MAX_POINTS_DRAWN_MAX,
+    MAX_POINTS_DRAWN_MIN, NON_HIGHLIGHT_SIZE_MAX, PERSPECTIVE_MAX, PERSPECTIVE_MIN,
+    SIDE_PANEL_MIN_WIDTH, SIGMA_K_MAX, SPIKE_DISTANCE_MAX, UI_MARGIN,
 };
-use crate::types::{SeriesType, VisualizationType};
+use crate::types::{Highlights, SeriesType, VisualizationType};
 use crate::visualizations::VizParams;
 use crate::visualizations::REGISTRY;
 
 static EMPTY_SET: LazyLock<HashSet<usize>> = LazyLock::new(HashSet::new);
 static EMPTY_VEC: LazyLock<Vec<usize>> = LazyLock::new(Vec::new);
 
+/// Segment buffers shared by every primes job, so regenerating while the
+/// max number slider moves does not allocate a fresh buffer each time
+static SIEVE_POOL: LazyLock<SievePool> = LazyLock::new(SievePool::new);
+
 static ERROR_BG_COLOR: LazyLock<egui::Color32> =
     LazyLock::new(|| egui::Color32::from_rgba_unmultiplied(80, 20, 20, 200));
 static ERROR_TEXT_COLOR: LazyLock<egui::Color32> =
@@ -34,20 +45,39 @@ fn empty_vec() -> &'static Vec<usize> {
     &EMPTY_VEC
 }
 
+/// Primes below `max_number`, or `None` if `cancel` is set before the sieve
+/// finishes.
+fn generate_primes_cancellable(max_number: usize, cancel: &AtomicBool) -> Option<Vec<usize>> {
+    segmented_sieve_cancellable_pooled(max_number, DEFAULT_SEGMENT_SIZE, cancel, None, &SIEVE_POOL)
+}
+
+/// Prime generation running on a background thread, so that dragging the
+/// max number slider does not freeze the UI.
+struct PrimesJob {
+    max_number: usize,
+    cancel: Arc<AtomicBool>,
+    handle: JoinHandle<Option<Vec<usize>>>,
+}
+
 /// Main application state for the Number Sequence Visualizer.
 pub struct NumberVisualizerApp {
     pub config: VisualizerConfig,
     pub series_type: SeriesType,
     per_viz_config: PerVisualizationConfig,
     primes: Option<(Vec<usize>, HashSet<usize>)>,
-    fibs: Option<(Vec<usize>, HashSet<usize>)>,
-    lucas: Option<(Vec<usize>, HashSet<usize>)>,
-    triangular: Option<(Vec<usize>, HashSet<usize>)>,
+    primes_job: Option<PrimesJob>,
+    // Series with a closed-form membership test (see
+    // `SeriesType::membership`) are never generated, so have no cache here.
     collatz: Option<(Vec<usize>, HashSet<usize>)>,
-    powers: Option<(Vec<usize>, HashSet<usize>)>,
-    catalan: Option<(Vec<usize>, HashSet<usize>)>,
-    hexagonal: Option<(Vec<usize>, HashSet<usize>)>,
     happy: Option<(Vec<usize>, HashSet<usize>)>,
+    twin_primes: Option<(Vec<usize>, HashSet<usize>)>,
+    harshad: Option<(Vec<usize>, HashSet<usize>)>,
+    palindromes: Option<(Vec<usize>, HashSet<usize>)>,
+    emirps: Option<(Vec<usize>, HashSet<usize>)>,
+    stern: Option<(Vec<usize>, HashSet<usize>)>,
+    thue_morse: Option<(Vec<usize>, HashSet<usize>)>,
+    sigma: Option<(Vec<usize>, HashSet<usize>)>,
+    amicable: Option<(Vec<usize>, HashSet<usize>)>,
     cached_max_number: usize,
     pub hovered_number: Option<usize>,
     pub error_message: Option<String>,
@@ -60,14 +90,17 @@ impl NumberVisualizerApp {
             series_type: SeriesType::default(),
             per_viz_config: PerVisualizationConfig::default(),
             primes: None,
-            fibs: None,
-            lucas: None,
-            triangular: None,
+            primes_job: None,
             collatz: None,
-            powers: None,
-            catalan: None,
-            hexagonal: None,
             happy: None,
+            twin_primes: None,
+            harshad: None,
+            palindromes: None,
+            emirps: None,
+            stern: None,
+            thue_morse: None,
+            sigma: None,
+            amicable: None,
             cached_max_number: 0,
             hovered_number: None,
             error_message: None,
@@ -141,6 +174,84 @@ impl NumberVisualizerApp {
         cache.as_ref().expect("BUG: series cache was not populated")
     }
 
+    /// Start generating primes below `max_number` in the background,
+    /// cancelling any generation already in flight for another max number.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_primes_job(&mut self, max_number: usize) {
+        if matches!(&self.primes_job, Some(job) if job.max_number == max_number) {
+            return;
+        }
+        self.cancel_primes_job();
+
+        let cancel = Arc::new(AtomicBool::new(false));
+        let thread_cancel = Arc::clone(&cancel);
+        let handle =
+            std::thread::spawn(move || generate_primes_cancellable(max_number, &thread_cancel));
+        self.primes_job = Some(PrimesJob {
+            max_number,
+            cancel,
+            handle,
+        });
+    }
+
+    /// Browsers have no threads, so the web build generates in place.
+    #[cfg(target_arch = "wasm32")]
+    fn start_primes_job(&mut self, max_number: usize) {
+        let primes = generate_primes_cancellable(max_number, &AtomicBool::new(false));
+        self.store_primes(primes);
+    }
+
+    fn cancel_primes_job(&mut self) {
+        if let Some(job) = self.primes_job.take() {
+            // The detached thread stops at its next segment
+            job.cancel.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Collect the background primes once the thread has finished. Called
+    /// every frame; does nothing while generation is still running.
+    fn poll_primes_job(&mut self) {
+        if !self
+            .primes_job
+            .as_ref()
+            .is_some_and(|job| job.handle.is_finished())
+        {
+            return;
+        }
+        let job = self.primes_job.take().expect("BUG: primes job vanished");
+        let primes = job.handle.join().unwrap_or(None);
+        self.store_primes(primes);
+    }
+
+    fn store_primes(&mut self, primes: Option<Vec<usize>>) {
+        match primes {
+            Some(primes_vec) => {
+                let set: HashSet<usize> = primes_vec.iter().copied().collect();
+                self.primes = Some((primes_vec, set));
+            }
+            None => {
+                self.set_error("Failed to generate primes".to_string());
+                self.primes = Some((Vec::new(), HashSet::new()));
+            }
+        }
+    }
+
+    pub fn is_generating_primes(&self) -> bool {
+        self.primes_job.is_some()
+    }
+
+    /// Switch to the series' default colors, unless the user has chosen
+    /// their own.
+    fn apply_series_colors(&mut self) {
+        if self.config.colors_customized {
+            return;
+        }
+        let (highlight, non_highlight, background) = self.series_type.default_colors();
+        self.config.highlight_color = highlight;
+        self.config.non_highlight_color = non_highlight;
+        self.config.background_color = background;
+    }
+
     fn set_error(&mut self, message: String) {
         self.error_message = Some(message);
     }
@@ -152,14 +263,23 @@ impl NumberVisualizerApp {
     fn series_is_loaded(&self) -> bool {
         match self.series_type {
             SeriesType::Primes => self.primes.is_some(),
-            SeriesType::Fibonacci => self.fibs.is_some(),
-            SeriesType::Lucas => self.lucas.is_some(),
-            SeriesType::Triangular => self.triangular.is_some(),
+            SeriesType::Fibonacci
+            | SeriesType::Lucas
+            | SeriesType::Triangular
+            | SeriesType::PowersOf2
+            | SeriesType::Catalan
+            | SeriesType::Hexagonal
+            | SeriesType::CenteredHexagonal => true,
             SeriesType::Collatz => self.collatz.is_some(),
-            SeriesType::PowersOf2 => self.powers.is_some(),
-            SeriesType::Catalan => self.catalan.is_some(),
-            SeriesType::Hexagonal => self.hexagonal.is_some(),
             SeriesType::Happy => self.happy.is_some(),
+            SeriesType::TwinPrimes => self.twin_primes.is_some(),
+            SeriesType::Harshad => self.harshad.is_some(),
+            SeriesType::Palindromes => self.palindromes.is_some(),
+            SeriesType::Emirps => self.emirps.is_some(),
+            SeriesType::Stern => self.stern.is_some(),
+            SeriesType::ThueMorse => self.thue_morse.is_some(),
+            SeriesType::DivisorSigma => self.sigma.is_some(),
+            SeriesType::Amicable => self.amicable.is_some(),
         }
     }
 
@@ -172,15 +292,18 @@ impl NumberVisualizerApp {
         }
 
         if self.config.max_number != self.cached_max_number {
+            self.cancel_primes_job();
             self.primes = None;
-            self.fibs = None;
-            self.lucas = None;
-            self.triangular = None;
             self.collatz = None;
-            self.powers = None;
-            self.catalan = None;
-            self.hexagonal = None;
             self.happy = None;
+            self.twin_primes = None;
+            self.harshad = None;
+            self.palindromes = None;
+            self.emirps = None;
+            self.stern = None;
+            self.thue_morse = None;
+            self.sigma = None;
+            self.amicable = None;
             self.cached_max_number = self.config.max_number;
             self.per_viz_config.invalidate_all_positions();
         }
@@ -190,31 +313,18 @@ impl NumberVisualizerApp {
         let max_number = self.config.max_number;
         match self.series_type {
             SeriesType::Primes => {
-                let primes = generate_primes(max_number, false, None, None, None);
-                match primes {
-                    Ok(primes_vec) => {
-                        let set: HashSet<usize> = primes_vec.iter().copied().collect();
-                        self.primes = Some((primes_vec, set));
-                    }
-                    Err(err) => {
-                        self.set_error(format!("Failed to generate primes: {}", err));
-                        self.primes = Some((Vec::new(), HashSet::new()));
-                    }
+                // Kept across series switches; only a new max number drops it
+                if self.primes.is_none() {
+                    self.start_primes_job(max_number);
                 }
             }
-            SeriesType::Fibonacci => {
-                Self::get_or_compute_series(&mut self.fibs, max_number, generate_fibonacci_up_to);
-            }
-            SeriesType::Lucas => {
-                Self::get_or_compute_series(&mut self.lucas, max_number, generate_lucas_up_to);
-            }
-            SeriesType::Triangular => {
-                Self::get_or_compute_series(
-                    &mut self.triangular,
-                    max_number,
-                    generate_triangular_up_to,
-                );
-            }
+            SeriesType::Fibonacci
+            | SeriesType::Lucas
+            | SeriesType::Triangular
+            | SeriesType::PowersOf2
+            | SeriesType::Catalan
+            | SeriesType::Hexagonal
+            | SeriesType::CenteredHexagonal => {}
             SeriesType::Collatz => {
                 Self::get_or_compute_series(
                     &mut self.collatz,
@@ -222,57 +332,119 @@ impl NumberVisualizerApp {
                     generate_collatz_times_up_to,
                 );
             }
-            SeriesType::PowersOf2 => {
+            SeriesType::Happy => {
+                // Base 10 has a precomputed table; other bases test each n
+                let base = self.config.happy_base;
+                Self::get_or_compute_series(&mut self.happy, max_number, |max| {
+                    if base == 10 {
+                        generate_happy_up_to(max)
+                    } else {
+                        (1..=max).filter(|&n| is_happy_base(n, base)).collect()
+                    }
+                });
+            }
+            SeriesType::TwinPrimes => match generate_twin_primes_up_to(max_number) {
+                Ok(twins_vec) => {
+                    let set: HashSet<usize> = twins_vec.iter().copied().collect();
+                    self.twin_primes = Some((twins_vec, set));
+                }
+                Err(err) => {
+                    self.set_error(format!("Failed to generate twin primes: {}", err));
+                    self.twin_primes = Some((Vec::new(), HashSet::new()));
+                }
+            },
+            SeriesType::Harshad => {
+                Self::get_or_compute_series(&mut self.harshad, max_number, generate_harshad_up_to);
+            }
+            SeriesType::Palindromes => {
                 Self::get_or_compute_series(
-                    &mut self.powers,
+                    &mut self.palindromes,
                     max_number,
-                    generate_powers_of_2_up_to,
+                    generate_palindromes_up_to,
                 );
             }
-            SeriesType::Catalan => {
-                Self::get_or_compute_series(&mut self.catalan, max_number, generate_catalan_up_to);
+            SeriesType::Emirps => {
+                Self::get_or_compute_series(&mut self.emirps, max_number, generate_emirps_up_to);
+            }
+            SeriesType::Stern => {
+                // Like Collatz, highlight the values taken by terms 0..=max_number
+                Self::get_or_compute_series(&mut self.stern, max_number, |max| {
+                    generate_stern(max.saturating_add(1))
+                });
             }
-            SeriesType::Hexagonal => {
+            SeriesType::ThueMorse => {
                 Self::get_or_compute_series(
-                    &mut self.hexagonal,
+                    &mut self.thue_morse,
                     max_number,
-                    generate_hexagonal_up_to,
+                    generate_thue_morse_positions,
                 );
             }
-            SeriesType::Happy => {
-                Self::get_or_compute_series(&mut self.happy, max_number, generate_happy_up_to);
+            SeriesType::DivisorSigma => {
+                // Like Collatz, highlight the values σₖ(1..=max_number)
+                let k = self.config.sigma_k;
+                Self::get_or_compute_series(&mut self.sigma, max_number, |max| {
+                    generate_sigma_series(max, k)
+                });
+            }
+            SeriesType::Amicable => {
+                Self::get_or_compute_series(
+                    &mut self.amicable,
+                    max_number,
+                    generate_amicable_up_to,
+                );
             }
         }
 
         if self.config.visualization.is_primes_only() && self.series_type != SeriesType::Primes {
             self.config.visualization = VisualizationType::UlamSpiral;
         }
+        if self.config.visualization == VisualizationType::CollatzGraph
+            && self.series_type != SeriesType::Collatz
+        {
+            self.config.visualization = VisualizationType::UlamSpiral;
+        }
+    }
+
+    /// Clamp the config into range, then load the series.
+    ///
+    /// Loading clears `error_message`, so anything `validate` adjusted is
+    /// reported afterwards and stays up until the next reload.
+    pub fn validate_and_load(&mut self) {
+        let adjusted = self.config.validate().err();
+        self.ensure_series_loaded();
+        if let Some(messages) = adjusted {
+            self.set_error(messages.join("; "));
+        }
     }
 
     pub fn contains(&self, n: usize) -> bool {
         self.highlights().contains(&n)
     }
 
-    pub fn highlights(&self) -> &HashSet<usize> {
-        match self.series_type {
-            SeriesType::Primes => self.primes.as_ref().map(|(_, s)| s).unwrap_or(empty_set()),
-            SeriesType::Fibonacci => self.fibs.as_ref().map(|(_, s)| s).unwrap_or(empty_set()),
-            SeriesType::Lucas => self.lucas.as_ref().map(|(_, s)| s).unwrap_or(empty_set()),
-            SeriesType::Triangular => self
-                .triangular
-                .as_ref()
-                .map(|(_, s)| s)
-                .unwrap_or(empty_set()),
-            SeriesType::Collatz => self.collatz.as_ref().map(|(_, s)| s).unwrap_or(empty_set()),
-            SeriesType::PowersOf2 => self.powers.as_ref().map(|(_, s)| s).unwrap_or(empty_set()),
-            SeriesType::Catalan => self.catalan.as_ref().map(|(_, s)| s).unwrap_or(empty_set()),
-            SeriesType::Hexagonal => self
-                .hexagonal
-                .as_ref()
-                .map(|(_, s)| s)
-                .unwrap_or(empty_set()),
-            SeriesType::Happy => self.happy.as_ref().map(|(_, s)| s).unwrap_or(empty_set()),
+    pub fn highlights(&self) -> Highlights<'_> {
+        if let Some(sequence) = self.series_type.membership() {
+            return Highlights::Membership {
+                sequence,
+                max_number: self.config.max_number,
+            };
         }
+
+        let cache = match self.series_type {
+            SeriesType::Primes => &self.primes,
+            SeriesType::Collatz => &self.collatz,
+            SeriesType::Happy => &self.happy,
+            SeriesType::TwinPrimes => &self.twin_primes,
+            SeriesType::Harshad => &self.harshad,
+            SeriesType::Palindromes => &self.palindromes,
+            SeriesType::Emirps => &self.emirps,
+            SeriesType::Stern => &self.stern,
+            SeriesType::ThueMorse => &self.thue_morse,
+            SeriesType::DivisorSigma => &self.sigma,
+            SeriesType::Amicable => &self.amicable,
+            // Closed-form series returned above
+            _ => return Highlights::Set(empty_set()),
+        };
+        Highlights::Set(cache.as_ref().map(|(_, s)| s).unwrap_or(empty_set()))
     }
 
     pub fn primes_set(&self) -> &HashSet<usize> {
@@ -293,7 +465,45 @@ impl NumberVisualizerApp {
             SeriesType::PowersOf2 => "power of 2",
             SeriesType::Catalan => "catalan",
             SeriesType::Hexagonal => "hexagonal",
+            SeriesType::CenteredHexagonal => "centered hexagonal",
             SeriesType::Happy => "happy",
+            SeriesType::TwinPrimes => "twin prime",
+            SeriesType::Harshad => "harshad",
+            SeriesType::Palindromes => "palindrome",
+            SeriesType::Emirps => "emirp",
+            SeriesType::Stern => "stern",
+            SeriesType::ThueMorse => "thue-morse",
+            SeriesType::DivisorSigma => "divisor sum",
+            SeriesType::Amicable => "amicable",
+        }
+    }
+
+    /// Label for a hovered number. Collatz shows the number's own stopping
+    /// time, read from the cache of stopping times for starts 0..=max_number.
+    /// Any other series `n` belongs to follow in brackets.
+    pub fn hover_text(&self, n: usize) -> String {
+        let text = self.series_hover_text(n);
+        let others: Vec<&str> = classify(n)
+            .into_iter()
+            .filter(|&label| label != self.series_name())
+            .collect();
+        if others.is_empty() {
+            text
+        } else {
+            format!("{} [{}]", text, others.join(", "))
+        }
+    }
+
+    fn series_hover_text(&self, n: usize) -> String {
+        if self.series_type == SeriesType::Collatz {
+            if let Some(steps) = self.collatz.as_ref().and_then(|(times, _)| times.get(n)) {
+                return format!("{} (collatz: {} steps)", n, steps);
+            }
+        }
+        if self.contains(n) {
+            format!("{} ({})", n, self.series_name())
+        } else {
+            format!("{}", n)
         }
     }
 
@@ -317,7 +527,10 @@ impl NumberVisualizerApp {
             let params = VizParams::default()
                 .with_modulo(modulo)
                 .with_grid_size(self.config.grid_size)
-                .with_num_zeros(self.config.num_zeros);
+                .with_num_zeros(self.config.num_zeros)
+                .with_sacks_theta(self.config.sacks_theta)
+                .with_spiral_start(self.config.spiral_start)
+                .with_display_start(self.config.display_start);
 
             let needs = !viz.generate_positions(max_n, &params).is_empty();
             let hover = viz.supports_hover();
@@ -325,7 +538,7 @@ impl NumberVisualizerApp {
                 needs,
                 hover,
                 Box::new(move |max_n: usize, mod_val: usize| {
-                    let p = VizParams::default().with_modulo(mod_val);
+                    let p = params.with_modulo(mod_val);
                     REGISTRY
                         .get(viz_type)
                         .unwrap()
@@ -361,6 +574,13 @@ impl NumberVisualizerApp {
 
 impl eframe::App for NumberVisualizerApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        let previous_sacks_theta = self.config.sacks_theta;
+        let previous_spiral_start = self.config.spiral_start;
+        let previous_display_start = self.config.display_start;
+        let previous_sigma_k = self.config.sigma_k;
+        let previous_happy_base = self.config.happy_base;
+        let previous_series_type = self.series_type;
+
         egui::SidePanel::left("controls")
             .min_width(SIDE_PANEL_MIN_WIDTH)
             .show(ctx, |ui| {
@@ -381,6 +601,23 @@ impl eframe::App for NumberVisualizerApp {
                         }
                     });
 
+                if self.series_type == SeriesType::DivisorSigma {
+                    ui.add(egui::Slider::new(&mut self.config.sigma_k, 0..=SIGMA_K_MAX).text("k"))
+                        .on_hover_text("σ₀ counts divisors, σ₁ sums them, σ₂ sums their squares");
+                }
+                if self.series_type == SeriesType::Happy {
+                    ui.add(
+                        egui::Slider::new(
+                            &mut self.config.happy_base,
+                            HAPPY_BASE_MIN..=HAPPY_BASE_MAX,
+                        )
+                        .text("Base"),
+                    )
+                    .on_hover_text(
+                        "Square the digits in this base; in bases 2 and 4 every n is happy",
+                    );
+                }
+
                 ui.separator();
 
                 ui.label("Visualization:");
@@ -390,7 +627,7 @@ impl eframe::App for NumberVisualizerApp {
                         for viz_type in VisualizationType::available_for(self.series_type) {
                             ui.selectable_value(
                                 &mut self.config.visualization,
-                                *viz_type,
+                                viz_type,
                                 format!("{}", viz_type),
                             );
                         }
@@ -398,6 +635,10 @@ impl eframe::App for NumberVisualizerApp {
 
                 ui.separator();
                 ui.label(self.config.visualization.description());
+                ui.checkbox(&mut self.config.show_formula, "Show formula");
+                if self.config.show_formula {
+                    ui.label(self.series_type.formula());
+                }
 
                 ui.separator();
 
@@ -410,6 +651,12 @@ impl eframe::App for NumberVisualizerApp {
                         )
                         .text("n"),
                     );
+                    if self.is_generating_primes() {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label("Generating primes...");
+                        });
+                    }
                 });
 
                 ui.separator();
@@ -417,20 +664,57 @@ impl eframe::App for NumberVisualizerApp {
 
                 if self.config.visualization.uses_point_rendering() {
                     ui.label("Highlight size:");
-                    ui.add(egui::Slider::new(&mut self.config.highlight_size, 1..=20));
+                    ui.add(egui::Slider::new(
+                        &mut self.config.highlight_size,
+                        HIGHLIGHT_SIZE_MIN..=HIGHLIGHT_SIZE_MAX,
+                    ));
 
                     ui.label("Non-highlight size:");
                     ui.add(egui::Slider::new(
                         &mut self.config.non_highlight_size,
-                        0..=10,
+                        0..=NON_HIGHLIGHT_SIZE_MAX,
                     ));
 
                     ui.label("Spike distance:");
                     ui.add(egui::Slider::new(
                         &mut self.config.spike_distance,
-                        0.0..=10.0,
+                        0.0..=SPIKE_DISTANCE_MAX,
                     ));
 
+                    if self.config.visualization.is_3d() {
+                        ui.label("Perspective:");
+                        ui.add(egui::Slider::new(
+                            &mut self.config.perspective,
+                            PERSPECTIVE_MIN..=PERSPECTIVE_MAX,
+                        ))
+                        .on_hover_text("Larger values flatten the 3D foreshortening");
+
+                        ui.label("Camera distance:");
+                        ui.add(egui::Slider::new(
+                            &mut self.config.camera_distance,
+                            0.0..=CAMERA_DISTANCE_MAX,
+                        ))
+                        .on_hover_text("Move the camera back if shapes clip");
+                    }
+
+                    ui.label("Max points drawn:");
+                    ui.add(egui::Slider::new(
+                        &mut self.config.max_points_drawn,
+                        MAX_POINTS_DRAWN_MIN..=MAX_POINTS_DRAWN_MAX,
+                    ))
+                    .on_hover_text(
+                        "Beyond this, non-highlighted points in 2D views are subsampled",
+                    );
+
+                    if self.config.visualization.uses_display_start() {
+                        ui.label("Start at:");
+                        ui.add(egui::Slider::new(
+                            &mut self.config.display_start,
+                            1..=self.config.max_number.max(1),
+                        ))
+                        .on_hover_text("First number in the layout; cell k shows start + k");
+                    }
+
                     ui.checkbox(&mut self.config.show_numbers, "Show numbers");
 
                     if self.series_type == SeriesType::Primes {
@@ -461,13 +745,18 @@ impl eframe::App for NumberVisualizerApp {
                 ui.label("Colors");
 
                 ui.label("Highlight:");
-                ui.color_edit_button_srgba(&mut self.config.highlight_color);
+                let highlight = ui.color_edit_button_srgba(&mut self.config.highlight_color);
 
                 ui.label("Non-highlight:");
-                ui.color_edit_button_srgba(&mut self.config.non_highlight_color);
+                let non_highlight =
+                    ui.color_edit_button_srgba(&mut self.config.non_highlight_color);
 
                 ui.label("Background:");
-                ui.color_edit_button_srgba(&mut self.config.background_color);
+                let background = ui.color_edit_button_srgba(&mut self.config.background_color);
+
+                if highlight.changed() || non_highlight.changed() || background.changed() {
+                    self.config.colors_customized = true;
+                }
 
                 if let Some(viz) = REGISTRY.get(self.config.visualization) {
                     ui.separator();
@@ -481,7 +770,34 @@ impl eframe::App for NumberVisualizerApp {
                 }
             });
 
-        self.ensure_series_loaded();
+        // Cached positions are keyed by max number and modulo only, so a new
+        // spiral angle or start value has to drop them explicitly.
+        if self.config.sacks_theta != previous_sacks_theta
+            || self.config.spiral_start != previous_spiral_start
+            || self.config.display_start != previous_display_start
+        {
+            self.per_viz_config.invalidate_all_positions();
+        }
+
+        if self.series_type != previous_series_type {
+            self.apply_series_colors();
+        }
+
+        // The σₖ cache is keyed by max number only, so a new k has to drop it.
+        if self.config.sigma_k != previous_sigma_k {
+            self.sigma = None;
+        }
+        // Likewise the happy cache for a new base
+        if self.config.happy_base != previous_happy_base {
+            self.happy = None;
+        }
+
+        self.poll_primes_job();
+        self.validate_and_load();
+        if self.is_generating_primes() {
+            // Keep polling the background thread even without input
+            ctx.request_repaint();
+        }
 
         egui::CentralPanel::default().show(ctx, |ui| {
             let rect = ui.available_rect_before_wrap();
@@ -505,11 +821,7 @@ impl eframe::App for NumberVisualizerApp {
 
             if let Some(hovered) = self.hovered_number {
                 let is_highlighted = self.contains(hovered);
-                let text = if is_highlighted {
-                    format!("{} ({})", hovered, self.series_name())
-                } else {
-                    format!("{}", hovered)
-                };
+                let text = self.hover_text(hovered);
                 ui.painter().text(
                     egui::Pos2::new(rect.left() + UI_MARGIN, rect.bottom() - HOVER_TEXT_OFFSET_Y),
                     egui::Align2::LEFT_BOTTOM,
@@ -525,3 +837,313 @@ impl eframe::App for NumberVisualizerApp {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_with(series_type: SeriesType, max_number: usize) -> NumberVisualizerApp {
+        let mut app = NumberVisualizerApp::new(VisualizerConfig {
+            max_number,
+            ..Default::default()
+        });
+        app.series_type = series_type;
+        app.ensure_series_loaded();
+        app
+    }
+
+    /// Run frames' worth of polling until background primes are in.
+    fn wait_for_primes(app: &mut NumberVisualizerApp) {
+        while app.is_generating_primes() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            app.poll_primes_job();
+        }
+    }
+
+    #[test]
+    fn test_generate_primes_cancellable() {
+        let cancel = AtomicBool::new(false);
+        assert_eq!(
+            generate_primes_cancellable(30, &cancel),
+            Some(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29])
+        );
+        cancel.store(true, Ordering::Relaxed);
+        assert_eq!(generate_primes_cancellable(1_000, &cancel), None);
+    }
+
+    #[test]
+    fn test_max_change_cancels_primes_job() {
+        let mut app = app_with(SeriesType::Primes, 10_000_000);
+        let first_cancel = Arc::clone(&app.primes_job.as_ref().unwrap().cancel);
+
+        app.config.max_number = 100;
+        app.ensure_series_loaded();
+        assert!(first_cancel.load(Ordering::Relaxed));
+
+        wait_for_primes(&mut app);
+        assert_eq!(app.primes_vec().len(), 25);
+        assert!(app.error_message.is_none());
+    }
+
+    #[test]
+    fn test_validate_and_load_reports_clamped_fields() {
+        let mut app = app_with(SeriesType::Fibonacci, 1_000);
+        app.config.modulo = 500;
+        app.config.max_number = 10;
+        app.validate_and_load();
+
+        assert_eq!(app.config.max_number, MAX_NUMBER_MIN);
+        let error = app.error_message.clone().unwrap();
+        assert!(error.contains("max_number 10"), "{}", error);
+        assert!(error.contains("modulo 500"), "{}", error);
+
+        // Already in range: nothing new to report, the message stays
+        app.validate_and_load();
+        assert_eq!(app.error_message, Some(error));
+    }
+
+    #[test]
+    fn test_primes_reused_across_calls_and_series_switches() {
+        let mut app = app_with(SeriesType::Primes, 1_000);
+        wait_for_primes(&mut app);
+        let primes_ptr = app.primes_vec().as_ptr();
+        assert_eq!(app.primes_vec().len(), 168);
+
+        app.ensure_series_loaded();
+        assert!(!app.is_generating_primes());
+        assert_eq!(app.primes_vec().as_ptr(), primes_ptr);
+
+        app.series_type = SeriesType::Collatz;
+        app.ensure_series_loaded();
+        app.series_type = SeriesType::Primes;
+        app.ensure_series_loaded();
+        assert!(!app.is_generating_primes());
+        assert_eq!(app.primes_vec().as_ptr(), primes_ptr);
+
+        // A new max number does regenerate
+        app.config.max_number = 2_000;
+        app.ensure_series_loaded();
+        assert!(app.is_generating_primes());
+        wait_for_primes(&mut app);
+        assert_eq!(app.primes_vec().len(), 303);
+    }
+
+    #[test]
+    fn test_series_colors_applied_until_customized() {
+        let mut app = app_with(SeriesType::Fibonacci, 100);
+        app.apply_series_colors();
+        assert_eq!(
+            app.config.highlight_color,
+            SeriesType::Fibonacci.default_colors().0
+        );
+
+        let custom = egui::Color32::from_rgb(1, 2, 3);
+        app.config.highlight_color = custom;
+        app.config.colors_customized = true;
+        app.series_type = SeriesType::Collatz;
+        app.apply_series_colors();
+        assert_eq!(app.config.highlight_color, custom);
+    }
+
+    #[test]
+    fn test_collatz_hover_shows_own_stopping_time() {
+        let app = app_with(SeriesType::Collatz, 30);
+        assert_eq!(app.series_hover_text(27), "27 (collatz: 111 steps)");
+        assert_eq!(app.series_hover_text(1), "1 (collatz: 0 steps)");
+        // Past max_number there is no cached stopping time
+        assert_eq!(app.series_hover_text(31), "31");
+
+        let app = app_with(SeriesType::Fibonacci, 30);
+        assert_eq!(app.series_hover_text(21), "21 (fibonacci)");
+        assert_eq!(app.series_hover_text(22), "22");
+    }
+
+    #[test]
+    fn test_hover_lists_other_series() {
+        let app = app_with(SeriesType::Fibonacci, 30);
+        // The current series is not repeated in the brackets
+        assert_eq!(
+            app.hover_text(21),
+            "21 (fibonacci) [triangular, harshad, thue-morse]"
+        );
+        assert_eq!(
+            app.hover_text(22),
+            "22 [pentagonal, palindrome, thue-morse]"
+        );
+
+        let app = app_with(SeriesType::Collatz, 30);
+        assert_eq!(app.hover_text(27), "27 (collatz: 111 steps) [harshad]");
+    }
+
+    #[test]
+    fn test_twin_primes_highlights() {
+        let app = app_with(SeriesType::TwinPrimes, 20);
+        for n in [3, 5, 7, 11, 13] {
+            assert!(app.contains(n), "{} should be a twin prime", n);
+        }
+        for n in [2, 4, 9, 23] {
+            assert!(!app.contains(n), "{} should not be a twin prime", n);
+        }
+        assert!(app.error_message.is_none());
+    }
+
+    #[test]
+    fn test_membership_highlights_match_generated_sets() {
+        let max_number = 1_000;
+        for &series_type in SeriesType::ALL {
+            let Some(sequence) = series_type.membership() else {
+                continue;
+            };
+            let app = app_with(series_type, max_number);
+            let expected: HashSet<usize> =
+                sequence.generate_up_to(max_number).into_iter().collect();
+            for n in 0..=max_number + 100 {
+                assert_eq!(
+                    app.contains(n),
+                    expected.contains(&n),
+                    "{} membership of {}",
+                    series_type,
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_membership_exact_to_a_million() {
+        // Terms come from BigUint `nth`, so a saturating usize generator
+        // could not hide a false member here
+        let max_number = 1_000_000;
+        for series_type in [
+            SeriesType::Fibonacci,
+            SeriesType::Lucas,
+            SeriesType::Catalan,
+        ] {
+            let sequence = series_type.membership().unwrap();
+            let expected: HashSet<usize> = (0..)
+                .map_while(|i| sequence.nth(i)?.to_string().parse::<usize>().ok())
+                .take_while(|&term| term <= max_number)
+                .collect();
+            assert!(expected.len() > 10, "{} has too few terms", series_type);
+
+            let app = app_with(series_type, max_number);
+            for n in 0..=max_number {
+                assert_eq!(
+                    app.contains(n),
+                    expected.contains(&n),
+                    "{} membership of {}",
+                    series_type,
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_harshad_highlights() {
+        let app = app_with(SeriesType::Harshad, 100);
+        for n in [1, 10, 12, 18, 21] {
+            assert!(app.contains(n), "{} should be Harshad", n);
+        }
+        for n in [11, 19, 22] {
+            assert!(!app.contains(n), "{} should not be Harshad", n);
+        }
+    }
+
+    #[test]
+    fn test_stern_highlights() {
+        // a(0..=20) = 0, 1, 1, 2, 1, 3, 2, 3, 1, 4, 3, 5, 2, 5, 3, 4, 1, 5, 4, 7, 3
+        let app = app_with(SeriesType::Stern, 20);
+        for n in [0, 1, 2, 3, 4, 5, 7] {
+            assert!(app.contains(n), "{} should be a Stern value", n);
+        }
+        for n in [6, 8, 20] {
+            assert!(!app.contains(n), "{} should not be a Stern value", n);
+        }
+    }
+
+    #[test]
+    fn test_thue_morse_highlights() {
+        let app = app_with(SeriesType::ThueMorse, 16);
+        for n in [1, 2, 4, 7, 8, 11, 13, 14, 16] {
+            assert!(app.contains(n), "t({}) should be 1", n);
+        }
+        for n in [0, 3, 5, 6, 9, 10, 12, 15] {
+            assert!(!app.contains(n), "t({}) should be 0", n);
+        }
+    }
+
+    #[test]
+    fn test_divisor_sigma_highlights() {
+        // σ₀(1..=12) = 1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6
+        let mut app = app_with(SeriesType::DivisorSigma, 12);
+        app.config.sigma_k = 0;
+        app.sigma = None;
+        app.ensure_series_loaded();
+        for n in [1, 2, 3, 4, 6] {
+            assert!(app.contains(n), "{} should be a divisor count", n);
+        }
+        assert!(!app.contains(5));
+
+        // σ₁(1..=12) = 1, 3, 4, 7, 6, 12, 8, 15, 13, 18, 12, 28
+        app.config.sigma_k = 1;
+        app.sigma = None;
+        app.ensure_series_loaded();
+        for n in [1, 3, 4, 7, 28] {
+            assert!(app.contains(n), "{} should be a divisor sum", n);
+        }
+        assert!(!app.contains(2));
+    }
+
+    #[test]
+    fn test_happy_base_highlights() {
+        // Base 2 and base 4 are the happy bases: every n >= 1 reaches 1
+        let mut app = app_with(SeriesType::Happy, 1_000);
+        app.config.happy_base = 2;
+        app.happy = None;
+        app.ensure_series_loaded();
+        assert!((1..=1_000).all(|n| app.contains(n)));
+        assert!(!app.contains(0));
+
+        // Back in base 10, 7 is happy and 4 is not
+        app.config.happy_base = 10;
+        app.happy = None;
+        app.ensure_series_loaded();
+        assert!(app.contains(7));
+        assert!(!app.contains(4));
+    }
+
+    #[test]
+    fn test_centered_hexagonal_highlights() {
+        let app = app_with(SeriesType::CenteredHexagonal, 100);
+        for n in [1, 7, 19, 37, 61, 91] {
+            assert!(app.contains(n), "{} should be centered hexagonal", n);
+        }
+        // Plain hexagonal numbers are not centered ones
+        for n in [6, 15, 28, 45, 66, 90] {
+            assert!(!app.contains(n), "{} should not be centered hexagonal", n);
+        }
+    }
+
+    #[test]
+    fn test_amicable_highlights() {
+        let app = app_with(SeriesType::Amicable, 1_300);
+        for n in [220, 284, 1184, 1210] {
+            assert!(app.contains(n), "{} should be amicable", n);
+        }
+        for n in [6, 28, 221, 496] {
+            assert!(!app.contains(n), "{} should not be amicable", n);
+        }
+    }
+
+    #[test]
+    fn test_emirps_highlights() {
+        let app = app_with(SeriesType::Emirps, 100);
+        for n in [13, 17, 31, 37, 71, 73, 79, 97] {
+            assert!(app.contains(n), "{} should be an emirp", n);
+        }
+        for n in [2, 11, 19, 23] {
+            assert!(!app.contains(n), "{} should not be an emirp", n);
+        }
+    }
+}
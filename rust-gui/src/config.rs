@@ -1,12 +1,23 @@
 //! Visualization configuration
 
-use crate::types::VisualizationType;
+use crate::types::{SeriesType, VisualizationType};
+use crate::visualizations::density_gradient::{
+    GRID_SIZE_MAX, GRID_SIZE_MIN, PROJECTION_POWER_MAX, PROJECTION_POWER_MIN,
+};
+use crate::visualizations::prime_wheel::{MODULO_MAX, MODULO_MIN};
+use crate::visualizations::riemann::{NUM_ZEROS_MAX, NUM_ZEROS_MIN};
+use crate::visualizations::sacks::{SACKS_THETA_MAX, SACKS_THETA_MIN};
+use crate::visualizations::ulam::{SPIRAL_START_MAX, SPIRAL_START_MIN};
 use eframe::egui;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 // Re-export constants for backward compatibility
 pub use crate::constants::layout::*;
 pub use crate::constants::limits::*;
+pub use crate::constants::projection::{OFFSET, PERSPECTIVE};
+pub use crate::constants::spiral::SACKS_THETA_MULTIPLIER;
 
 /// Per-visualization 3D rotation settings.
 #[derive(Clone, Copy, Debug)]
@@ -84,6 +95,37 @@ pub enum PrimePairType {
     Sexy,
 }
 
+/// How the density gradient places a prime on the vertical axis. The
+/// horizontal axis is always the prime's position in `0..max_number`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DensityProjection {
+    /// pᵏ mod max_number, k = `density_projection_power`
+    #[default]
+    PowerMod,
+    /// p mod grid size, one row per residue class
+    ModGrid,
+    /// p's base-10 digits reversed, mod max_number
+    ReverseDigits,
+}
+
+impl DensityProjection {
+    pub const ALL: &'static [DensityProjection] = &[
+        DensityProjection::PowerMod,
+        DensityProjection::ModGrid,
+        DensityProjection::ReverseDigits,
+    ];
+}
+
+impl std::fmt::Display for DensityProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DensityProjection::PowerMod => write!(f, "pᵏ mod max"),
+            DensityProjection::ModGrid => write!(f, "p mod grid"),
+            DensityProjection::ReverseDigits => write!(f, "Reversed digits"),
+        }
+    }
+}
+
 /// Cached blended colors for prime pairs.
 #[derive(Clone, Debug)]
 pub struct PrimePairColors {
@@ -188,6 +230,9 @@ pub struct VisualizerConfig {
     pub highlight_color: egui::Color32,
     pub non_highlight_color: egui::Color32,
     pub background_color: egui::Color32,
+    /// Set once the user edits a color; series switches then keep their
+    /// colors instead of applying `SeriesType::default_colors`.
+    pub colors_customized: bool,
     pub visualization: VisualizationType,
     pub num_zeros: usize,
     pub show_twin_primes: bool,
@@ -197,7 +242,25 @@ pub struct VisualizerConfig {
     pub show_sexy_primes: bool,
     pub sexy_color: egui::Color32,
     pub grid_size: usize,
+    pub density_projection: DensityProjection,
+    /// Exponent k of the `PowerMod` density projection
+    pub density_projection_power: u32,
     pub spike_distance: f32,
+    /// Perspective distance for 3D views; larger means less foreshortening
+    pub perspective: f32,
+    /// Camera distance for 3D views, added to each point's depth
+    pub camera_distance: f32,
+    pub sacks_theta: f32,
+    pub spiral_start: usize,
+    /// First number shown by the grid, row and lattice layouts; the Ulam
+    /// spiral has its own `spiral_start`
+    pub display_start: usize,
+    pub show_legend: bool,
+    pub show_formula: bool,
+    pub max_points_drawn: usize,
+    pub sigma_k: u32,
+    /// Base whose digit-square sums decide the Happy series
+    pub happy_base: usize,
     #[doc(hidden)]
     pub prime_pair_colors: PrimePairColors,
 }
@@ -207,6 +270,8 @@ impl Default for VisualizerConfig {
         let twin_color = egui::Color32::from_rgba_unmultiplied(255, 50, 50, 255);
         let cousin_color = egui::Color32::from_rgba_unmultiplied(255, 120, 120, 255);
         let sexy_color = egui::Color32::from_rgba_unmultiplied(255, 180, 180, 255);
+        let (highlight_color, non_highlight_color, background_color) =
+            SeriesType::default().default_colors();
 
         Self {
             max_number: MAX_NUMBER_DEFAULT,
@@ -214,9 +279,10 @@ impl Default for VisualizerConfig {
             non_highlight_size: NON_HIGHLIGHT_SIZE_DEFAULT,
             modulo: MODULO_DEFAULT,
             show_numbers: false,
-            highlight_color: egui::Color32::from_rgba_unmultiplied(255, 220, 80, 255),
-            non_highlight_color: egui::Color32::from_rgba_unmultiplied(60, 60, 70, 180),
-            background_color: egui::Color32::from_rgba_unmultiplied(20, 20, 30, 255),
+            highlight_color,
+            non_highlight_color,
+            background_color,
+            colors_customized: false,
             visualization: VisualizationType::UlamSpiral,
             num_zeros: NUM_ZEROS_DEFAULT,
             show_twin_primes: false,
@@ -226,16 +292,218 @@ impl Default for VisualizerConfig {
             show_sexy_primes: false,
             sexy_color,
             grid_size: GRID_SIZE_DEFAULT,
+            density_projection: DensityProjection::default(),
+            density_projection_power: 2,
             spike_distance: 8.0,
+            perspective: PERSPECTIVE,
+            camera_distance: OFFSET,
+            sacks_theta: SACKS_THETA_MULTIPLIER,
+            spiral_start: 1,
+            display_start: 1,
+            show_legend: true,
+            show_formula: true,
+            max_points_drawn: MAX_POINTS_DRAWN_DEFAULT,
+            sigma_k: SIGMA_K_DEFAULT,
+            happy_base: HAPPY_BASE_DEFAULT,
             prime_pair_colors: PrimePairColors::new(twin_color, cousin_color, sexy_color),
         }
     }
 }
 
+impl VisualizerConfig {
+    /// Clamp every numeric field to the range its slider offers.
+    ///
+    /// Returns one message per field that had to be adjusted, naming the
+    /// value it held and the one it now has. NaN floats are reset to the
+    /// bottom of their range.
+    pub fn validate(&mut self) -> Result<(), Vec<String>> {
+        let mut messages = Vec::new();
+        let m = &mut messages;
+
+        clamp_field(
+            "max_number",
+            &mut self.max_number,
+            MAX_NUMBER_MIN,
+            MAX_NUMBER_MAX,
+            m,
+        );
+        clamp_field(
+            "highlight_size",
+            &mut self.highlight_size,
+            HIGHLIGHT_SIZE_MIN,
+            HIGHLIGHT_SIZE_MAX,
+            m,
+        );
+        clamp_field(
+            "non_highlight_size",
+            &mut self.non_highlight_size,
+            0,
+            NON_HIGHLIGHT_SIZE_MAX,
+            m,
+        );
+        clamp_field("modulo", &mut self.modulo, MODULO_MIN, MODULO_MAX, m);
+        clamp_field(
+            "num_zeros",
+            &mut self.num_zeros,
+            NUM_ZEROS_MIN,
+            NUM_ZEROS_MAX,
+            m,
+        );
+        clamp_field(
+            "grid_size",
+            &mut self.grid_size,
+            GRID_SIZE_MIN,
+            GRID_SIZE_MAX,
+            m,
+        );
+        clamp_field(
+            "density_projection_power",
+            &mut self.density_projection_power,
+            PROJECTION_POWER_MIN,
+            PROJECTION_POWER_MAX,
+            m,
+        );
+        clamp_field(
+            "spike_distance",
+            &mut self.spike_distance,
+            0.0,
+            SPIKE_DISTANCE_MAX,
+            m,
+        );
+        clamp_field(
+            "perspective",
+            &mut self.perspective,
+            PERSPECTIVE_MIN,
+            PERSPECTIVE_MAX,
+            m,
+        );
+        clamp_field(
+            "camera_distance",
+            &mut self.camera_distance,
+            0.0,
+            CAMERA_DISTANCE_MAX,
+            m,
+        );
+        clamp_field(
+            "sacks_theta",
+            &mut self.sacks_theta,
+            SACKS_THETA_MIN,
+            SACKS_THETA_MAX,
+            m,
+        );
+        clamp_field(
+            "spiral_start",
+            &mut self.spiral_start,
+            SPIRAL_START_MIN,
+            SPIRAL_START_MAX,
+            m,
+        );
+        // Bounded by max_number, so checked after it. Lowering max_number in
+        // the UI leaves the start past the end, so follow it without a message
+        self.display_start = self.display_start.min(self.max_number);
+        clamp_field(
+            "display_start",
+            &mut self.display_start,
+            1,
+            self.max_number,
+            m,
+        );
+        clamp_field(
+            "max_points_drawn",
+            &mut self.max_points_drawn,
+            MAX_POINTS_DRAWN_MIN,
+            MAX_POINTS_DRAWN_MAX,
+            m,
+        );
+        clamp_field("sigma_k", &mut self.sigma_k, 0, SIGMA_K_MAX, m);
+        clamp_field(
+            "happy_base",
+            &mut self.happy_base,
+            HAPPY_BASE_MIN,
+            HAPPY_BASE_MAX,
+            m,
+        );
+
+        if messages.is_empty() {
+            Ok(())
+        } else {
+            Err(messages)
+        }
+    }
+}
+
+/// Clamp `value` to `min..=max`, recording a message if it moved.
+fn clamp_field<T: PartialOrd + Copy + Display>(
+    name: &str,
+    value: &mut T,
+    min: T,
+    max: T,
+    messages: &mut Vec<String>,
+) {
+    let current = *value;
+    let clamped = match (current.partial_cmp(&min), current.partial_cmp(&max)) {
+        (Some(Ordering::Less) | None, _) => min,
+        (_, Some(Ordering::Greater)) => max,
+        _ => return,
+    };
+    messages.push(format!(
+        "{} {} is outside {}..={}, using {}",
+        name, current, min, max, clamped
+    ));
+    *value = clamped;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_validate_default_is_ok() {
+        assert_eq!(VisualizerConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_clamps_modulo() {
+        let mut config = VisualizerConfig {
+            modulo: 500,
+            ..Default::default()
+        };
+        let messages = config.validate().unwrap_err();
+        assert_eq!(config.modulo, MODULO_MAX);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].starts_with("modulo 500"), "{}", messages[0]);
+
+        config.modulo = 0;
+        assert!(config.validate().is_err());
+        assert_eq!(config.modulo, MODULO_MIN);
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_display_start_follows_max_number() {
+        let mut config = VisualizerConfig {
+            max_number: MAX_NUMBER_MAX * 2,
+            display_start: MAX_NUMBER_MAX + 1,
+            sacks_theta: f32::NAN,
+            ..Default::default()
+        };
+        let messages = config.validate().unwrap_err();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(config.max_number, MAX_NUMBER_MAX);
+        assert_eq!(config.display_start, MAX_NUMBER_MAX);
+        assert_eq!(config.sacks_theta, SACKS_THETA_MIN);
+
+        // Shrinking max_number pulls the start along silently
+        config.max_number = MAX_NUMBER_MIN;
+        assert_eq!(config.validate(), Ok(()));
+        assert_eq!(config.display_start, MAX_NUMBER_MIN);
+
+        // The slider never goes below 1, so 0 is still reported
+        config.display_start = 0;
+        assert_eq!(config.validate().unwrap_err().len(), 1);
+        assert_eq!(config.display_start, 1);
+    }
+
     #[test]
     fn test_blend_two_colors() {
         let c1 = egui::Color32::from_rgba_unmultiplied(255, 0, 0, 255);
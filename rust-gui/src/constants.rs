@@ -11,6 +11,36 @@ pub mod limits {
     pub const MAX_NUMBER_DEFAULT: usize = 10000;
     /// Maximum number of values to show text labels for
     pub const SHOW_NUMBERS_MAX: usize = 10000;
+    /// Minimum value for the max points drawn slider
+    pub const MAX_POINTS_DRAWN_MIN: usize = 1000;
+    /// Maximum value for the max points drawn slider (draws every point)
+    pub const MAX_POINTS_DRAWN_MAX: usize = MAX_NUMBER_MAX;
+    /// Default number of points drawn before non-highlights are subsampled
+    pub const MAX_POINTS_DRAWN_DEFAULT: usize = 20000;
+    /// Largest exponent k offered for the divisor sum σₖ series
+    pub const SIGMA_K_MAX: u32 = 3;
+    /// Default exponent k for the divisor sum σₖ series (σ₁, the divisor sum)
+    pub const SIGMA_K_DEFAULT: u32 = 1;
+    /// Smallest base offered for the Happy series
+    pub const HAPPY_BASE_MIN: usize = 2;
+    /// Largest base offered for the Happy series
+    pub const HAPPY_BASE_MAX: usize = 16;
+    /// Default base for the Happy series
+    pub const HAPPY_BASE_DEFAULT: usize = 10;
+    /// Minimum value for the 3D perspective slider (strongest foreshortening)
+    pub const PERSPECTIVE_MIN: f32 = 100.0;
+    /// Maximum value for the 3D perspective slider (nearly orthographic)
+    pub const PERSPECTIVE_MAX: f32 = 3000.0;
+    /// Maximum value for the 3D camera distance slider
+    pub const CAMERA_DISTANCE_MAX: f32 = 1500.0;
+    /// Minimum value for the highlight size slider
+    pub const HIGHLIGHT_SIZE_MIN: usize = 1;
+    /// Maximum value for the highlight size slider
+    pub const HIGHLIGHT_SIZE_MAX: usize = 20;
+    /// Maximum value for the non-highlight size slider (0 hides them)
+    pub const NON_HIGHLIGHT_SIZE_MAX: usize = 10;
+    /// Maximum value for the spike distance slider
+    pub const SPIKE_DISTANCE_MAX: f32 = 10.0;
     /// Number of intervals for density calculations
     pub const DENSITY_INTERVALS: usize = 100;
 
@@ -57,7 +87,7 @@ pub mod visualization {
 pub mod spiral {
     //! Spiral-specific mathematical constants
 
-    /// Sacks spiral angle multiplier (r = sqrt(n), theta = n * multiplier)
+    /// Default Sacks spiral angle multiplier (r = sqrt(n), theta = n * multiplier)
     pub const SACKS_THETA_MULTIPLIER: f32 = 0.5;
     /// Sacks Mobius spiral radius multiplier
     pub const SACKS_MOBIUS_RADIUS_MULTIPLIER: f32 = 0.8;
@@ -107,15 +137,29 @@ pub mod gap {
     pub const BRIGHTNESS_LARGE: u8 = 30;
     /// Brightness for extra large gaps (51+)
     pub const BRIGHTNESS_XLARGE: u8 = 20;
+
+    /// Gap legend box width in pixels
+    pub const LEGEND_WIDTH: f32 = 70.0;
+    /// Gap legend row height in pixels
+    pub const LEGEND_ROW_HEIGHT: f32 = 14.0;
+    /// Gap legend inner padding in pixels
+    pub const LEGEND_PADDING: f32 = 6.0;
+    /// Gap legend color swatch size in pixels
+    pub const LEGEND_SWATCH_SIZE: f32 = 10.0;
+    /// Gap legend font size
+    pub const LEGEND_FONT_SIZE: f32 = 11.0;
 }
 
 pub mod projection {
     //! 3D projection constants
 
-    /// Perspective distance for 3D projection
+    /// Default perspective distance for 3D projection
     pub const PERSPECTIVE: f32 = 500.0;
-    /// Offset added to depth before projection
+    /// Default camera distance, added to depth before projection
     pub const OFFSET: f32 = 300.0;
+    /// Closest a point may be to the camera and still be drawn; nearer
+    /// points, and any behind the camera, are culled
+    pub const NEAR_PLANE: f32 = 10.0;
     /// Range of depth values for scaling
     pub const DEPTH_RANGE: f32 = 600.0;
     /// Minimum depth factor for brightness adjustment
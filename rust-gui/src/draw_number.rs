@@ -2,16 +2,15 @@
 
 use crate::config::{PrimePairType, VisualizerConfig};
 use crate::constants::{drawing, limits};
-use crate::types::SeriesType;
+use crate::types::{Highlights, SeriesType};
 use eframe::egui;
-use std::collections::HashSet;
 
 // Re-export constants for backward compatibility
 pub use crate::constants::drawing::*;
 
 fn get_prime_pair_types(
     n: usize,
-    highlights: &HashSet<usize>,
+    highlights: Highlights<'_>,
     config: &VisualizerConfig,
 ) -> Vec<PrimePairType> {
     let mut pair_types = Vec::new();
@@ -43,7 +42,7 @@ fn get_prime_pair_types(
 /// is not primes.
 pub fn get_prime_pair_color(
     n: usize,
-    highlights: &HashSet<usize>,
+    highlights: Highlights<'_>,
     config: &VisualizerConfig,
     series_type: SeriesType,
 ) -> Option<egui::Color32> {
@@ -77,7 +76,7 @@ pub fn draw_number(
     x: f32,
     y: f32,
     painter: &egui::Painter,
-    highlights: &HashSet<usize>,
+    highlights: Highlights<'_>,
     config: &VisualizerConfig,
     series_type: SeriesType,
 ) {
@@ -112,6 +111,49 @@ pub fn draw_number(
     draw_number_text(n, x, y, painter, config);
 }
 
+/// Stride for subsampling non-highlighted points so that about `max_points`
+/// of `point_count` are drawn. Returns 1 (draw everything) when the count is
+/// within the cap or the cap is 0.
+pub fn lod_stride(point_count: usize, max_points: usize) -> usize {
+    if max_points == 0 || point_count <= max_points {
+        1
+    } else {
+        point_count.div_ceil(max_points)
+    }
+}
+
+/// Draw every position with level-of-detail applied.
+///
+/// `to_screen` maps position coordinates to screen coordinates. When there are
+/// more than `config.max_points_drawn` positions, only every `lod_stride`-th
+/// non-highlighted point is drawn; highlighted points are always drawn.
+pub fn draw_numbers(
+    positions: &[(usize, f32, f32)],
+    to_screen: impl Fn(f32, f32) -> (f32, f32),
+    painter: &egui::Painter,
+    highlights: Highlights<'_>,
+    config: &VisualizerConfig,
+    series_type: SeriesType,
+) {
+    let stride = lod_stride(positions.len(), config.max_points_drawn);
+
+    for (i, &(n, x, y)) in positions.iter().enumerate() {
+        if stride > 1 && !i.is_multiple_of(stride) && !highlights.contains(&n) {
+            continue;
+        }
+        let (screen_x, screen_y) = to_screen(x, y);
+        draw_number(
+            n,
+            screen_x,
+            screen_y,
+            painter,
+            highlights,
+            config,
+            series_type,
+        );
+    }
+}
+
 fn draw_number_text(n: usize, x: f32, y: f32, painter: &egui::Painter, config: &VisualizerConfig) {
     let show_text = config.show_numbers
         && config.highlight_size as f32 >= drawing::MIN_SIZE_FOR_TEXT
@@ -138,13 +180,16 @@ mod tests {
 
     #[test]
     fn test_get_prime_pair_color_blend() {
-        let highlights: HashSet<usize> = [2, 3, 5, 7, 11, 13].into_iter().collect();
-        let mut config = VisualizerConfig::default();
-        config.show_twin_primes = true;
-        config.show_cousin_primes = true;
-        config.show_sexy_primes = true;
+        let set: HashSet<usize> = [2, 3, 5, 7, 11, 13].into_iter().collect();
+        let highlights = Highlights::Set(&set);
+        let config = VisualizerConfig {
+            show_twin_primes: true,
+            show_cousin_primes: true,
+            show_sexy_primes: true,
+            ..Default::default()
+        };
 
-        let color = get_prime_pair_color(7, &highlights, &config, SeriesType::Primes);
+        let color = get_prime_pair_color(7, highlights, &config, SeriesType::Primes);
         assert!(color.is_some());
 
         let expected_color = config.prime_pair_colors.get_color(&[
@@ -157,19 +202,67 @@ mod tests {
 
     #[test]
     fn test_get_prime_pair_color_none_for_non_prime() {
-        let highlights: HashSet<usize> = [2, 3, 5, 7].into_iter().collect();
+        let set: HashSet<usize> = [2, 3, 5, 7].into_iter().collect();
+        let highlights = Highlights::Set(&set);
         let config = VisualizerConfig::default();
 
-        let color = get_prime_pair_color(4, &highlights, &config, SeriesType::Primes);
+        let color = get_prime_pair_color(4, highlights, &config, SeriesType::Primes);
         assert!(color.is_none());
     }
 
     #[test]
     fn test_get_prime_pair_color_non_primes_series() {
-        let highlights: HashSet<usize> = [1, 2, 3, 5, 8].into_iter().collect();
+        let set: HashSet<usize> = [1, 2, 3, 5, 8].into_iter().collect();
+        let highlights = Highlights::Set(&set);
         let config = VisualizerConfig::default();
 
-        let color = get_prime_pair_color(5, &highlights, &config, SeriesType::Fibonacci);
+        let color = get_prime_pair_color(5, highlights, &config, SeriesType::Fibonacci);
         assert!(color.is_none());
     }
+
+    #[test]
+    fn test_get_prime_pair_color_none_for_all_non_prime_series() {
+        // Even with every pair overlay enabled and a highlight set full of
+        // twin/cousin/sexy gaps, non-prime series must fall back to the plain color.
+        let set: HashSet<usize> = [3, 5, 7, 11, 13, 17, 19, 23].into_iter().collect();
+        let highlights = Highlights::Set(&set);
+        let config = VisualizerConfig {
+            show_twin_primes: true,
+            show_cousin_primes: true,
+            show_sexy_primes: true,
+            ..Default::default()
+        };
+
+        assert!(get_prime_pair_color(7, highlights, &config, SeriesType::Primes).is_some());
+        for &series in SeriesType::ALL {
+            if series == SeriesType::Primes {
+                continue;
+            }
+            for &n in &set {
+                assert!(
+                    get_prime_pair_color(n, highlights, &config, series).is_none(),
+                    "{} should not get prime-pair coloring for {}",
+                    series,
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lod_stride() {
+        // Within the cap: draw everything
+        assert_eq!(lod_stride(0, 1000), 1);
+        assert_eq!(lod_stride(1000, 1000), 1);
+        // A cap of 0 disables subsampling
+        assert_eq!(lod_stride(100_000, 0), 1);
+        // Over the cap: round up so at most max_points are drawn
+        assert_eq!(lod_stride(1001, 1000), 2);
+        assert_eq!(lod_stride(100_000, 20_000), 5);
+        assert_eq!(lod_stride(100_000, 30_000), 4);
+        for (count, cap) in [(1001, 1000), (100_000, 30_000), (99_999, 7)] {
+            let stride = lod_stride(count, cap);
+            assert!(count.div_ceil(stride) <= cap, "count={} cap={}", count, cap);
+        }
+    }
 }
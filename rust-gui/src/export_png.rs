@@ -56,6 +56,10 @@ impl Exporter {
             VisualizationType::PrimeDensityGradient => {
                 Self::render_density_gradient(&mut img, app, width, height)
             }
+            // Bar charts have no PNG renderer yet
+            VisualizationType::NumberLineFactors => {}
+            // Nor does the Collatz graph's line overlay
+            VisualizationType::CollatzGraph => {}
             VisualizationType::Helix3D => Self::render_helix_3d(&mut img, app, width, height),
             VisualizationType::Sphere3D => Self::render_sphere_3d(&mut img, app, width, height),
             VisualizationType::Torus3D => Self::render_torus_3d(&mut img, app, width, height),
@@ -89,7 +93,10 @@ impl Exporter {
         width: u32,
         height: u32,
     ) {
-        let positions = crate::visualizations::generate_ulam_positions(app.config.max_number);
+        let positions = crate::visualizations::generate_ulam_positions(
+            app.config.max_number,
+            app.config.spiral_start,
+        );
         Self::render_spiral_points(img, app, width, height, &positions);
     }
 
@@ -99,7 +106,10 @@ impl Exporter {
         width: u32,
         height: u32,
     ) {
-        let positions = crate::visualizations::generate_sacks_positions(app.config.max_number);
+        let positions = crate::visualizations::generate_sacks_positions(
+            app.config.max_number,
+            app.config.sacks_theta,
+        );
         Self::render_spiral_points(img, app, width, height, &positions);
     }
 
@@ -123,7 +133,10 @@ impl Exporter {
         width: u32,
         height: u32,
     ) {
-        let positions = crate::visualizations::generate_hexagonal_positions(app.config.max_number);
+        let positions = crate::visualizations::generate_hexagonal_positions(
+            app.config.max_number,
+            app.config.display_start,
+        );
         Self::render_lattice_points(img, app, width, height, &positions);
     }
 
@@ -133,7 +146,10 @@ impl Exporter {
         width: u32,
         height: u32,
     ) {
-        let positions = crate::visualizations::generate_triangular_positions(app.config.max_number);
+        let positions = crate::visualizations::generate_triangular_positions(
+            app.config.max_number,
+            app.config.display_start,
+        );
         Self::render_lattice_points(img, app, width, height, &positions);
     }
 
@@ -158,7 +174,7 @@ impl Exporter {
         width: u32,
         height: u32,
         positions: &[(usize, f32, f32)],
-        highlights: &std::collections::HashSet<usize>,
+        highlights: crate::types::Highlights<'_>,
         _rot_x: f32,
         _rot_y: f32,
         flip_y: bool,
@@ -277,7 +293,10 @@ impl Exporter {
         width: u32,
         height: u32,
     ) {
-        let positions = crate::visualizations::generate_grid_positions(app.config.max_number);
+        let positions = crate::visualizations::generate_grid_positions(
+            app.config.max_number,
+            app.config.display_start,
+        );
         let highlights = app.highlights();
 
         let (min_x, max_x, min_y, max_y) = Self::calculate_bounds(&positions);
@@ -337,7 +356,10 @@ impl Exporter {
         width: u32,
         height: u32,
     ) {
-        let positions = crate::visualizations::generate_row_positions(app.config.max_number);
+        let positions = crate::visualizations::generate_row_positions(
+            app.config.max_number,
+            app.config.display_start,
+        );
         let highlights = app.highlights();
 
         let max_x = positions
@@ -441,9 +463,12 @@ impl Exporter {
         }
 
         let spiral_positions: Vec<(usize, f32, f32)> = if use_sacks {
-            crate::visualizations::generate_sacks_positions(primes_vec.len())
+            crate::visualizations::generate_sacks_positions(
+                primes_vec.len(),
+                app.config.sacks_theta,
+            )
         } else {
-            crate::visualizations::generate_ulam_positions(primes_vec.len())
+            crate::visualizations::generate_ulam_positions(primes_vec.len(), 1)
         };
 
         let positions: Vec<(usize, f32, f32)> = primes_vec
@@ -777,7 +802,15 @@ impl Exporter {
             let radius = 100.0;
             let y = (n as f32 - max_n_f / 2.0) * 3.0;
             let point = Point3D::new(radius * theta.cos(), y, radius * theta.sin());
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -810,7 +843,15 @@ impl Exporter {
                 r * phi.cos(),
                 r * phi.sin() * theta.sin(),
             );
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -844,7 +885,15 @@ impl Exporter {
                 minor * phi.sin(),
                 (major + minor * phi.cos()) * theta.sin(),
             );
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -874,7 +923,15 @@ impl Exporter {
             let h = (n as f32 - 1.0) / max_n_f * 200.0 - 100.0;
             let radius = if is_highlighted { r + 15.0 } else { r };
             let point = Point3D::new(radius * theta.cos(), h, radius * theta.sin());
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -903,7 +960,15 @@ impl Exporter {
             let h = (n as f32 - 1.0) / max_n_f * 200.0 - 100.0;
             let r = if is_highlighted { 95.0 } else { 80.0 };
             let point = Point3D::new(r * theta.cos(), h, r * theta.sin());
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -941,7 +1006,15 @@ impl Exporter {
                 4 => Point3D::new(r * angle.cos(), r, r * angle.sin()),
                 _ => Point3D::new(r * angle.cos(), -r, -r * angle.sin()),
             };
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -975,7 +1048,15 @@ impl Exporter {
                 w * half_t.sin(),
                 (r + w * half_t.cos()) * t.sin(),
             );
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -1007,7 +1088,15 @@ impl Exporter {
             let y = (r + r * t.cos() / 2.0 * t.cos()) * s.sin();
             let z = r * t.sin() / 2.0;
             let point = Point3D::new(x, y, z);
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -1038,7 +1127,15 @@ impl Exporter {
             let angle = t * 4.0 * std::f32::consts::PI;
             let r = if is_highlighted { base + 15.0 } else { base };
             let point = Point3D::new(r * angle.cos(), h, r * angle.sin());
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -1074,7 +1171,15 @@ impl Exporter {
                 r * phi.cos(),
                 r * phi.sin() * theta.sin(),
             );
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -1110,7 +1215,15 @@ impl Exporter {
                 r * phi.cos(),
                 r * phi.sin() * theta.sin(),
             );
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -1142,7 +1255,15 @@ impl Exporter {
             let y = r * (2.0 * t).cos() + tube * (3.0 * t).sin();
             let z = tube * t.cos() * (3.0 * t).cos();
             let point = Point3D::new(x, y, z);
-            let (px, py, pz) = project_3d_to_2d(&point, rot_y, rot_x);
+            let Some((px, py, pz)) = project_3d_to_2d(
+                &point,
+                rot_y,
+                rot_x,
+                app.config.perspective,
+                app.config.camera_distance,
+            ) else {
+                continue;
+            };
             projected.push((px, py, pz, n, is_highlighted));
         }
 
@@ -175,6 +175,16 @@ pub fn find_hovered_centered(
     closest_n
 }
 
+/// Convert axial hex coordinates (q, r) to a pixel offset on a unit
+/// pointy-top hexagonal lattice: x = √3·(q + r/2), y = 1.5·r.
+///
+/// All six neighbours of a cell end up √3 away, unlike the plain integer
+/// offsets (2q + r, 2r), whose diagonal neighbours are √5 away.
+pub fn axial_to_pixel(q: i32, r: i32) -> (f32, f32) {
+    let (q, r) = (q as f32, r as f32);
+    (3f32.sqrt() * (q + r / 2.0), 1.5 * r)
+}
+
 /// Calculate bounding box from positions.
 ///
 /// Returns: (min_x, max_x, min_y, max_y)
@@ -240,6 +250,74 @@ pub fn gap_color(gap: usize) -> egui::Color32 {
     egui::Color32::from_rgba_unmultiplied(brightness, brightness, brightness, 255)
 }
 
+/// Legend entries for the Mobius spiral gap colors, smallest gap first.
+///
+/// Each entry pairs a gap label with the color `gap_color` assigns it, so the
+/// legend always matches the rendered lines:
+/// 2, 4, 6, 8, 10, 12, 14, 16, 17-20, 21-30, 31-50, 51+
+pub fn gap_legend_entries() -> Vec<(&'static str, egui::Color32)> {
+    // Each label is paired with a representative gap from its bucket
+    [
+        ("2", 2),
+        ("4", 4),
+        ("6", 6),
+        ("8", 8),
+        ("10", 10),
+        ("12", 12),
+        ("14", 14),
+        ("16", 16),
+        ("17-20", 20),
+        ("21-30", 30),
+        ("31-50", 50),
+        ("51+", 51),
+    ]
+    .into_iter()
+    .map(|(label, gap)| (label, gap_color(gap)))
+    .collect()
+}
+
+/// Draw the gap color legend in the top-left corner of `rect`.
+pub fn draw_gap_legend(painter: &egui::Painter, rect: egui::Rect) {
+    let entries = gap_legend_entries();
+    let font_id = egui::FontId::proportional(gap::LEGEND_FONT_SIZE);
+    let height = gap::LEGEND_PADDING * 2.0 + gap::LEGEND_ROW_HEIGHT * (entries.len() + 1) as f32;
+    let legend_rect = egui::Rect::from_min_size(
+        rect.min + egui::vec2(MARGIN_SMALL, MARGIN_SMALL),
+        egui::vec2(gap::LEGEND_WIDTH, height),
+    );
+    painter.rect_filled(
+        legend_rect,
+        4.0,
+        egui::Color32::from_rgba_unmultiplied(0, 0, 0, 180),
+    );
+
+    let left = legend_rect.left() + gap::LEGEND_PADDING;
+    let mut y = legend_rect.top() + gap::LEGEND_PADDING + gap::LEGEND_ROW_HEIGHT / 2.0;
+    painter.text(
+        egui::Pos2::new(left, y),
+        egui::Align2::LEFT_CENTER,
+        "Gap",
+        font_id.clone(),
+        egui::Color32::WHITE,
+    );
+
+    for (label, color) in entries {
+        y += gap::LEGEND_ROW_HEIGHT;
+        let swatch = egui::Rect::from_center_size(
+            egui::Pos2::new(left + gap::LEGEND_SWATCH_SIZE / 2.0, y),
+            egui::vec2(gap::LEGEND_SWATCH_SIZE, gap::LEGEND_SWATCH_SIZE),
+        );
+        painter.rect_filled(swatch, 1.0, color);
+        painter.text(
+            egui::Pos2::new(left + gap::LEGEND_SWATCH_SIZE + gap::LEGEND_PADDING, y),
+            egui::Align2::LEFT_CENTER,
+            label,
+            font_id.clone(),
+            egui::Color32::WHITE,
+        );
+    }
+}
+
 /// Generate stroke width based on gap size for Mobius spirals.
 ///
 /// Larger gaps produce thinner lines to reduce visual clutter.
@@ -304,6 +382,31 @@ mod tests {
         assert_eq!(scale, 1.0);
     }
 
+    #[test]
+    fn test_gap_legend_entries() {
+        let entries: Vec<(&str, u8)> = gap_legend_entries()
+            .into_iter()
+            .map(|(label, color)| (label, color.r()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("2", 255),
+                ("4", 220),
+                ("6", 180),
+                ("8", 150),
+                ("10", 120),
+                ("12", 100),
+                ("14", 85),
+                ("16", 70),
+                ("17-20", 60),
+                ("21-30", 45),
+                ("31-50", 30),
+                ("51+", 20),
+            ]
+        );
+    }
+
     #[test]
     fn test_gap_color_twin_prime() {
         let color = gap_color(2);
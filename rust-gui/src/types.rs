@@ -1,5 +1,10 @@
 //! Series and visualization types
 
+use crate::visualizations::REGISTRY;
+use eframe::egui;
+use series::Sequence;
+use std::collections::HashSet;
+
 /// Represents different number sequences that can be visualized.
 #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
 pub enum SeriesType {
@@ -12,7 +17,16 @@ pub enum SeriesType {
     PowersOf2,
     Catalan,
     Hexagonal,
+    CenteredHexagonal,
     Happy,
+    TwinPrimes,
+    Harshad,
+    Palindromes,
+    Emirps,
+    Stern,
+    ThueMorse,
+    DivisorSigma,
+    Amicable,
 }
 
 impl SeriesType {
@@ -25,10 +39,130 @@ impl SeriesType {
         SeriesType::PowersOf2,
         SeriesType::Catalan,
         SeriesType::Hexagonal,
+        SeriesType::CenteredHexagonal,
         SeriesType::Happy,
+        SeriesType::TwinPrimes,
+        SeriesType::Harshad,
+        SeriesType::Palindromes,
+        SeriesType::Emirps,
+        SeriesType::Stern,
+        SeriesType::ThueMorse,
+        SeriesType::DivisorSigma,
+        SeriesType::Amicable,
     ];
 }
 
+impl SeriesType {
+    /// The series' closed-form membership test, for series whose terms never
+    /// need to be listed. Highlighting these checks each point directly
+    /// instead of building a set of every term up to the max number.
+    pub fn membership(self) -> Option<&'static dyn Sequence> {
+        use series::sequence;
+
+        let sequence: &'static dyn Sequence = match self {
+            SeriesType::Fibonacci => &sequence::FIBONACCI,
+            SeriesType::Lucas => &sequence::LUCAS,
+            SeriesType::Triangular => &sequence::TRIANGULAR,
+            SeriesType::PowersOf2 => &sequence::POWERS_OF_2,
+            SeriesType::Catalan => &sequence::CATALAN,
+            SeriesType::Hexagonal => &sequence::HEXAGONAL,
+            SeriesType::CenteredHexagonal => &sequence::CENTERED_HEXAGONAL,
+            _ => return None,
+        };
+        Some(sequence)
+    }
+}
+
+impl SeriesType {
+    /// The defining formula or rule, shown in the side panel.
+    pub fn formula(self) -> &'static str {
+        match self {
+            SeriesType::Primes => "p > 1 with no divisors but 1 and p",
+            SeriesType::Fibonacci => "F₀ = 0, F₁ = 1, Fₙ = Fₙ₋₁ + Fₙ₋₂",
+            SeriesType::Lucas => "L₀ = 2, L₁ = 1, Lₙ = Lₙ₋₁ + Lₙ₋₂",
+            SeriesType::Triangular => "Tₙ = n(n+1)/2",
+            SeriesType::Collatz => "Steps of n → n/2 (even), 3n+1 (odd) to reach 1",
+            SeriesType::PowersOf2 => "2ⁿ",
+            SeriesType::Catalan => "Cₙ = (2n)! / (n!(n+1)!)",
+            SeriesType::Hexagonal => "Hₙ = n(2n-1)",
+            SeriesType::CenteredHexagonal => "Cₙ = 3n² - 3n + 1",
+            SeriesType::Happy => "Iterating the digit-square sum reaches 1",
+            SeriesType::TwinPrimes => "p prime with p - 2 or p + 2 prime",
+            SeriesType::Harshad => "n divisible by its digit sum",
+            SeriesType::Palindromes => "Digits read the same reversed",
+            SeriesType::Emirps => "p prime, reverse(p) ≠ p also prime",
+            SeriesType::Stern => "a(2n) = a(n), a(2n+1) = a(n) + a(n+1)",
+            SeriesType::ThueMorse => "t(n) = number of 1 bits in n, mod 2",
+            SeriesType::DivisorSigma => "σₖ(n) = Σ dᵏ over divisors d of n",
+            SeriesType::Amicable => "s(a) = b, s(b) = a, a ≠ b, s = sum of proper divisors",
+        }
+    }
+}
+
+impl SeriesType {
+    /// Default (highlight, non-highlight, background) colors, applied when
+    /// switching to this series unless the user has picked their own.
+    ///
+    /// Only the highlight differs between series; the dark background and
+    /// dim non-highlight are shared so every series reads the same way.
+    pub fn default_colors(self) -> (egui::Color32, egui::Color32, egui::Color32) {
+        let (r, g, b) = match self {
+            SeriesType::Primes => (255, 220, 80),
+            SeriesType::Fibonacci => (90, 220, 120),
+            SeriesType::Lucas => (170, 230, 90),
+            SeriesType::Triangular => (255, 150, 60),
+            SeriesType::Collatz => (80, 160, 255),
+            SeriesType::PowersOf2 => (200, 120, 255),
+            SeriesType::Catalan => (255, 110, 180),
+            SeriesType::Hexagonal => (80, 220, 220),
+            SeriesType::CenteredHexagonal => (40, 200, 150),
+            SeriesType::Happy => (255, 240, 150),
+            SeriesType::TwinPrimes => (255, 80, 80),
+            SeriesType::Harshad => (170, 190, 255),
+            SeriesType::Palindromes => (120, 255, 200),
+            SeriesType::Emirps => (255, 185, 100),
+            SeriesType::Stern => (130, 120, 255),
+            SeriesType::ThueMorse => (230, 230, 230),
+            SeriesType::DivisorSigma => (210, 170, 120),
+            SeriesType::Amicable => (255, 150, 150),
+        };
+        (
+            egui::Color32::from_rgba_unmultiplied(r, g, b, 255),
+            egui::Color32::from_rgba_unmultiplied(60, 60, 70, 180),
+            egui::Color32::from_rgba_unmultiplied(20, 20, 30, 255),
+        )
+    }
+}
+
+/// The numbers to highlight: a precomputed set, or a series' membership
+/// test bounded by the max number.
+#[derive(Clone, Copy)]
+pub enum Highlights<'a> {
+    Set(&'a HashSet<usize>),
+    Membership {
+        sequence: &'static dyn Sequence,
+        max_number: usize,
+    },
+}
+
+impl Highlights<'_> {
+    pub fn contains(&self, n: &usize) -> bool {
+        match *self {
+            Highlights::Set(set) => set.contains(n),
+            Highlights::Membership {
+                sequence,
+                max_number,
+            } => sequence.contains_up_to(*n, max_number).unwrap_or(false),
+        }
+    }
+}
+
+impl<'a> From<&'a HashSet<usize>> for Highlights<'a> {
+    fn from(set: &'a HashSet<usize>) -> Self {
+        Highlights::Set(set)
+    }
+}
+
 impl std::fmt::Display for SeriesType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -40,7 +174,16 @@ impl std::fmt::Display for SeriesType {
             SeriesType::PowersOf2 => write!(f, "Powers of 2"),
             SeriesType::Catalan => write!(f, "Catalan"),
             SeriesType::Hexagonal => write!(f, "Hexagonal"),
+            SeriesType::CenteredHexagonal => write!(f, "Centered Hexagonal"),
             SeriesType::Happy => write!(f, "Happy"),
+            SeriesType::TwinPrimes => write!(f, "Twin Primes"),
+            SeriesType::Harshad => write!(f, "Harshad"),
+            SeriesType::Palindromes => write!(f, "Palindromes"),
+            SeriesType::Emirps => write!(f, "Emirps"),
+            SeriesType::Stern => write!(f, "Stern Diatomic"),
+            SeriesType::ThueMorse => write!(f, "Thue-Morse"),
+            SeriesType::DivisorSigma => write!(f, "Divisor Sum σₖ"),
+            SeriesType::Amicable => write!(f, "Amicable"),
         }
     }
 }
@@ -62,6 +205,8 @@ pub enum VisualizationType {
     SacksMobiusSpiral,
     UlamMobiusSpiral,
     PrimeDensityGradient,
+    NumberLineFactors,
+    CollatzGraph,
     Helix3D,
     Sphere3D,
     Torus3D,
@@ -92,6 +237,7 @@ impl VisualizationType {
         VisualizationType::SacksMobiusSpiral,
         VisualizationType::UlamMobiusSpiral,
         VisualizationType::PrimeDensityGradient,
+        VisualizationType::NumberLineFactors,
         VisualizationType::Helix3D,
         VisualizationType::Sphere3D,
         VisualizationType::Torus3D,
@@ -105,43 +251,21 @@ impl VisualizationType {
         VisualizationType::Dodecahedron3D,
         VisualizationType::Icosahedron3D,
         VisualizationType::Trefoil3D,
+        VisualizationType::CollatzGraph,
     ];
 
-    pub const GENERAL: &'static [VisualizationType] = &[
-        VisualizationType::UlamSpiral,
-        VisualizationType::SacksSpiral,
-        VisualizationType::Grid,
-        VisualizationType::Row,
-        VisualizationType::HexagonalLattice,
-        VisualizationType::TriangularLattice,
-        VisualizationType::FermatsSpiral,
-        VisualizationType::Helix3D,
-        VisualizationType::Sphere3D,
-        VisualizationType::Torus3D,
-        VisualizationType::Cone3D,
-        VisualizationType::Cylinder3D,
-        VisualizationType::CubeQuadratic3D,
-        VisualizationType::CubeSimple3D,
-        VisualizationType::Mobius3D,
-        VisualizationType::Klein3D,
-        VisualizationType::Pyramid3D,
-        VisualizationType::Dodecahedron3D,
-        VisualizationType::Icosahedron3D,
-        VisualizationType::Trefoil3D,
-    ];
-
-    pub fn available_for(series: SeriesType) -> &'static [VisualizationType] {
-        match series {
-            SeriesType::Primes => Self::ALL,
-            SeriesType::Fibonacci
-            | SeriesType::Lucas
-            | SeriesType::Triangular
-            | SeriesType::Collatz
-            | SeriesType::PowersOf2
-            | SeriesType::Catalan
-            | SeriesType::Hexagonal
-            | SeriesType::Happy => Self::GENERAL,
-        }
+    /// The visualizations whose registered `Visualizer` supports `series`,
+    /// in `ALL` order.
+    pub fn available_for(series: SeriesType) -> Vec<VisualizationType> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|&viz_type| {
+                REGISTRY
+                    .get(viz_type)
+                    .is_some_and(|viz| viz.supports_series(series))
+            })
+            .collect()
     }
 
     pub fn is_primes_only(self) -> bool {
@@ -153,6 +277,7 @@ impl VisualizationType {
                 | Self::SacksMobiusSpiral
                 | Self::UlamMobiusSpiral
                 | Self::PrimeDensityGradient
+                | Self::NumberLineFactors
         )
     }
 
@@ -169,6 +294,7 @@ impl VisualizationType {
                 | Self::FermatsSpiral
                 | Self::SacksMobiusSpiral
                 | Self::UlamMobiusSpiral
+                | Self::CollatzGraph
                 | Self::Helix3D
                 | Self::Sphere3D
                 | Self::Torus3D
@@ -185,6 +311,36 @@ impl VisualizationType {
         )
     }
 
+    /// Whether cell k of this layout holds `display_start + k`. Spirals
+    /// placed by the number itself (Sacks, Fermat) and the Ulam spiral, with
+    /// its own start, are not offset.
+    pub fn uses_display_start(self) -> bool {
+        matches!(
+            self,
+            Self::Grid | Self::Row | Self::HexagonalLattice | Self::TriangularLattice
+        )
+    }
+
+    /// Whether this is a rotatable 3D view drawn through `project_3d_to_2d`.
+    pub fn is_3d(self) -> bool {
+        matches!(
+            self,
+            Self::Helix3D
+                | Self::Sphere3D
+                | Self::Torus3D
+                | Self::Cone3D
+                | Self::Cylinder3D
+                | Self::CubeQuadratic3D
+                | Self::CubeSimple3D
+                | Self::Mobius3D
+                | Self::Klein3D
+                | Self::Pyramid3D
+                | Self::Dodecahedron3D
+                | Self::Icosahedron3D
+                | Self::Trefoil3D
+        )
+    }
+
     pub fn description(self) -> &'static str {
         match self {
             Self::UlamSpiral => "Classic diagonal pattern on a square grid spiral",
@@ -204,6 +360,8 @@ impl VisualizationType {
                 "Square-grid spiral with gap-colored lines between consecutive primes"
             }
             Self::PrimeDensityGradient => "Heatmap grid showing local prime density",
+            Self::NumberLineFactors => "Bars of distinct prime factor counts along the number line",
+            Self::CollatzGraph => "Ulam spiral with a line from each n to its Collatz successor",
             Self::Helix3D => "3D spiral helix with highlighted numbers spiking outward",
             Self::Sphere3D => "3D sphere with numbers distributed on surface, highlights bulge",
             Self::Torus3D => "3D torus (donut) with numbers wrapped around, highlights bulge",
@@ -239,6 +397,8 @@ impl std::fmt::Display for VisualizationType {
             VisualizationType::SacksMobiusSpiral => write!(f, "Sacks Mobius Spiral"),
             VisualizationType::UlamMobiusSpiral => write!(f, "Ulam Mobius Spiral"),
             VisualizationType::PrimeDensityGradient => write!(f, "Prime Density Gradient"),
+            VisualizationType::NumberLineFactors => write!(f, "Number Line Factors"),
+            VisualizationType::CollatzGraph => write!(f, "Collatz Graph"),
             VisualizationType::Helix3D => write!(f, "3D Helix"),
             VisualizationType::Sphere3D => write!(f, "3D Sphere"),
             VisualizationType::Torus3D => write!(f, "3D Torus"),
@@ -267,6 +427,23 @@ mod tests {
         assert_eq!(format!("{}", SeriesType::PowersOf2), "Powers of 2");
     }
 
+    #[test]
+    fn test_series_type_formula() {
+        for &series in SeriesType::ALL {
+            assert!(!series.formula().is_empty(), "{} has no formula", series);
+        }
+        assert_eq!(SeriesType::Triangular.formula(), "Tₙ = n(n+1)/2");
+    }
+
+    #[test]
+    fn test_default_colors_distinct_highlights() {
+        let highlights: HashSet<egui::Color32> = SeriesType::ALL
+            .iter()
+            .map(|series| series.default_colors().0)
+            .collect();
+        assert_eq!(highlights.len(), SeriesType::ALL.len());
+    }
+
     #[test]
     fn test_visualization_type_display() {
         assert_eq!(format!("{}", VisualizationType::UlamSpiral), "Ulam Spiral");
@@ -281,6 +458,7 @@ mod tests {
         assert!(VisualizationType::PrimeWheel.is_primes_only());
         assert!(VisualizationType::PrimeDensity.is_primes_only());
         assert!(VisualizationType::RiemannZeta.is_primes_only());
+        assert!(VisualizationType::NumberLineFactors.is_primes_only());
         assert!(!VisualizationType::UlamSpiral.is_primes_only());
         assert!(!VisualizationType::Grid.is_primes_only());
     }
@@ -292,6 +470,22 @@ mod tests {
         assert!(!VisualizationType::PrimeDensity.uses_point_rendering());
     }
 
+    #[test]
+    fn test_visualization_uses_display_start() {
+        assert!(VisualizationType::Grid.uses_display_start());
+        assert!(VisualizationType::HexagonalLattice.uses_display_start());
+        assert!(!VisualizationType::UlamSpiral.uses_display_start());
+        assert!(!VisualizationType::SacksSpiral.uses_display_start());
+    }
+
+    #[test]
+    fn test_visualization_is_3d() {
+        assert!(VisualizationType::Helix3D.is_3d());
+        assert!(VisualizationType::CubeSimple3D.is_3d());
+        assert!(!VisualizationType::UlamSpiral.is_3d());
+        assert!(!VisualizationType::HexagonalLattice.is_3d());
+    }
+
     #[test]
     fn test_visualization_available_for() {
         let primes_viz = VisualizationType::available_for(SeriesType::Primes);
@@ -301,5 +495,13 @@ mod tests {
         let fib_viz = VisualizationType::available_for(SeriesType::Fibonacci);
         assert!(!fib_viz.contains(&VisualizationType::PrimeWheel));
         assert!(!fib_viz.contains(&VisualizationType::RiemannZeta));
+        assert!(!fib_viz.contains(&VisualizationType::CollatzGraph));
+
+        let collatz_viz = VisualizationType::available_for(SeriesType::Collatz);
+        assert!(collatz_viz.contains(&VisualizationType::CollatzGraph));
+        assert!(!primes_viz.contains(&VisualizationType::CollatzGraph));
+        assert_eq!(primes_viz.len(), VisualizationType::ALL.len() - 1);
+        assert!(!collatz_viz.contains(&VisualizationType::PrimeWheel));
+        assert_eq!(collatz_viz.len(), fib_viz.len() + 1);
     }
 }
@@ -0,0 +1,141 @@
+//! Collatz graph visualization
+
+use crate::app::NumberVisualizerApp;
+use crate::draw_number::draw_numbers;
+use crate::types::{SeriesType, VisualizationType};
+use crate::visualizations::params::VizParams;
+use crate::visualizations::traits::Visualizer;
+use crate::visualizations::ulam::{
+    compute_layout, find_hovered as find_hovered_ulam,
+    generate_positions as generate_ulam_positions,
+};
+use eframe::egui;
+use series::collatz_step;
+
+/// Alpha of the successor lines, so dense regions read as a tree rather
+/// than a solid block
+const EDGE_ALPHA: u8 = 90;
+
+/// (n, successor) for every n in 1..=max_n whose Collatz successor is also
+/// in range. 1 → 4 closes the 4 → 2 → 1 cycle, so it is kept too.
+pub fn successor_edges(max_n: usize) -> Vec<(usize, usize)> {
+    (1..=max_n)
+        .filter_map(|n| collatz_step(n).map(|next| (n, next)))
+        .filter(|&(_, next)| next <= max_n)
+        .collect()
+}
+
+/// Draw the Collatz graph.
+///
+/// Numbers sit on the Ulam spiral, with a line from each n to its Collatz
+/// successor. Halving steps use the non-highlight color and 3n+1 steps the
+/// highlight color, so odd numbers' jumps stand out from the paths merging
+/// down towards 1. Successors past the max number are not drawn.
+pub fn draw(
+    app: &NumberVisualizerApp,
+    ui: &mut egui::Ui,
+    rect: egui::Rect,
+    positions: &[(usize, f32, f32)],
+) {
+    if positions.is_empty() {
+        return;
+    }
+
+    let (center_x, center_y, scale, _) = compute_layout(positions, rect);
+    let to_screen = |x: f32, y: f32| egui::Pos2::new(center_x + x * scale, center_y + y * scale);
+    let painter = ui.painter();
+
+    let fade =
+        |c: egui::Color32| egui::Color32::from_rgba_unmultiplied(c.r(), c.g(), c.b(), EDGE_ALPHA);
+    let halving = egui::Stroke::new(1.0, fade(app.config.non_highlight_color));
+    let tripling = egui::Stroke::new(1.0, fade(app.config.highlight_color));
+
+    // Positions run 1..=max_n, so n sits at index n - 1
+    for (n, next) in successor_edges(positions.len()) {
+        let (_, x1, y1) = positions[n - 1];
+        let (_, x2, y2) = positions[next - 1];
+        let stroke = if n.is_multiple_of(2) {
+            halving
+        } else {
+            tripling
+        };
+        painter.line_segment([to_screen(x1, y1), to_screen(x2, y2)], stroke);
+    }
+
+    draw_numbers(
+        positions,
+        |x, y| (center_x + x * scale, center_y + y * scale),
+        painter,
+        app.highlights(),
+        &app.config,
+        app.series_type,
+    );
+}
+
+pub struct CollatzGraph;
+
+impl Visualizer for CollatzGraph {
+    fn viz_type(&self) -> VisualizationType {
+        VisualizationType::CollatzGraph
+    }
+
+    fn name(&self) -> &'static str {
+        "Collatz Graph"
+    }
+
+    fn description(&self) -> &'static str {
+        VisualizationType::CollatzGraph.description()
+    }
+
+    fn supports_series(&self, series: SeriesType) -> bool {
+        series == SeriesType::Collatz
+    }
+
+    fn supports_hover(&self) -> bool {
+        true
+    }
+
+    fn uses_point_rendering(&self) -> bool {
+        true
+    }
+
+    fn generate_positions(&self, max_n: usize, _params: &VizParams) -> Vec<(usize, f32, f32)> {
+        generate_ulam_positions(max_n, 1)
+    }
+
+    fn draw(
+        &self,
+        app: &mut NumberVisualizerApp,
+        ui: &mut egui::Ui,
+        rect: egui::Rect,
+        positions: &[(usize, f32, f32)],
+    ) {
+        draw(app, ui, rect, positions);
+    }
+
+    fn find_hovered(
+        &self,
+        app: &NumberVisualizerApp,
+        mouse_pos: egui::Pos2,
+        rect: egui::Rect,
+        positions: &[(usize, f32, f32)],
+    ) -> Option<usize> {
+        find_hovered_ulam(app, mouse_pos, rect, positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_successor_edges() {
+        // Even n halve, odd n go to 3n + 1; 3 → 10, 5 → 16, 7 → 22 leave the range
+        assert_eq!(
+            successor_edges(8),
+            vec![(1, 4), (2, 1), (4, 2), (6, 3), (8, 4)]
+        );
+        assert!(successor_edges(10).contains(&(3, 10)));
+        assert!(successor_edges(0).is_empty());
+    }
+}
@@ -122,7 +122,15 @@ pub fn draw(app: &mut crate::app::NumberVisualizerApp, ui: &mut egui::Ui, rect:
         let spike = if is_highlighted { spike_distance } else { 0.0 };
 
         let point = cube_face_point(face, u, v, spike);
-        let (px, py, pz) = project_3d_to_2d(&point, rotation_y, rotation_x);
+        let Some((px, py, pz)) = project_3d_to_2d(
+            &point,
+            rotation_y,
+            rotation_x,
+            app.config.perspective,
+            app.config.camera_distance,
+        ) else {
+            continue;
+        };
 
         projected.push((px, py, pz, n, is_highlighted));
     }
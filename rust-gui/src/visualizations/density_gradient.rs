@@ -1,18 +1,59 @@
 //! Prime density gradient visualization
 
 use crate::app::NumberVisualizerApp;
-use crate::config::VisualizerConfig;
+use crate::config::{DensityProjection, VisualizerConfig};
 use crate::helpers::MARGIN_SMALL;
 use crate::types::{SeriesType, VisualizationType};
 use crate::visualizations::params::VizParams;
 use crate::visualizations::traits::Visualizer;
 use eframe::egui;
+use series::reverse_digits;
 
 /// Minimum grid size for the density gradient heatmap.
 pub const GRID_SIZE_MIN: usize = 10;
 /// Maximum grid size for the density gradient heatmap.
 pub const GRID_SIZE_MAX: usize = 100;
 
+/// Smallest exponent k for the pᵏ mod max projection.
+pub const PROJECTION_POWER_MIN: u32 = 1;
+/// Largest exponent k for the pᵏ mod max projection.
+pub const PROJECTION_POWER_MAX: u32 = 4;
+
+/// pᵏ mod `modulus`, reducing after each multiplication so nothing overflows.
+fn pow_mod(p: usize, power: u32, modulus: usize) -> usize {
+    let modulus = modulus as u128;
+    let base = p as u128 % modulus;
+    (0..power).fold(1 % modulus, |acc, _| acc * base % modulus) as usize
+}
+
+/// Grid cell `(x, y)` for prime `p` on a `grid_size` × `grid_size` grid
+/// covering `0..max_number`, with the row chosen by `projection`. `power` is
+/// the exponent k of [`DensityProjection::PowerMod`].
+pub fn density_cell(
+    p: usize,
+    max_number: usize,
+    grid_size: usize,
+    projection: DensityProjection,
+    power: u32,
+) -> (usize, usize) {
+    let max_number = max_number.max(1);
+    let to_cell = |value: usize| {
+        let frac = value as f64 / max_number as f64;
+        ((frac * grid_size as f64) as usize).min(grid_size - 1)
+    };
+
+    let grid_x = to_cell(p);
+    let grid_y = match projection {
+        DensityProjection::PowerMod => to_cell(pow_mod(p, power, max_number)),
+        DensityProjection::ModGrid => p % grid_size,
+        DensityProjection::ReverseDigits => {
+            to_cell(reverse_digits(p, 10).unwrap_or(p) % max_number)
+        }
+    };
+
+    (grid_x, grid_y)
+}
+
 /// Draw the prime density gradient visualization.
 ///
 /// Renders a heatmap showing local prime density across the number space.
@@ -47,11 +88,13 @@ pub fn draw(app: &crate::app::NumberVisualizerApp, ui: &mut egui::Ui, rect: egui
     let mut density_grid = vec![0.0_f32; grid_size * grid_size];
 
     for &p in app.primes_vec() {
-        let x_frac = p as f32 / app.config.max_number as f32;
-        let y_frac = (p * p % app.config.max_number) as f32 / app.config.max_number as f32;
-
-        let grid_x = ((x_frac * grid_size as f32) as usize).min(grid_size - 1);
-        let grid_y = ((y_frac * grid_size as f32) as usize).min(grid_size - 1);
+        let (grid_x, grid_y) = density_cell(
+            p,
+            app.config.max_number,
+            grid_size,
+            app.config.density_projection,
+            app.config.density_projection_power,
+        );
 
         let idx = grid_y * grid_size + grid_x;
         density_grid[idx] += 1.0;
@@ -137,5 +180,109 @@ impl Visualizer for PrimeDensityGradient {
             egui::Slider::new(&mut config.grid_size, GRID_SIZE_MIN..=GRID_SIZE_MAX)
                 .text("Grid size"),
         );
+
+        ui.label("Y projection:");
+        egui::ComboBox::from_id_salt("density_projection")
+            .selected_text(format!("{}", config.density_projection))
+            .show_ui(ui, |ui| {
+                for projection in DensityProjection::ALL {
+                    ui.selectable_value(
+                        &mut config.density_projection,
+                        *projection,
+                        format!("{}", projection),
+                    );
+                }
+            });
+
+        if config.density_projection == DensityProjection::PowerMod {
+            ui.add(
+                egui::Slider::new(
+                    &mut config.density_projection_power,
+                    PROJECTION_POWER_MIN..=PROJECTION_POWER_MAX,
+                )
+                .text("Power k"),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primes::generate_primes;
+
+    #[test]
+    fn test_density_cell_in_bounds() {
+        for &max_number in &[10, 97, 1_000, 10_000] {
+            let primes = generate_primes(max_number, false, None, None, None).unwrap();
+            for &grid_size in &[GRID_SIZE_MIN, 37, GRID_SIZE_MAX] {
+                for &projection in DensityProjection::ALL {
+                    for &p in &primes {
+                        let (x, y) = density_cell(p, max_number, grid_size, projection, 2);
+                        assert!(
+                            x < grid_size && y < grid_size,
+                            "{:?} p={} max={} grid={} -> ({}, {})",
+                            projection,
+                            p,
+                            max_number,
+                            grid_size,
+                            x,
+                            y
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_density_cell_projections() {
+        // p = 13, max = 100, grid = 10: 13² mod 100 = 69, 13 mod 10 = 3, reversed 31
+        let cell = |projection| density_cell(13, 100, 10, projection, 2);
+        assert_eq!(cell(DensityProjection::PowerMod), (1, 6));
+        assert_eq!(cell(DensityProjection::ModGrid), (1, 3));
+        assert_eq!(cell(DensityProjection::ReverseDigits), (1, 3));
+    }
+
+    #[test]
+    fn test_projection_power_changes_distribution() {
+        let max_number = 1_000;
+        let grid_size = 20;
+        let primes = generate_primes(max_number, false, None, None, None).unwrap();
+        let rows = |power| -> Vec<usize> {
+            let mut counts = vec![0; grid_size];
+            for &p in &primes {
+                let (_, y) =
+                    density_cell(p, max_number, grid_size, DensityProjection::PowerMod, power);
+                counts[y] += 1;
+            }
+            counts
+        };
+
+        // k = 1 puts every prime on the diagonal
+        for &p in &primes {
+            let (x, y) = density_cell(p, max_number, grid_size, DensityProjection::PowerMod, 1);
+            assert_eq!(x, y, "p={}", p);
+        }
+        let distributions: Vec<Vec<usize>> = (PROJECTION_POWER_MIN..=PROJECTION_POWER_MAX)
+            .map(rows)
+            .collect();
+        for (i, a) in distributions.iter().enumerate() {
+            assert_eq!(a.iter().sum::<usize>(), primes.len());
+            for b in &distributions[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        assert_eq!(pow_mod(13, 2, 100), 69);
+        assert_eq!(pow_mod(13, 3, 100), 97);
+        assert_eq!(pow_mod(7, 1, 5), 2);
+        assert_eq!(pow_mod(usize::MAX, 4, 1_000_003), {
+            let r = (usize::MAX % 1_000_003) as u128;
+            (r * r % 1_000_003 * r % 1_000_003 * r % 1_000_003) as usize
+        });
     }
 }
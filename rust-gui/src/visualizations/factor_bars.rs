@@ -0,0 +1,145 @@
+//! Number line factor bars visualization
+
+use crate::app::NumberVisualizerApp;
+use crate::helpers::MARGIN_SMALL;
+use crate::types::{SeriesType, VisualizationType};
+use crate::visualizations::params::VizParams;
+use crate::visualizations::traits::Visualizer;
+use eframe::egui;
+use primes::distinct_prime_factors;
+
+/// ω(n), the number of distinct prime factors, for n = 1..=max_n.
+///
+/// Element i is ω(i + 1); ω(1) = 0 and every prime has height 1.
+pub fn bar_heights(max_n: usize) -> Vec<usize> {
+    (1..=max_n)
+        .map(|n| distinct_prime_factors(n).len())
+        .collect()
+}
+
+/// Draw the factor bars visualization.
+///
+/// The x-axis is n and each bar's height is ω(n). Primes are drawn in the
+/// highlight color, so they show up as the shortest non-empty bars; prime
+/// powers share their height but not their color. Bars are at least one
+/// pixel wide, so only the n that fit across the graph are shown.
+pub fn draw(app: &NumberVisualizerApp, ui: &mut egui::Ui, rect: egui::Rect) {
+    let graph_left = rect.left() + MARGIN_SMALL;
+    let graph_right = rect.right() - MARGIN_SMALL;
+    let graph_top = rect.top() + MARGIN_SMALL;
+    let graph_bottom = rect.bottom() - MARGIN_SMALL;
+    let graph_width = graph_right - graph_left;
+    let graph_height = graph_bottom - graph_top;
+
+    let count = app.config.max_number.min(graph_width.max(1.0) as usize);
+    if count == 0 {
+        return;
+    }
+
+    let heights = bar_heights(count);
+    let max_height = heights.iter().copied().max().unwrap_or(1).max(1) as f32;
+    let bar_width = graph_width / count as f32;
+
+    let painter = ui.painter();
+
+    painter.line_segment(
+        [
+            egui::Pos2::new(graph_left, graph_top),
+            egui::Pos2::new(graph_left, graph_bottom),
+        ],
+        egui::Stroke::new(2.0, egui::Color32::GRAY),
+    );
+    painter.line_segment(
+        [
+            egui::Pos2::new(graph_left, graph_bottom),
+            egui::Pos2::new(graph_right, graph_bottom),
+        ],
+        egui::Stroke::new(2.0, egui::Color32::GRAY),
+    );
+
+    for (i, &height) in heights.iter().enumerate() {
+        if height == 0 {
+            continue;
+        }
+        let n = i + 1;
+        let color = if app.contains(n) {
+            app.config.highlight_color
+        } else {
+            app.config.non_highlight_color
+        };
+
+        let x = graph_left + i as f32 * bar_width;
+        let top = graph_bottom - height as f32 / max_height * graph_height;
+        painter.rect_filled(
+            egui::Rect::from_min_max(
+                egui::Pos2::new(x, top),
+                egui::Pos2::new(x + bar_width.max(1.0), graph_bottom),
+            ),
+            0.0,
+            color,
+        );
+    }
+}
+
+pub struct NumberLineFactors;
+
+impl Visualizer for NumberLineFactors {
+    fn viz_type(&self) -> VisualizationType {
+        VisualizationType::NumberLineFactors
+    }
+
+    fn name(&self) -> &'static str {
+        "Number Line Factors"
+    }
+
+    fn description(&self) -> &'static str {
+        VisualizationType::NumberLineFactors.description()
+    }
+
+    fn supports_series(&self, series: SeriesType) -> bool {
+        series == SeriesType::Primes
+    }
+
+    fn supports_hover(&self) -> bool {
+        false
+    }
+
+    fn uses_point_rendering(&self) -> bool {
+        false
+    }
+
+    fn generate_positions(&self, _max_n: usize, _params: &VizParams) -> Vec<(usize, f32, f32)> {
+        Vec::new()
+    }
+
+    fn draw(
+        &self,
+        app: &mut NumberVisualizerApp,
+        ui: &mut egui::Ui,
+        rect: egui::Rect,
+        _positions: &[(usize, f32, f32)],
+    ) {
+        draw(app, ui, rect);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_heights_small_n() {
+        // n = 1..=12: 1 has no prime factors, 6, 10 and 12 have two
+        assert_eq!(bar_heights(12), vec![0, 1, 1, 1, 1, 2, 1, 1, 1, 2, 1, 2]);
+        assert_eq!(bar_heights(30)[29], 3);
+        assert!(bar_heights(0).is_empty());
+    }
+
+    #[test]
+    fn test_primes_have_height_one() {
+        let heights = bar_heights(100);
+        for p in [2, 3, 5, 7, 11, 97] {
+            assert_eq!(heights[p - 1], 1, "ω({})", p);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Fermat's spiral visualization
 
 use crate::app::NumberVisualizerApp;
-use crate::draw_number::draw_number;
+use crate::draw_number::draw_numbers;
 use crate::helpers::{
     find_hovered_center_flip_y, GOLDEN_ANGLE, HOVER_THRESHOLD_DEFAULT, MARGIN_SMALL,
 };
@@ -64,19 +64,14 @@ pub fn draw(
     let (center_x, center_y, scale) = compute_layout(positions, rect);
     let painter = ui.painter();
 
-    for (n, x, y) in positions {
-        let screen_x = center_x + *x * scale;
-        let screen_y = center_y - *y * scale;
-        draw_number(
-            *n,
-            screen_x,
-            screen_y,
-            painter,
-            app.highlights(),
-            &app.config,
-            app.series_type,
-        );
-    }
+    draw_numbers(
+        positions,
+        |x, y| (center_x + x * scale, center_y - y * scale),
+        painter,
+        app.highlights(),
+        &app.config,
+        app.series_type,
+    );
 }
 
 /// Find the number at the given mouse position.
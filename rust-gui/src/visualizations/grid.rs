@@ -1,7 +1,7 @@
 //! Grid visualization
 
 use crate::app::NumberVisualizerApp;
-use crate::draw_number::draw_number;
+use crate::draw_number::draw_numbers;
 use crate::helpers::{
     find_hovered_offset_based, LayoutData, HOVER_THRESHOLD_DEFAULT, MARGIN_SMALL,
 };
@@ -12,14 +12,20 @@ use eframe::egui;
 
 /// Generate positions for grid layout.
 ///
-/// Numbers are arranged in a square grid from left to right, top to bottom.
+/// Numbers `start..=max_n` are arranged in a square grid from left to right,
+/// top to bottom, so cell k holds `start + k`.
 /// Returns a vector of (number, x, y) tuples where x and y are column/row indices.
-pub fn generate_positions(max_n: usize) -> Vec<(usize, f32, f32)> {
-    let side = (max_n as f32).sqrt() as usize + 1;
-    (1..=max_n)
+pub fn generate_positions(max_n: usize, start: usize) -> Vec<(usize, f32, f32)> {
+    let start = start.max(1);
+    if max_n < start {
+        return Vec::new();
+    }
+
+    let side = ((max_n - start + 1) as f32).sqrt() as usize + 1;
+    (start..=max_n)
         .map(|n| {
-            let row = (n - 1) / side;
-            let col = (n - 1) % side;
+            let row = (n - start) / side;
+            let col = (n - start) % side;
             (n, col as f32, row as f32)
         })
         .collect()
@@ -27,15 +33,17 @@ pub fn generate_positions(max_n: usize) -> Vec<(usize, f32, f32)> {
 
 /// Compute layout for grid visualization.
 ///
+/// `count` is the number of cells laid out.
+///
 /// Returns: (start_x, start_y, scale)
 /// - start_x, start_y: Top-left corner of the grid
 /// - scale: Pixels per cell
 pub fn compute_layout(
     _positions: &[(usize, f32, f32)],
     rect: egui::Rect,
-    max_number: usize,
+    count: usize,
 ) -> (f32, f32, f32) {
-    let side = (max_number as f32).sqrt() as usize + 1;
+    let side = (count as f32).sqrt() as usize + 1;
     let available_width = rect.width() - 2.0 * MARGIN_SMALL;
     let available_height = rect.height() - 2.0 * MARGIN_SMALL;
 
@@ -65,29 +73,24 @@ pub fn draw(
         return;
     }
 
-    let (start_x, start_y, scale) = compute_layout(positions, rect, app.config.max_number);
+    let (start_x, start_y, scale) = compute_layout(positions, rect, positions.len());
     let painter = ui.painter();
 
-    for (n, x, y) in positions {
-        let screen_x = start_x + *x * scale;
-        let screen_y = start_y + *y * scale;
-        draw_number(
-            *n,
-            screen_x,
-            screen_y,
-            painter,
-            app.highlights(),
-            &app.config,
-            app.series_type,
-        );
-    }
+    draw_numbers(
+        positions,
+        |x, y| (start_x + x * scale, start_y + y * scale),
+        painter,
+        app.highlights(),
+        &app.config,
+        app.series_type,
+    );
 }
 
 /// Find the number at the given mouse position.
 ///
 /// Returns the closest number within the hover threshold, or None if no number is close enough.
 pub fn find_hovered(
-    app: &crate::app::NumberVisualizerApp,
+    _app: &crate::app::NumberVisualizerApp,
     mouse_pos: egui::Pos2,
     rect: egui::Rect,
     positions: &[(usize, f32, f32)],
@@ -96,7 +99,7 @@ pub fn find_hovered(
         return None;
     }
 
-    let layout: LayoutData = compute_layout(positions, rect, app.config.max_number);
+    let layout: LayoutData = compute_layout(positions, rect, positions.len());
     find_hovered_offset_based(mouse_pos, positions, layout, HOVER_THRESHOLD_DEFAULT)
 }
 
@@ -127,8 +130,8 @@ impl Visualizer for Grid {
         true
     }
 
-    fn generate_positions(&self, max_n: usize, _params: &VizParams) -> Vec<(usize, f32, f32)> {
-        generate_positions(max_n)
+    fn generate_positions(&self, max_n: usize, params: &VizParams) -> Vec<(usize, f32, f32)> {
+        generate_positions(max_n, params.display_start.unwrap_or(1))
     }
 
     fn draw(
@@ -158,22 +161,36 @@ mod tests {
 
     #[test]
     fn test_generate_positions_count() {
-        let positions = generate_positions(100);
+        let positions = generate_positions(100, 1);
         assert_eq!(positions.len(), 100);
     }
 
     #[test]
     fn test_generate_positions_values() {
-        let positions = generate_positions(9);
+        let positions = generate_positions(9, 1);
         assert_eq!(positions[0], (1, 0.0, 0.0));
         assert_eq!(positions[1], (2, 1.0, 0.0));
         assert_eq!(positions[2], (3, 2.0, 0.0));
         assert_eq!(positions[3], (4, 3.0, 0.0));
     }
 
+    #[test]
+    fn test_generate_positions_with_start() {
+        // Cell k holds 1000 + k, in the same cells 1 + k used from the start
+        let from_one = generate_positions(100, 1);
+        let offset = generate_positions(1099, 1000);
+        assert_eq!(offset.len(), 100);
+        assert_eq!(offset[0], (1000, 0.0, 0.0));
+        for (&(n, x, y), &(m, x1, y1)) in offset.iter().zip(&from_one) {
+            assert_eq!(n, m + 999);
+            assert_eq!((x, y), (x1, y1));
+        }
+        assert!(generate_positions(999, 1000).is_empty());
+    }
+
     #[test]
     fn test_compute_layout_positive_scale() {
-        let positions = generate_positions(100);
+        let positions = generate_positions(100, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (start_x, start_y, scale) = compute_layout(&positions, rect, 100);
@@ -186,7 +203,7 @@ mod tests {
     #[test]
     fn test_compute_layout_all_points_fit() {
         let max_n = 100;
-        let positions = generate_positions(max_n);
+        let positions = generate_positions(max_n, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (start_x, start_y, scale) = compute_layout(&positions, rect, max_n);
@@ -208,7 +225,7 @@ mod tests {
     #[test]
     fn test_find_hovered_at_first_point() {
         let max_n = 25;
-        let positions = generate_positions(max_n);
+        let positions = generate_positions(max_n, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let layout: LayoutData = compute_layout(&positions, rect, max_n);
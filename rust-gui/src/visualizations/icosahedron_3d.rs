@@ -62,7 +62,7 @@ fn icosahedron_faces() -> Vec<[usize; 3]> {
 /// Calculate a point on a triangular face using barycentric coordinates.
 ///
 /// Distributes points within a triangle defined by 3 vertices.
-fn point_on_triangle(
+pub(super) fn point_on_triangle(
     vertices: &[[f32; 3]],
     face: &[usize; 3],
     u: f32,
@@ -7,12 +7,14 @@
 //! - (f32, f32, f32, f32) - center_x, center_y, scale, extra: Polar/spiral with extra param
 //! - (f32, f32, f32, f32, f32) - center_x, center_y, scale, mid_x, mid_y: Bounding box centering
 
+pub mod collatz_graph;
 pub mod cone_3d;
 pub mod cube_quadratic_3d;
 pub mod cube_simple_3d;
 pub mod cylinder_3d;
 pub mod density_gradient;
 pub mod dodecahedron_3d;
+pub mod factor_bars;
 pub mod fermats;
 pub mod grid;
 pub mod helix_3d;
@@ -42,12 +44,14 @@ pub use params::VizParams;
 pub use registry::{VisualizationRegistry, REGISTRY};
 pub use traits::{Visualizer, Visualizer3D};
 
+pub use collatz_graph::{draw as draw_collatz_graph, CollatzGraph};
 pub use cone_3d::{draw as draw_cone_3d, Cone3D};
 pub use cube_quadratic_3d::{draw as draw_cube_quadratic_3d, CubeQuadratic3D};
 pub use cube_simple_3d::{draw as draw_cube_simple_3d, CubeSimple3D};
 pub use cylinder_3d::{draw as draw_cylinder_3d, Cylinder3D};
 pub use density_gradient::{draw as draw_density_gradient, PrimeDensityGradient};
 pub use dodecahedron_3d::{draw as draw_dodecahedron_3d, Dodecahedron3D};
+pub use factor_bars::{draw as draw_factor_bars, NumberLineFactors};
 pub use fermats::{
     draw as draw_fermats, find_hovered as find_hovered_fermats,
     generate_positions as generate_fermats_positions, FermatsSpiral,
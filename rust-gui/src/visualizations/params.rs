@@ -15,6 +15,12 @@ pub struct VizParams {
     pub grid_size: Option<usize>,
     /// Number of zeros to show for Riemann visualization
     pub num_zeros: Option<usize>,
+    /// Angle multiplier for Sacks spiral visualizations
+    pub sacks_theta: Option<f32>,
+    /// Starting value for the Ulam spiral
+    pub spiral_start: Option<usize>,
+    /// First number shown by the cell-based 2D layouts (grid, row, lattices)
+    pub display_start: Option<usize>,
     /// Custom parameters for future visualizations
     pub custom: HashMap<String, f32>,
 }
@@ -35,6 +41,21 @@ impl VizParams {
         self
     }
 
+    pub fn with_sacks_theta(mut self, theta: f32) -> Self {
+        self.sacks_theta = Some(theta);
+        self
+    }
+
+    pub fn with_spiral_start(mut self, start: usize) -> Self {
+        self.spiral_start = Some(start);
+        self
+    }
+
+    pub fn with_display_start(mut self, start: usize) -> Self {
+        self.display_start = Some(start);
+        self
+    }
+
     pub fn with_custom(mut self, key: impl Into<String>, value: f32) -> Self {
         self.custom.insert(key.into(), value);
         self
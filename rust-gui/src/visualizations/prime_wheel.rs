@@ -2,7 +2,7 @@
 
 use crate::app::NumberVisualizerApp;
 use crate::config::VisualizerConfig;
-use crate::draw_number::draw_number;
+use crate::draw_number::draw_numbers;
 use crate::helpers::{HOVER_THRESHOLD_DEFAULT, MARGIN_SMALL};
 use crate::types::{SeriesType, VisualizationType};
 use crate::visualizations::params::VizParams;
@@ -84,19 +84,14 @@ pub fn draw(
     let max_ring = (app.config.max_number / app.config.modulo) as f32 + 2.0;
     let painter = ui.painter();
 
-    for (n, x, y) in positions {
-        let screen_x = center_x + *x * scale;
-        let screen_y = center_y + *y * scale;
-        draw_number(
-            *n,
-            screen_x,
-            screen_y,
-            painter,
-            app.primes_set(),
-            &app.config,
-            SeriesType::Primes,
-        );
-    }
+    draw_numbers(
+        positions,
+        |x, y| (center_x + x * scale, center_y + y * scale),
+        painter,
+        app.primes_set().into(),
+        &app.config,
+        SeriesType::Primes,
+    );
 
     for spoke in 0..app.config.modulo {
         let theta = spoke as f32 * 2.0 * std::f32::consts::PI / modulo - std::f32::consts::PI / 2.0;
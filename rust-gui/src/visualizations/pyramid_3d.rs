@@ -12,7 +12,7 @@ use eframe::egui;
 /// Calculate a point on the pyramid surface.
 ///
 /// Distributes points across 4 triangular faces and the square base.
-fn point_on_pyramid_surface(seed: f32, u: f32, v: f32, spike: f32) -> Point3D {
+pub(super) fn point_on_pyramid_surface(seed: f32, u: f32, v: f32, spike: f32) -> Point3D {
     let half = shapes::PYRAMID_BASE / 2.0;
     let h = shapes::PYRAMID_HEIGHT / 2.0;
 
@@ -13,10 +13,10 @@ use crate::visualizations::params::VizParams;
 use crate::visualizations::traits::Visualizer;
 
 pub use crate::visualizations::{
-    Cone3D, CubeQuadratic3D, CubeSimple3D, Cylinder3D, Dodecahedron3D, FermatsSpiral, Grid,
-    Helix3D, HexagonalLattice, Icosahedron3D, Klein3D, Mobius3D, PrimeDensity,
-    PrimeDensityGradient, PrimeWheel, Pyramid3D, RiemannZeta, Row, SacksMobiusSpiral, SacksSpiral,
-    Sphere3D, Torus3D, Trefoil3D, TriangularLattice, UlamMobiusSpiral, UlamSpiral,
+    CollatzGraph, Cone3D, CubeQuadratic3D, CubeSimple3D, Cylinder3D, Dodecahedron3D, FermatsSpiral,
+    Grid, Helix3D, HexagonalLattice, Icosahedron3D, Klein3D, Mobius3D, NumberLineFactors,
+    PrimeDensity, PrimeDensityGradient, PrimeWheel, Pyramid3D, RiemannZeta, Row, SacksMobiusSpiral,
+    SacksSpiral, Sphere3D, Torus3D, Trefoil3D, TriangularLattice, UlamMobiusSpiral, UlamSpiral,
 };
 
 /// Registry for looking up visualizations by type
@@ -42,6 +42,8 @@ impl VisualizationRegistry {
         registry.register(SacksMobiusSpiral);
         registry.register(UlamMobiusSpiral);
         registry.register(PrimeDensityGradient);
+        registry.register(NumberLineFactors);
+        registry.register(CollatzGraph);
         registry.register(Sphere3D);
         registry.register(Torus3D);
         registry.register(Cone3D);
@@ -1,7 +1,7 @@
 //! Row visualization
 
 use crate::app::NumberVisualizerApp;
-use crate::draw_number::draw_number;
+use crate::draw_number::draw_numbers;
 use crate::helpers::{find_hovered_row, LayoutData, HOVER_THRESHOLD_DEFAULT, MARGIN_SMALL};
 use crate::types::{SeriesType, VisualizationType};
 use crate::visualizations::params::VizParams;
@@ -10,10 +10,14 @@ use eframe::egui;
 
 /// Generate positions for row layout.
 ///
-/// Numbers are arranged in a single horizontal line from left to right.
+/// Numbers `start..=max_n` are arranged in a single horizontal line from
+/// left to right, the k-th cell (from 1) holding `start + k - 1`.
 /// Returns a vector of (number, x, y) tuples where y is always 0.
-pub fn generate_positions(max_n: usize) -> Vec<(usize, f32, f32)> {
-    (1..=max_n).map(|n| (n, n as f32, 0.0)).collect()
+pub fn generate_positions(max_n: usize, start: usize) -> Vec<(usize, f32, f32)> {
+    let start = start.max(1);
+    (start..=max_n)
+        .map(|n| (n, (n - start + 1) as f32, 0.0))
+        .collect()
 }
 
 /// Compute layout for row visualization.
@@ -25,9 +29,9 @@ pub fn generate_positions(max_n: usize) -> Vec<(usize, f32, f32)> {
 pub fn compute_layout(
     _positions: &[(usize, f32, f32)],
     rect: egui::Rect,
-    max_number: usize,
+    count: usize,
 ) -> (f32, f32, f32) {
-    let max_x = max_number as f32;
+    let max_x = count as f32;
     let available_width = rect.width() - 2.0 * MARGIN_SMALL;
     let scale = available_width / max_x;
 
@@ -50,28 +54,24 @@ pub fn draw(
         return;
     }
 
-    let (start_x, center_y, scale) = compute_layout(positions, rect, app.config.max_number);
+    let (start_x, center_y, scale) = compute_layout(positions, rect, positions.len());
     let painter = ui.painter();
 
-    for (n, x, _) in positions {
-        let screen_x = start_x + *x * scale;
-        draw_number(
-            *n,
-            screen_x,
-            center_y,
-            painter,
-            app.highlights(),
-            &app.config,
-            app.series_type,
-        );
-    }
+    draw_numbers(
+        positions,
+        |x, _| (start_x + x * scale, center_y),
+        painter,
+        app.highlights(),
+        &app.config,
+        app.series_type,
+    );
 }
 
 /// Find the number at the given mouse position.
 ///
 /// Returns the closest number within the hover threshold, or None if no number is close enough.
 pub fn find_hovered(
-    app: &crate::app::NumberVisualizerApp,
+    _app: &crate::app::NumberVisualizerApp,
     mouse_pos: egui::Pos2,
     rect: egui::Rect,
     positions: &[(usize, f32, f32)],
@@ -80,7 +80,7 @@ pub fn find_hovered(
         return None;
     }
 
-    let layout: LayoutData = compute_layout(positions, rect, app.config.max_number);
+    let layout: LayoutData = compute_layout(positions, rect, positions.len());
     find_hovered_row(mouse_pos, positions, layout, HOVER_THRESHOLD_DEFAULT)
 }
 
@@ -111,8 +111,8 @@ impl Visualizer for Row {
         true
     }
 
-    fn generate_positions(&self, max_n: usize, _params: &VizParams) -> Vec<(usize, f32, f32)> {
-        generate_positions(max_n)
+    fn generate_positions(&self, max_n: usize, params: &VizParams) -> Vec<(usize, f32, f32)> {
+        generate_positions(max_n, params.display_start.unwrap_or(1))
     }
 
     fn draw(
@@ -140,15 +140,30 @@ impl Visualizer for Row {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_generate_positions_with_start() {
+        let positions = generate_positions(1004, 1000);
+        assert_eq!(
+            positions,
+            vec![
+                (1000, 1.0, 0.0),
+                (1001, 2.0, 0.0),
+                (1002, 3.0, 0.0),
+                (1003, 4.0, 0.0),
+                (1004, 5.0, 0.0)
+            ]
+        );
+    }
+
     #[test]
     fn test_generate_positions_count() {
-        let positions = generate_positions(50);
+        let positions = generate_positions(50, 1);
         assert_eq!(positions.len(), 50);
     }
 
     #[test]
     fn test_generate_positions_linear() {
-        let positions = generate_positions(5);
+        let positions = generate_positions(5, 1);
         assert_eq!(positions[0], (1, 1.0, 0.0));
         assert_eq!(positions[1], (2, 2.0, 0.0));
         assert_eq!(positions[2], (3, 3.0, 0.0));
@@ -158,7 +173,7 @@ mod tests {
 
     #[test]
     fn test_compute_layout_centered_vertically() {
-        let positions = generate_positions(10);
+        let positions = generate_positions(10, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 300.0));
         let (start_x, center_y, scale) = compute_layout(&positions, rect, 10);
@@ -171,7 +186,7 @@ mod tests {
     #[test]
     fn test_compute_layout_all_points_fit() {
         let max_n = 50;
-        let positions = generate_positions(max_n);
+        let positions = generate_positions(max_n, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 300.0));
         let (start_x, _, scale) = compute_layout(&positions, rect, max_n);
@@ -188,7 +203,7 @@ mod tests {
     #[test]
     fn test_find_hovered_at_first_point() {
         let max_n = 10;
-        let positions = generate_positions(max_n);
+        let positions = generate_positions(max_n, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 300.0));
         let layout: LayoutData = compute_layout(&positions, rect, max_n);
@@ -208,7 +223,7 @@ mod tests {
     #[test]
     fn test_find_hovered_miss() {
         let max_n = 10;
-        let positions = generate_positions(max_n);
+        let positions = generate_positions(max_n, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 300.0));
         let layout: LayoutData = compute_layout(&positions, rect, max_n);
@@ -1,7 +1,8 @@
 //! Sacks spiral visualization
 
 use crate::app::NumberVisualizerApp;
-use crate::draw_number::draw_number;
+use crate::config::VisualizerConfig;
+use crate::draw_number::draw_numbers;
 use crate::helpers::SACKS_THETA_MULTIPLIER;
 use crate::helpers::{find_hovered_center_based, HOVER_THRESHOLD_DEFAULT, MARGIN_SMALL};
 use crate::types::{SeriesType, VisualizationType};
@@ -9,16 +10,22 @@ use crate::visualizations::params::VizParams;
 use crate::visualizations::traits::Visualizer;
 use eframe::egui;
 
+/// Minimum angle multiplier for the Sacks spiral slider.
+pub const SACKS_THETA_MIN: f32 = 0.1;
+/// Maximum angle multiplier for the Sacks spiral slider.
+pub const SACKS_THETA_MAX: f32 = 2.0;
+
 /// Generate positions for Sacks spiral (Archimedean spiral).
 ///
-/// Each number n is placed at polar coordinates (r = sqrt(n), theta = n/2).
-/// This creates a spiral where primes tend to form curved lines.
-pub fn generate_positions(max_n: usize) -> Vec<(usize, f32, f32)> {
+/// Each number n is placed at polar coordinates (r = sqrt(n), theta = n * theta_multiplier).
+/// The default multiplier of 0.5 creates a spiral where primes tend to form curved lines;
+/// other multipliers reveal different ray patterns.
+pub fn generate_positions(max_n: usize, theta_multiplier: f32) -> Vec<(usize, f32, f32)> {
     (1..=max_n)
         .map(|n| {
             let n_f = n as f32;
             let r = n_f.sqrt();
-            let theta = n_f * SACKS_THETA_MULTIPLIER;
+            let theta = n_f * theta_multiplier;
             let x = r * theta.cos();
             let y = r * theta.sin();
             (n, x, y)
@@ -63,19 +70,14 @@ pub fn draw(
     let (center_x, center_y, scale) = compute_layout(positions, rect);
     let painter = ui.painter();
 
-    for (n, x, y) in positions {
-        let screen_x = center_x + *x * scale;
-        let screen_y = center_y + *y * scale;
-        draw_number(
-            *n,
-            screen_x,
-            screen_y,
-            painter,
-            app.highlights(),
-            &app.config,
-            app.series_type,
-        );
-    }
+    draw_numbers(
+        positions,
+        |x, y| (center_x + x * scale, center_y + y * scale),
+        painter,
+        app.highlights(),
+        &app.config,
+        app.series_type,
+    );
 }
 
 /// Find the number at the given mouse position.
@@ -122,8 +124,8 @@ impl Visualizer for SacksSpiral {
         true
     }
 
-    fn generate_positions(&self, max_n: usize, _params: &VizParams) -> Vec<(usize, f32, f32)> {
-        generate_positions(max_n)
+    fn generate_positions(&self, max_n: usize, params: &VizParams) -> Vec<(usize, f32, f32)> {
+        generate_positions(max_n, params.sacks_theta.unwrap_or(SACKS_THETA_MULTIPLIER))
     }
 
     fn draw(
@@ -145,6 +147,19 @@ impl Visualizer for SacksSpiral {
     ) -> Option<usize> {
         find_hovered(app, mouse_pos, rect, positions)
     }
+
+    fn config_ui(&self, ui: &mut egui::Ui, config: &mut VisualizerConfig, _series: SeriesType) {
+        sacks_theta_ui(ui, config);
+    }
+}
+
+/// Slider for the Sacks spiral angle multiplier, shared with the Sacks Mobius spiral.
+pub fn sacks_theta_ui(ui: &mut egui::Ui, config: &mut VisualizerConfig) {
+    ui.label("Sacks Spiral");
+    ui.add(
+        egui::Slider::new(&mut config.sacks_theta, SACKS_THETA_MIN..=SACKS_THETA_MAX)
+            .text("Angle multiplier"),
+    );
 }
 
 #[cfg(test)]
@@ -153,13 +168,13 @@ mod tests {
 
     #[test]
     fn test_generate_positions_count() {
-        let positions = generate_positions(100);
+        let positions = generate_positions(100, SACKS_THETA_MULTIPLIER);
         assert_eq!(positions.len(), 100);
     }
 
     #[test]
     fn test_generate_positions_monotonic_radius() {
-        let positions = generate_positions(100);
+        let positions = generate_positions(100, SACKS_THETA_MULTIPLIER);
         let mut prev_r: f32 = 0.0;
         for (_, x, y) in positions {
             let r = (x * x + y * y).sqrt();
@@ -170,13 +185,13 @@ mod tests {
 
     #[test]
     fn test_empty_positions() {
-        let positions = generate_positions(0);
+        let positions = generate_positions(0, SACKS_THETA_MULTIPLIER);
         assert!(positions.is_empty());
     }
 
     #[test]
     fn test_compute_layout_centering() {
-        let positions = generate_positions(50);
+        let positions = generate_positions(50, SACKS_THETA_MULTIPLIER);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (center_x, center_y, scale) = compute_layout(&positions, rect);
@@ -188,7 +203,7 @@ mod tests {
 
     #[test]
     fn test_compute_layout_scale_fits_rect() {
-        let positions = generate_positions(100);
+        let positions = generate_positions(100, SACKS_THETA_MULTIPLIER);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (center_x, center_y, scale) = compute_layout(&positions, rect);
@@ -209,7 +224,7 @@ mod tests {
 
     #[test]
     fn test_find_hovered_near_first_point() {
-        let positions = generate_positions(50);
+        let positions = generate_positions(50, SACKS_THETA_MULTIPLIER);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let layout = compute_layout(&positions, rect);
@@ -231,7 +246,7 @@ mod tests {
 
     #[test]
     fn test_find_hovered_miss() {
-        let positions = generate_positions(50);
+        let positions = generate_positions(50, SACKS_THETA_MULTIPLIER);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let layout = compute_layout(&positions, rect);
@@ -244,4 +259,20 @@ mod tests {
         );
         assert_eq!(hovered, None);
     }
+
+    #[test]
+    fn test_generate_positions_theta_changes_coordinates() {
+        let default = generate_positions(50, SACKS_THETA_MULTIPLIER);
+        let wider = generate_positions(50, 1.3);
+
+        // Radius depends only on n, so the same n lands at the same distance
+        // from the center but at a different angle.
+        let (n_a, xa, ya) = default[9];
+        let (n_b, xb, yb) = wider[9];
+        assert_eq!(n_a, n_b);
+        assert!((xa - xb).abs() > 0.01 || (ya - yb).abs() > 0.01);
+        let ra = (xa * xa + ya * ya).sqrt();
+        let rb = (xb * xb + yb * yb).sqrt();
+        assert!((ra - rb).abs() < 0.001);
+    }
 }
@@ -1,9 +1,10 @@
 //! Sacks Mobius spiral visualization
 
 use crate::app::NumberVisualizerApp;
+use crate::config::VisualizerConfig;
 use crate::draw_number::draw_number;
 use crate::helpers::{
-    gap_color, gap_stroke_width, MARGIN_SMALL, SACKS_MOBIUS_RADIUS_MULTIPLIER,
+    draw_gap_legend, gap_color, gap_stroke_width, MARGIN_SMALL, SACKS_MOBIUS_RADIUS_MULTIPLIER,
     SACKS_THETA_MULTIPLIER,
 };
 use crate::types::{SeriesType, VisualizationType};
@@ -20,6 +21,7 @@ pub fn draw(app: &crate::app::NumberVisualizerApp, ui: &mut egui::Ui, rect: egui
         return;
     }
 
+    let theta_multiplier = app.config.sacks_theta;
     let positions: Vec<(usize, f32, f32)> = app
         .primes_vec()
         .iter()
@@ -27,7 +29,7 @@ pub fn draw(app: &crate::app::NumberVisualizerApp, ui: &mut egui::Ui, rect: egui
         .map(|(idx, &n)| {
             let idx_f = idx as f32;
             let r = idx_f * SACKS_MOBIUS_RADIUS_MULTIPLIER;
-            let theta = idx_f * SACKS_THETA_MULTIPLIER;
+            let theta = idx_f * theta_multiplier;
             let x = r * theta.cos();
             let y = r * theta.sin();
             (n, x, y)
@@ -80,11 +82,15 @@ pub fn draw(app: &crate::app::NumberVisualizerApp, ui: &mut egui::Ui, rect: egui
             screen_x,
             screen_y,
             painter,
-            app.primes_set(),
+            app.primes_set().into(),
             &app.config,
             SeriesType::Primes,
         );
     }
+
+    if app.config.show_legend {
+        draw_gap_legend(painter, rect);
+    }
 }
 
 pub struct SacksMobiusSpiral;
@@ -114,8 +120,11 @@ impl Visualizer for SacksMobiusSpiral {
         true
     }
 
-    fn generate_positions(&self, max_n: usize, _params: &VizParams) -> Vec<(usize, f32, f32)> {
-        crate::visualizations::generate_sacks_positions(max_n)
+    fn generate_positions(&self, max_n: usize, params: &VizParams) -> Vec<(usize, f32, f32)> {
+        crate::visualizations::generate_sacks_positions(
+            max_n,
+            params.sacks_theta.unwrap_or(SACKS_THETA_MULTIPLIER),
+        )
     }
 
     fn draw(
@@ -127,4 +136,9 @@ impl Visualizer for SacksMobiusSpiral {
     ) {
         draw(app, ui, rect);
     }
+
+    fn config_ui(&self, ui: &mut egui::Ui, config: &mut VisualizerConfig, _series: SeriesType) {
+        crate::visualizations::sacks::sacks_theta_ui(ui, config);
+        ui.checkbox(&mut config.show_legend, "Show gap legend");
+    }
 }
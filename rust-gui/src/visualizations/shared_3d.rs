@@ -22,10 +22,21 @@ impl Point3D {
 /// Project a 3D point to 2D screen coordinates with perspective.
 ///
 /// Applies Y-axis rotation followed by X-axis rotation, then perspective projection.
+/// A larger `perspective` flattens the foreshortening; a larger
+/// `camera_distance` pulls the camera back so bulging shapes stay in front
+/// of it instead of clipping.
 ///
 /// # Returns
-/// A tuple of (screen_x, screen_y, depth) where depth is used for sorting.
-pub fn project_3d_to_2d(point: &Point3D, rotation_y: f32, rotation_x: f32) -> (f32, f32, f32) {
+/// A tuple of (screen_x, screen_y, depth) where depth is used for sorting,
+/// or `None` for a point closer than [`NEAR_PLANE`] to the camera or behind
+/// it. Such points would be blown up or mirrored, so callers skip them.
+pub fn project_3d_to_2d(
+    point: &Point3D,
+    rotation_y: f32,
+    rotation_x: f32,
+    perspective: f32,
+    camera_distance: f32,
+) -> Option<(f32, f32, f32)> {
     let cos_y = rotation_y.cos();
     let sin_y = rotation_y.sin();
     let x1 = point.x * cos_y - point.z * sin_y;
@@ -37,9 +48,13 @@ pub fn project_3d_to_2d(point: &Point3D, rotation_y: f32, rotation_x: f32) -> (f
     let y2 = y1 * cos_x - z1 * sin_x;
     let z2 = y1 * sin_x + z1 * cos_x;
 
-    let scale = projection::PERSPECTIVE / (projection::PERSPECTIVE - z2 + projection::OFFSET);
+    let distance = perspective - z2 + camera_distance;
+    if distance < NEAR_PLANE {
+        return None;
+    }
+    let scale = perspective / distance;
 
-    (x1 * scale, y2 * scale, z2)
+    Some((x1 * scale, y2 * scale, z2))
 }
 
 /// Adjust the brightness of a color by a multiplicative factor.
@@ -108,7 +123,15 @@ pub fn draw_3d_scene(
     for n in 1..=max_n {
         let is_highlighted = highlights.contains(&n);
         let point = generate_point(n, is_highlighted);
-        let (px, py, pz) = project_3d_to_2d(&point, rotation_y, rotation_x);
+        let Some((px, py, pz)) = project_3d_to_2d(
+            &point,
+            rotation_y,
+            rotation_x,
+            app.config.perspective,
+            app.config.camera_distance,
+        ) else {
+            continue;
+        };
         projected.push((px, py, pz, n, is_highlighted));
     }
 
@@ -155,12 +178,71 @@ pub fn draw_3d_scene(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_larger_perspective_less_foreshortening() {
+        // The same x at two depths projects closer together when the
+        // perspective is larger
+        let spread = |perspective: f32| {
+            let (near, _, _) = project_3d_to_2d(
+                &Point3D::new(10.0, 0.0, 100.0),
+                0.0,
+                0.0,
+                perspective,
+                OFFSET,
+            )
+            .unwrap();
+            let (far, _, _) = project_3d_to_2d(
+                &Point3D::new(10.0, 0.0, -100.0),
+                0.0,
+                0.0,
+                perspective,
+                OFFSET,
+            )
+            .unwrap();
+            near - far
+        };
+        assert!(spread(500.0) > 0.0);
+        assert!(spread(2000.0) < spread(500.0));
+    }
+
+    #[test]
+    fn test_larger_camera_distance_shrinks_projection() {
+        let point = Point3D::new(10.0, 0.0, 0.0);
+        let (close, _, _) = project_3d_to_2d(&point, 0.0, 0.0, PERSPECTIVE, OFFSET).unwrap();
+        let (far, _, _) = project_3d_to_2d(&point, 0.0, 0.0, PERSPECTIVE, OFFSET * 2.0).unwrap();
+        assert!(far < close);
+    }
+
+    #[test]
+    fn test_closest_slider_settings_cull_points_behind_camera() {
+        use crate::constants::limits::PERSPECTIVE_MIN;
+
+        // Perspective at its minimum and the camera pulled all the way in
+        // put the camera at z = 100; a shape's near side passes it
+        let project =
+            |z: f32| project_3d_to_2d(&Point3D::new(10.0, 0.0, z), 0.0, 0.0, PERSPECTIVE_MIN, 0.0);
+        for z in [
+            PERSPECTIVE_MIN,
+            PERSPECTIVE_MIN + 50.0,
+            PERSPECTIVE_MIN - 5.0,
+        ] {
+            assert_eq!(project(z), None, "z={}", z);
+        }
+
+        // Points in front keep their side and a bounded size
+        for z in [PERSPECTIVE_MIN - NEAR_PLANE, 0.0, -150.0] {
+            let (x, _, _) = project(z).unwrap();
+            assert!(x > 0.0 && x.is_finite(), "z={} x={}", z, x);
+            assert!(x <= 10.0 * PERSPECTIVE_MIN / NEAR_PLANE, "z={} x={}", z, x);
+        }
+    }
+
     #[test]
     fn test_project_identity_rotation() {
         // With zero rotation, x should pass through unchanged, y should pass through,
         // and z should remain as-is for depth.
         let point = Point3D::new(10.0, 20.0, 0.0);
-        let (px, py, pz) = project_3d_to_2d(&point, 0.0, 0.0);
+        let (px, py, pz) = project_3d_to_2d(&point, 0.0, 0.0, PERSPECTIVE, OFFSET).unwrap();
 
         // With z=0 and zero rotation, z stays 0, perspective scale = PERSPECTIVE / (PERSPECTIVE + 0 + OFFSET)
         let expected_scale =
@@ -174,7 +256,8 @@ mod tests {
     fn test_project_y_rotation_180() {
         // Rotating 180 degrees around Y should flip x and z
         let point = Point3D::new(10.0, 0.0, 0.0);
-        let (px, _, _) = project_3d_to_2d(&point, std::f32::consts::PI, 0.0);
+        let (px, _, _) =
+            project_3d_to_2d(&point, std::f32::consts::PI, 0.0, PERSPECTIVE, OFFSET).unwrap();
 
         // After 180-degree Y rotation, x should be negated (approximately)
         assert!(px < 0.0, "x should be negative after 180-degree Y rotation");
@@ -186,8 +269,8 @@ mod tests {
         let front = Point3D::new(0.0, 0.0, -50.0);
         let back = Point3D::new(0.0, 0.0, 50.0);
 
-        let (_, _, depth_front) = project_3d_to_2d(&front, 0.0, 0.0);
-        let (_, _, depth_back) = project_3d_to_2d(&back, 0.0, 0.0);
+        let (_, _, depth_front) = project_3d_to_2d(&front, 0.0, 0.0, PERSPECTIVE, OFFSET).unwrap();
+        let (_, _, depth_back) = project_3d_to_2d(&back, 0.0, 0.0, PERSPECTIVE, OFFSET).unwrap();
 
         assert!(
             depth_back > depth_front,
@@ -197,12 +280,12 @@ mod tests {
 
     #[test]
     fn test_project_perspective_scaling() {
-        // Closer objects (negative z) should appear larger (higher scale factor)
-        let close = Point3D::new(10.0, 0.0, -50.0);
-        let far = Point3D::new(10.0, 0.0, 50.0);
+        // Closer objects (positive z, drawn last by the depth sort) should appear larger
+        let close = Point3D::new(10.0, 0.0, 50.0);
+        let far = Point3D::new(10.0, 0.0, -50.0);
 
-        let (px_close, _, _) = project_3d_to_2d(&close, 0.0, 0.0);
-        let (px_far, _, _) = project_3d_to_2d(&far, 0.0, 0.0);
+        let (px_close, _, _) = project_3d_to_2d(&close, 0.0, 0.0, PERSPECTIVE, OFFSET).unwrap();
+        let (px_far, _, _) = project_3d_to_2d(&far, 0.0, 0.0, PERSPECTIVE, OFFSET).unwrap();
 
         assert!(
             px_close.abs() > px_far.abs(),
@@ -278,4 +361,45 @@ mod tests {
         assert!(df2 >= df1, "depth_factor should increase with depth");
         assert!(df3 >= df2, "depth_factor should increase with depth");
     }
+
+    #[test]
+    fn test_project_identical_across_shapes() {
+        // Pyramid and icosahedron both build shared Point3D values, so the same
+        // surface position must project identically through project_3d_to_2d.
+        use crate::constants::shapes;
+        use crate::visualizations::{icosahedron_3d, pyramid_3d};
+
+        // Seed 0.9 selects the pyramid base; u = v = 0.5 is its center.
+        let from_pyramid = pyramid_3d::point_on_pyramid_surface(0.9, 0.5, 0.5, 0.0);
+
+        let vertex = [
+            from_pyramid.x / shapes::ICOSAHEDRON_SCALE,
+            from_pyramid.y / shapes::ICOSAHEDRON_SCALE,
+            from_pyramid.z / shapes::ICOSAHEDRON_SCALE,
+        ];
+        let from_icosahedron =
+            icosahedron_3d::point_on_triangle(&[vertex; 3], &[0, 1, 2], 0.3, 0.3, 0.0);
+
+        for (rotation_y, rotation_x) in [(0.0, 0.0), (0.5, 0.3), (-1.2, 1.0)] {
+            let a = project_3d_to_2d(&from_pyramid, rotation_y, rotation_x, PERSPECTIVE, OFFSET)
+                .unwrap();
+            let b = project_3d_to_2d(
+                &from_icosahedron,
+                rotation_y,
+                rotation_x,
+                PERSPECTIVE,
+                OFFSET,
+            )
+            .unwrap();
+            assert!((a.0 - b.0).abs() < 1e-3, "x differs: {:?} vs {:?}", a, b);
+            assert!((a.1 - b.1).abs() < 1e-3, "y differs: {:?} vs {:?}", a, b);
+            assert!(
+                (a.2 - b.2).abs() < 1e-3,
+                "depth differs: {:?} vs {:?}",
+                a,
+                b
+            );
+            assert!((depth_factor(a.2) - depth_factor(b.2)).abs() < 1e-6);
+        }
+    }
 }
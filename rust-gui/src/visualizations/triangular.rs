@@ -1,9 +1,9 @@
 //! Triangular lattice visualization
 
 use crate::app::NumberVisualizerApp;
-use crate::draw_number::draw_number;
+use crate::draw_number::draw_numbers;
 use crate::helpers::{
-    calculate_bounds, calculate_scale, find_hovered_centered, LayoutDataCentered,
+    axial_to_pixel, calculate_bounds, calculate_scale, find_hovered_centered, LayoutDataCentered,
     HOVER_THRESHOLD_LARGE, MARGIN_SMALL,
 };
 use crate::types::{SeriesType, VisualizationType};
@@ -13,34 +13,38 @@ use eframe::egui;
 
 /// Generate positions for triangular lattice.
 ///
-/// Numbers spiral outward from the center in a triangular pattern (3 directions).
+/// Numbers `start..=max_n` spiral outward from the center in a triangular
+/// pattern (3 directions), so cell k of the spiral holds `start + k`.
 /// Returns a vector of (number, x, y) tuples where (0,0) is the center.
-pub fn generate_positions(max_n: usize) -> Vec<(usize, f32, f32)> {
-    let mut positions = Vec::with_capacity(max_n);
-
-    if max_n == 0 {
-        return positions;
+pub fn generate_positions(max_n: usize, start: usize) -> Vec<(usize, f32, f32)> {
+    let start = start.max(1);
+    if max_n < start {
+        return Vec::new();
     }
 
-    let mut x = 0i32;
-    let mut y = 0i32;
+    let mut positions = Vec::with_capacity(max_n - start + 1);
+
+    let mut q = 0i32;
+    let mut r = 0i32;
 
-    let tri_directions: [(i32, i32); 3] = [(2, 0), (-1, 2), (-1, -2)];
+    // Axial (q, r) steps to three of the six neighbouring cells
+    let tri_directions: [(i32, i32); 3] = [(1, 0), (-1, 1), (0, -1)];
 
     let mut steps_in_direction = 1;
     let mut steps_since_turn = 0;
     let mut turn_count = 0;
     let mut dir_idx = 0;
 
-    for n in 1..=max_n {
-        positions.push((n, x as f32, y as f32));
+    for n in start..=max_n {
+        let (x, y) = axial_to_pixel(q, r);
+        positions.push((n, x, y));
 
         if n == max_n {
             break;
         }
 
-        x += tri_directions[dir_idx].0;
-        y += tri_directions[dir_idx].1;
+        q += tri_directions[dir_idx].0;
+        r += tri_directions[dir_idx].1;
         steps_since_turn += 1;
 
         if steps_since_turn == steps_in_direction {
@@ -96,19 +100,19 @@ pub fn draw(
     let (center_x, center_y, scale, mid_x, mid_y) = compute_layout(positions, rect);
     let painter = ui.painter();
 
-    for (n, x, y) in positions {
-        let screen_x = center_x + (*x - mid_x) * scale;
-        let screen_y = center_y - (*y - mid_y) * scale;
-        draw_number(
-            *n,
-            screen_x,
-            screen_y,
-            painter,
-            app.highlights(),
-            &app.config,
-            app.series_type,
-        );
-    }
+    draw_numbers(
+        positions,
+        |x, y| {
+            (
+                center_x + (x - mid_x) * scale,
+                center_y - (y - mid_y) * scale,
+            )
+        },
+        painter,
+        app.highlights(),
+        &app.config,
+        app.series_type,
+    );
 }
 
 /// Find the number at the given mouse position.
@@ -155,8 +159,8 @@ impl Visualizer for TriangularLattice {
         true
     }
 
-    fn generate_positions(&self, max_n: usize, _params: &VizParams) -> Vec<(usize, f32, f32)> {
-        generate_positions(max_n)
+    fn generate_positions(&self, max_n: usize, params: &VizParams) -> Vec<(usize, f32, f32)> {
+        generate_positions(max_n, params.display_start.unwrap_or(1))
     }
 
     fn draw(
@@ -184,27 +188,55 @@ impl Visualizer for TriangularLattice {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_generate_positions_with_start() {
+        let from_one = generate_positions(50, 1);
+        let offset = generate_positions(1049, 1000);
+        assert_eq!(offset.len(), from_one.len());
+        for (&(n, x, y), &(m, x1, y1)) in offset.iter().zip(&from_one) {
+            assert_eq!(n, m + 999);
+            assert_eq!((x, y), (x1, y1));
+        }
+    }
+
     #[test]
     fn test_generate_positions_count() {
-        let positions = generate_positions(50);
+        let positions = generate_positions(50, 1);
         assert_eq!(positions.len(), 50);
     }
 
     #[test]
     fn test_generate_positions_start_at_origin() {
-        let positions = generate_positions(1);
+        let positions = generate_positions(1, 1);
         assert_eq!(positions[0], (1, 0.0, 0.0));
     }
 
+    #[test]
+    fn test_consecutive_cells_equidistant() {
+        // Each spiral step moves to a neighbouring cell, √3 away on the lattice
+        let positions = generate_positions(200, 1);
+        for pair in positions.windows(2) {
+            let (_, x0, y0) = pair[0];
+            let (n, x1, y1) = pair[1];
+            let distance = ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt();
+            assert!(
+                (distance - 3f32.sqrt()).abs() < 1e-4,
+                "step to {} has length {}",
+                n,
+                distance
+            );
+        }
+    }
+
     #[test]
     fn test_empty_positions() {
-        let positions = generate_positions(0);
+        let positions = generate_positions(0, 1);
         assert!(positions.is_empty());
     }
 
     #[test]
     fn test_compute_layout_centering() {
-        let positions = generate_positions(50);
+        let positions = generate_positions(50, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (center_x, center_y, scale, _, _) = compute_layout(&positions, rect);
@@ -216,7 +248,7 @@ mod tests {
 
     #[test]
     fn test_compute_layout_all_points_fit() {
-        let positions = generate_positions(100);
+        let positions = generate_positions(100, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (center_x, center_y, scale, mid_x, mid_y) = compute_layout(&positions, rect);
@@ -237,7 +269,7 @@ mod tests {
 
     #[test]
     fn test_find_hovered_at_first_point() {
-        let positions = generate_positions(50);
+        let positions = generate_positions(50, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let layout: LayoutDataCentered = compute_layout(&positions, rect);
@@ -258,7 +290,7 @@ mod tests {
 
     #[test]
     fn test_find_hovered_miss() {
-        let positions = generate_positions(50);
+        let positions = generate_positions(50, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let layout: LayoutDataCentered = compute_layout(&positions, rect);
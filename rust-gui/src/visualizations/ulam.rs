@@ -2,24 +2,32 @@
 
 use crate::app::NumberVisualizerApp;
 use crate::config::VisualizerConfig;
-use crate::draw_number::draw_number;
+use crate::draw_number::draw_numbers;
 use crate::helpers::{find_hovered_center_based, HOVER_THRESHOLD_DEFAULT, MARGIN_SMALL};
 use crate::types::{SeriesType, VisualizationType};
 use crate::visualizations::params::VizParams;
 use crate::visualizations::traits::Visualizer;
 use eframe::egui;
 
+/// Minimum starting value for the Ulam spiral.
+pub const SPIRAL_START_MIN: usize = 1;
+/// Maximum starting value for the Ulam spiral.
+pub const SPIRAL_START_MAX: usize = 1000;
+
 /// Generate positions for Ulam spiral (square spiral).
 ///
-/// Numbers spiral outward from the center in a square pattern.
+/// Numbers `start..=max_n` spiral outward from the center in a square
+/// pattern, so grid cell k holds `start + k`. Ulam's own start is 1; other
+/// starts shift the diagonals (41 puts n² + n + 41 on the main diagonal).
 /// Returns a vector of (number, x, y) tuples where (0,0) is the center.
-pub fn generate_positions(max_n: usize) -> Vec<(usize, f32, f32)> {
-    let mut positions = Vec::with_capacity(max_n);
-
-    if max_n == 0 {
-        return positions;
+pub fn generate_positions(max_n: usize, start: usize) -> Vec<(usize, f32, f32)> {
+    let start = start.max(1);
+    if max_n < start {
+        return Vec::new();
     }
 
+    let mut positions = Vec::with_capacity(max_n - start + 1);
+
     let mut x = 0i32;
     let mut y = 0i32;
     let mut dx = 1i32;
@@ -28,7 +36,7 @@ pub fn generate_positions(max_n: usize) -> Vec<(usize, f32, f32)> {
     let mut steps_since_turn = 0;
     let mut turn_count = 0;
 
-    for n in 1..=max_n {
+    for n in start..=max_n {
         positions.push((n, x as f32, y as f32));
 
         if n == max_n {
@@ -102,19 +110,14 @@ pub fn draw(
     let (center_x, center_y, scale, _) = compute_layout(positions, rect);
     let painter = ui.painter();
 
-    for (n, x, y) in positions {
-        let screen_x = center_x + *x * scale;
-        let screen_y = center_y + *y * scale;
-        draw_number(
-            *n,
-            screen_x,
-            screen_y,
-            painter,
-            app.highlights(),
-            &app.config,
-            app.series_type,
-        );
-    }
+    draw_numbers(
+        positions,
+        |x, y| (center_x + x * scale, center_y + y * scale),
+        painter,
+        app.highlights(),
+        &app.config,
+        app.series_type,
+    );
 }
 
 /// Find the number at the given mouse position.
@@ -166,8 +169,8 @@ impl Visualizer for UlamSpiral {
         true
     }
 
-    fn generate_positions(&self, max_n: usize, _params: &VizParams) -> Vec<(usize, f32, f32)> {
-        generate_positions(max_n)
+    fn generate_positions(&self, max_n: usize, params: &VizParams) -> Vec<(usize, f32, f32)> {
+        generate_positions(max_n, params.spiral_start.unwrap_or(SPIRAL_START_MIN))
     }
 
     fn draw(
@@ -189,6 +192,17 @@ impl Visualizer for UlamSpiral {
     ) -> Option<usize> {
         find_hovered(app, mouse_pos, rect, positions)
     }
+
+    fn config_ui(&self, ui: &mut egui::Ui, config: &mut VisualizerConfig, _series: SeriesType) {
+        ui.label("Ulam Spiral");
+        ui.add(
+            egui::Slider::new(
+                &mut config.spiral_start,
+                SPIRAL_START_MIN..=SPIRAL_START_MAX,
+            )
+            .text("Start value"),
+        );
+    }
 }
 
 #[cfg(test)]
@@ -197,32 +211,64 @@ mod tests {
 
     #[test]
     fn test_generate_positions_count() {
-        let positions = generate_positions(10);
+        let positions = generate_positions(10, 1);
         assert_eq!(positions.len(), 10);
     }
 
     #[test]
     fn test_generate_positions_start_at_center() {
-        let positions = generate_positions(1);
+        let positions = generate_positions(1, 1);
         assert_eq!(positions[0], (1, 0.0, 0.0));
     }
 
     #[test]
     fn test_generate_positions_spiral_outward() {
-        let positions = generate_positions(9);
+        let positions = generate_positions(9, 1);
         assert_eq!(positions[0], (1, 0.0, 0.0));
         assert_eq!(positions[8].0, 9);
     }
 
     #[test]
     fn test_empty_positions() {
-        let positions = generate_positions(0);
+        let positions = generate_positions(0, 1);
         assert!(positions.is_empty());
+        assert!(generate_positions(40, 41).is_empty());
+    }
+
+    #[test]
+    fn test_generate_positions_custom_start() {
+        let from_one = generate_positions(30, 1);
+        let from_five = generate_positions(34, 5);
+        assert_eq!(from_five.len(), 30);
+        for (a, b) in from_one.iter().zip(&from_five) {
+            assert_eq!(b.0, a.0 + 4);
+            assert_eq!((a.1, a.2), (b.1, b.2));
+        }
+        // A start of 0 is treated as 1
+        assert_eq!(generate_positions(10, 0), generate_positions(10, 1));
+    }
+
+    #[test]
+    fn test_start_41_euler_diagonal() {
+        // With start 41 the main diagonal holds n² + n + 41 for n = 0, 1, 2, ...,
+        // which is prime for n < 40.
+        let positions = generate_positions(41 + 1599, 41);
+        let diagonal: Vec<usize> = positions
+            .iter()
+            .filter(|(_, x, y)| x == y)
+            .map(|&(n, _, _)| n)
+            .collect();
+        assert_eq!(diagonal.len(), 40);
+        for n in 0..40 {
+            let value = n * n + n + 41;
+            assert!(diagonal.contains(&value), "{} not on the diagonal", value);
+            assert!(primes::is_prime(value), "{} should be prime", value);
+        }
     }
 
     #[test]
     fn test_compute_layout_centering() {
-        let positions = generate_positions(25);
+        let positions = generate_positions(25, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (center_x, center_y, scale, max_coord) = compute_layout(&positions, rect);
@@ -238,7 +284,7 @@ mod tests {
 
     #[test]
     fn test_compute_layout_scale_fits_rect() {
-        let positions = generate_positions(100);
+        let positions = generate_positions(100, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (center_x, center_y, scale, _) = compute_layout(&positions, rect);
@@ -264,7 +310,7 @@ mod tests {
 
     #[test]
     fn test_compute_layout_single_point() {
-        let positions = generate_positions(1);
+        let positions = generate_positions(1, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (_, _, scale, max_coord) = compute_layout(&positions, rect);
@@ -276,7 +322,7 @@ mod tests {
 
     #[test]
     fn test_find_hovered_at_center() {
-        let positions = generate_positions(25);
+        let positions = generate_positions(25, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
         let (center_x, center_y, _, _) = compute_layout(&positions, rect);
@@ -294,7 +340,7 @@ mod tests {
 
     #[test]
     fn test_find_hovered_miss() {
-        let positions = generate_positions(10);
+        let positions = generate_positions(10, 1);
         let rect =
             egui::Rect::from_min_size(egui::Pos2::new(0.0, 0.0), egui::Vec2::new(400.0, 400.0));
 
@@ -312,7 +358,7 @@ mod tests {
 
     #[test]
     fn test_find_hovered_empty() {
-        let positions = generate_positions(0);
+        let positions = generate_positions(0, 1);
         let mouse = egui::Pos2::new(200.0, 200.0);
         let hovered = find_hovered_center_based(
             mouse,
@@ -1,9 +1,10 @@
 //! Ulam Mobius spiral visualization
 
 use crate::app::NumberVisualizerApp;
+use crate::config::VisualizerConfig;
 use crate::draw_number::draw_number;
 use crate::helpers::{
-    calculate_bounds, calculate_scale, gap_color, gap_stroke_width, MARGIN_SMALL,
+    calculate_bounds, calculate_scale, draw_gap_legend, gap_color, gap_stroke_width, MARGIN_SMALL,
 };
 use crate::types::{SeriesType, VisualizationType};
 use crate::visualizations::params::VizParams;
@@ -20,7 +21,7 @@ pub fn draw(app: &crate::app::NumberVisualizerApp, ui: &mut egui::Ui, rect: egui
         return;
     }
 
-    let spiral_positions = generate_ulam_positions(app.primes_vec().len());
+    let spiral_positions = generate_ulam_positions(app.primes_vec().len(), 1);
     let positions: Vec<(usize, f32, f32)> = app
         .primes_vec()
         .iter()
@@ -71,11 +72,15 @@ pub fn draw(app: &crate::app::NumberVisualizerApp, ui: &mut egui::Ui, rect: egui
             screen_x,
             screen_y,
             painter,
-            app.primes_set(),
+            app.primes_set().into(),
             &app.config,
             SeriesType::Primes,
         );
     }
+
+    if app.config.show_legend {
+        draw_gap_legend(painter, rect);
+    }
 }
 
 pub struct UlamMobiusSpiral;
@@ -106,7 +111,7 @@ impl Visualizer for UlamMobiusSpiral {
     }
 
     fn generate_positions(&self, max_n: usize, _params: &VizParams) -> Vec<(usize, f32, f32)> {
-        generate_ulam_positions(max_n)
+        generate_ulam_positions(max_n, 1)
     }
 
     fn draw(
@@ -118,4 +123,9 @@ impl Visualizer for UlamMobiusSpiral {
     ) {
         draw(app, ui, rect);
     }
+
+    fn config_ui(&self, ui: &mut egui::Ui, config: &mut VisualizerConfig, _series: SeriesType) {
+        ui.label("Ulam Mobius Spiral");
+        ui.checkbox(&mut config.show_legend, "Show gap legend");
+    }
 }
@@ -4,7 +4,7 @@
 //! The number of bases required depends on the size of the number being tested:
 //!
 //! - For n < 3,474,749,660,399: 12 bases are sufficient for deterministic results
-//! - For larger n: 19 bases provide deterministic coverage up to ~3.3x10^25
+//! - For larger n: 19 bases provide deterministic coverage up to ~3.3x10^24
 //!
 //! These bounds are derived from published research on deterministic Miller-Rabin testing.
 
@@ -14,6 +14,10 @@ use num_bigint::BigUint;
 /// Above this threshold, 19 bases are used.
 pub const SMALL_NUMBER_THRESHOLD: u64 = 3_474_749_660_399;
 
+/// Numbers below this threshold (~3.3x10^24) are proven prime by passing all
+/// 19 extended bases. Above it, a pass only means "probably prime".
+pub const DETERMINISTIC_THRESHOLD: u128 = 3_317_044_064_679_887_385_961_981;
+
 /// Test bases sufficient for deterministic results when n < SMALL_NUMBER_THRESHOLD
 const BASES_SMALL: &[u64] = &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
 
@@ -0,0 +1,281 @@
+//! Verifiable primality results
+//!
+//! Instead of a bare bool, a [`PrimalityCertificate`] records how the verdict
+//! was reached: the bases that all passed, the base that proved n composite,
+//! or the number of rounds behind a merely probable prime. A
+//! [`WitnessTrace`] goes one step further and keeps the intermediate values
+//! that make the verdict checkable by hand.
+
+use crate::bases::{filter_bases_for_n, get_test_bases_for_size, DETERMINISTIC_THRESHOLD};
+use crate::error::{PrimalityError, Result};
+use crate::witness::{decompose_into_d_and_s, miller_rabin_witness, mod_pow};
+
+use num_bigint::BigUint;
+use num_traits::One;
+
+/// The outcome of a Miller-Rabin run together with the evidence behind it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrimalityCertificate {
+    /// n is below [`DETERMINISTIC_THRESHOLD`] and passed every base in
+    /// `bases`, which is sufficient to prove primality. Only bases below n
+    /// are listed, so 2 carries an empty list and 3 carries `[2]`.
+    DeterministicPrime { bases: Vec<u64> },
+    /// `witness` is a base for which n fails the Miller-Rabin test, so n is
+    /// definitely composite.
+    Composite { witness: BigUint },
+    /// n is beyond the deterministic range and passed `rounds` bases.
+    ProbablePrime { rounds: usize },
+}
+
+impl PrimalityCertificate {
+    /// True unless the certificate proves n composite.
+    pub fn is_probable_prime(&self) -> bool {
+        !matches!(self, PrimalityCertificate::Composite { .. })
+    }
+}
+
+/// Tests n against the size-appropriate bases and returns a certificate of
+/// the result.
+///
+/// Every base is checked (up to the first witness) rather than stopping at
+/// small-prime trial division, so the certificate can be re-verified with
+/// [`miller_rabin_test`](crate::miller_rabin_test) alone.
+///
+/// # Errors
+/// Returns [`PrimalityError::InvalidInput`] if `n < 2`.
+///
+/// # Examples
+/// ```
+/// use miller_rabin_tester::{primality_certificate, PrimalityCertificate};
+/// use num_bigint::BigUint;
+///
+/// let cert = primality_certificate(&BigUint::from(561u32)).unwrap();
+/// assert_eq!(cert, PrimalityCertificate::Composite { witness: BigUint::from(2u32) });
+/// ```
+pub fn primality_certificate(n: &BigUint) -> Result<PrimalityCertificate> {
+    if n < &BigUint::from(2u32) {
+        return Err(PrimalityError::InvalidInput(n.to_string()));
+    }
+
+    let (d, s) = decompose_into_d_and_s(n);
+    let bases = filter_bases_for_n(get_test_bases_for_size(n), n);
+
+    for &a in &bases {
+        let a_big = BigUint::from(a);
+        if !miller_rabin_witness(&a_big, &d, s, n, None) {
+            return Ok(PrimalityCertificate::Composite { witness: a_big });
+        }
+    }
+
+    if n < &BigUint::from(DETERMINISTIC_THRESHOLD) {
+        Ok(PrimalityCertificate::DeterministicPrime { bases })
+    } else {
+        Ok(PrimalityCertificate::ProbablePrime {
+            rounds: bases.len(),
+        })
+    }
+}
+
+/// The working behind a Miller-Rabin verdict: how n − 1 was split, which
+/// bases were tried and, for a composite, the residues that gave it away.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WitnessTrace {
+    /// Odd part of n − 1
+    pub d: BigUint,
+    /// Power of two in n − 1, so that n − 1 = d · 2^s
+    pub s: usize,
+    /// Bases tested in order, ending at the witness if there is one
+    pub bases: Vec<u64>,
+    /// The base proving n composite and its residues a^d, a^(2d), ...
+    /// mod n, up to s of them and stopping at the first 1. None of them
+    /// is n − 1, which is what makes the base a witness.
+    pub witness: Option<(u64, Vec<BigUint>)>,
+}
+
+/// Runs the same test as [`primality_certificate`] and records its working.
+///
+/// # Errors
+/// Returns [`PrimalityError::InvalidInput`] if `n < 2`.
+///
+/// # Examples
+/// ```
+/// use miller_rabin_tester::witness_trace;
+/// use num_bigint::BigUint;
+///
+/// // 561 - 1 = 35 · 2^4, and 2^35 mod 561 = 263 squares to 1 without
+/// // passing through 560
+/// let trace = witness_trace(&BigUint::from(561u32)).unwrap();
+/// assert_eq!((trace.d, trace.s), (BigUint::from(35u32), 4));
+/// let residues: Vec<BigUint> = [263u32, 166, 67, 1].map(BigUint::from).into();
+/// assert_eq!(trace.witness, Some((2, residues)));
+/// ```
+pub fn witness_trace(n: &BigUint) -> Result<WitnessTrace> {
+    witness_trace_with_bases(n, &[])
+}
+
+/// Like [`witness_trace`], but tries `custom_bases` the way
+/// [`is_probable_prime_with_bases`](crate::is_probable_prime_with_bases)
+/// does: through [`filter_bases_for_n`], with an empty slice meaning the
+/// default bases.
+///
+/// # Errors
+/// Returns [`PrimalityError::InvalidInput`] if `n < 2`.
+pub fn witness_trace_with_bases(n: &BigUint, custom_bases: &[u64]) -> Result<WitnessTrace> {
+    if n < &BigUint::from(2u32) {
+        return Err(PrimalityError::InvalidInput(n.to_string()));
+    }
+
+    let (d, s) = decompose_into_d_and_s(n);
+    let mut bases = Vec::new();
+    let mut witness = None;
+
+    let source = if custom_bases.is_empty() {
+        get_test_bases_for_size(n)
+    } else {
+        custom_bases
+    };
+    for &a in filter_bases_for_n(source, n).iter() {
+        bases.push(a);
+        let a_big = BigUint::from(a);
+        if !miller_rabin_witness(&a_big, &d, s, n, None) {
+            witness = Some((a, residues(a_big, &d, s, n)));
+            break;
+        }
+    }
+
+    Ok(WitnessTrace {
+        d,
+        s,
+        bases,
+        witness,
+    })
+}
+
+/// a^d mod n followed by its repeated squares, at most `s` values in all
+/// and none past the first 1.
+fn residues(a: BigUint, d: &BigUint, s: usize, n: &BigUint) -> Vec<BigUint> {
+    let mut x = mod_pow(a, d, n, None);
+    let mut sequence = Vec::with_capacity(s.max(1));
+    for _ in 1..s {
+        if x.is_one() {
+            break;
+        }
+        let next = (&x * &x) % n;
+        sequence.push(std::mem::replace(&mut x, next));
+    }
+    sequence.push(x);
+    sequence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_prime_is_deterministic() {
+        let cert = primality_certificate(&BigUint::from(104729u32)).unwrap();
+        assert_eq!(
+            cert,
+            PrimalityCertificate::DeterministicPrime {
+                bases: vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]
+            }
+        );
+        assert!(cert.is_probable_prime());
+    }
+
+    #[test]
+    fn test_large_prime_uses_extended_bases() {
+        // 2^61 - 1 is above the 12-base threshold
+        let n = (BigUint::from(1u32) << 61usize) - BigUint::from(1u32);
+        match primality_certificate(&n).unwrap() {
+            PrimalityCertificate::DeterministicPrime { bases } => assert_eq!(bases.len(), 19),
+            other => panic!("expected DeterministicPrime, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_carmichael_has_witness() {
+        let n = BigUint::from(561u32);
+        let cert = primality_certificate(&n).unwrap();
+        let PrimalityCertificate::Composite { witness } = &cert else {
+            panic!("561 must be composite, got {:?}", cert);
+        };
+        assert!(!crate::miller_rabin_test(witness, &n));
+        assert!(!cert.is_probable_prime());
+    }
+
+    #[test]
+    fn test_small_inputs() {
+        assert_eq!(
+            primality_certificate(&BigUint::from(2u32)).unwrap(),
+            PrimalityCertificate::DeterministicPrime { bases: vec![] }
+        );
+        assert_eq!(
+            primality_certificate(&BigUint::from(3u32)).unwrap(),
+            PrimalityCertificate::DeterministicPrime { bases: vec![2] }
+        );
+        assert_eq!(
+            primality_certificate(&BigUint::from(5u32)).unwrap(),
+            PrimalityCertificate::DeterministicPrime { bases: vec![2, 3] }
+        );
+        assert_eq!(
+            primality_certificate(&BigUint::from(4u32)).unwrap(),
+            PrimalityCertificate::Composite {
+                witness: BigUint::from(2u32)
+            }
+        );
+        assert!(primality_certificate(&BigUint::from(1u32)).is_err());
+    }
+
+    #[test]
+    fn test_witness_trace_with_bases() {
+        let n = BigUint::from(561u32);
+        // 50 is a strong liar for 561, 7 is not
+        let trace = witness_trace_with_bases(&n, &[50, 7]).unwrap();
+        assert_eq!(trace.bases, vec![50, 7]);
+        assert_eq!(trace.witness.map(|(a, _)| a), Some(7));
+
+        let trace = witness_trace_with_bases(&n, &[50]).unwrap();
+        assert_eq!(trace.bases, vec![50]);
+        assert_eq!(trace.witness, None);
+
+        assert_eq!(witness_trace_with_bases(&n, &[]), witness_trace(&n));
+    }
+
+    #[test]
+    fn test_witness_trace_matches_certificate() {
+        for n in [4u32, 9, 15, 341, 561, 1105, 104729] {
+            let n = BigUint::from(n);
+            let trace = witness_trace(&n).unwrap();
+            assert_eq!(
+                (BigUint::from(1u32) << trace.s) * &trace.d + 1u32,
+                n,
+                "n={}",
+                n
+            );
+            match (primality_certificate(&n).unwrap(), &trace.witness) {
+                (PrimalityCertificate::Composite { witness }, Some((a, residues))) => {
+                    assert_eq!(witness, BigUint::from(*a), "n={}", n);
+                    assert_eq!(trace.bases.last(), Some(a), "n={}", n);
+                    assert!(residues.len() <= trace.s.max(1), "n={}", n);
+                    assert!(!residues.contains(&(&n - 1u32)), "n={}", n);
+                }
+                (PrimalityCertificate::DeterministicPrime { bases }, None) => {
+                    assert_eq!(trace.bases, bases, "n={}", n);
+                }
+                (cert, witness) => panic!("n={}: {:?} vs {:?}", n, cert, witness),
+            }
+        }
+        assert!(witness_trace(&BigUint::from(1u32)).is_err());
+    }
+
+    #[test]
+    fn test_beyond_deterministic_range() {
+        // 2^89 - 1 is a Mersenne prime above ~3.3x10^24
+        let n = (BigUint::from(1u32) << 89usize) - BigUint::from(1u32);
+        assert_eq!(
+            primality_certificate(&n).unwrap(),
+            PrimalityCertificate::ProbablePrime { rounds: 19 }
+        );
+    }
+}
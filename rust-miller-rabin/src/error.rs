@@ -16,6 +16,10 @@ pub enum PrimalityError {
     /// Thread-related error during parallel execution
     #[error("parallel execution failed: {0}")]
     ParallelError(String),
+
+    /// The input string is not a valid decimal number
+    #[error("cannot parse number: {0}")]
+    ParseError(String),
 }
 
 /// Result type for primality testing operations
@@ -32,5 +36,8 @@ mod tests {
 
         let err = PrimalityError::InvalidBase("0".to_string());
         assert_eq!(err.to_string(), "invalid base: 0");
+
+        let err = PrimalityError::ParseError("\"12a\"".to_string());
+        assert_eq!(err.to_string(), "cannot parse number: \"12a\"");
     }
 }
@@ -5,7 +5,7 @@
 //!
 //! # Features
 //!
-//! - **Deterministic testing**: Guaranteed correct results for numbers up to ~3.3x10^25
+//! - **Deterministic testing**: Guaranteed correct results for numbers up to ~3.3x10^24
 //! - **Parallel execution**: Multi-threaded testing for large numbers
 //! - **Progress tracking**: Optional progress bars for long-running tests
 //! - **Custom bases**: Support for custom test bases
@@ -16,7 +16,7 @@
 //! specific thresholds, it can be made deterministic by testing against known bases:
 //!
 //! - **n < 3,474,749,660,399**: 12 bases (deterministic for all 64-bit integers)
-//! - **n < 3,317,044,064,679,887,385,961,981**: 19 bases (deterministic up to ~3.3x10^25)
+//! - **n < 3,317,044,064,679,887,385,961,981**: 19 bases (deterministic up to ~3.3x10^24)
 //!
 //! # Examples
 //!
@@ -59,6 +59,7 @@
 
 // Module declarations
 pub mod bases;
+pub mod certificate;
 pub mod error;
 pub mod parallel;
 pub mod progress;
@@ -66,15 +67,21 @@ pub mod witness;
 
 // Re-export main types and functions
 pub use bases::{filter_bases_for_n, get_test_bases_for_size};
+pub use certificate::{
+    primality_certificate, witness_trace, witness_trace_with_bases, PrimalityCertificate,
+    WitnessTrace,
+};
 pub use error::{PrimalityError, Result};
-pub use parallel::is_probable_prime_parallel;
+pub use parallel::{is_probable_prime_parallel, PARALLEL_MIN_BITS};
 pub use progress::{ProgressBar, ProgressCallback};
 pub use witness::{
     decompose_into_d_and_s, miller_rabin_test, miller_rabin_witness, mod_pow, witness_check,
 };
 
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use num_traits::Zero;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 /// Checks if a number passes small prime divisibility tests.
 ///
@@ -136,6 +143,87 @@ pub fn is_probable_prime(n: &BigUint) -> bool {
     true
 }
 
+/// [`is_probable_prime`] for a signed value.
+///
+/// Every n < 2, negatives included, is not prime; the rest are tested as
+/// their unsigned magnitude.
+///
+/// # Examples
+/// ```
+/// use miller_rabin_tester::is_probable_prime_signed;
+/// use num_bigint::BigInt;
+///
+/// assert!(is_probable_prime_signed(&BigInt::from(7)));
+/// assert!(!is_probable_prime_signed(&BigInt::from(-7)));
+/// ```
+pub fn is_probable_prime_signed(n: &BigInt) -> bool {
+    match n.to_biguint() {
+        Some(n) => is_probable_prime(&n),
+        None => false,
+    }
+}
+
+/// Like [`is_probable_prime`], also reporting how long each base's witness
+/// test took, as `(base, elapsed)` in the order tested.
+///
+/// Only bases actually run are listed: those left after filtering for n,
+/// and none past the first witness of compositeness. Numbers settled by
+/// the small-prime checks list no bases.
+///
+/// # Examples
+/// ```
+/// use miller_rabin_tester::is_probable_prime_timed;
+/// use num_bigint::BigUint;
+///
+/// let (is_prime, timings) = is_probable_prime_timed(&BigUint::from(104729u32));
+/// assert!(is_prime);
+/// let slowest = timings.iter().max_by_key(|(_, elapsed)| *elapsed);
+/// assert!(slowest.is_some());
+/// ```
+pub fn is_probable_prime_timed(n: &BigUint) -> (bool, Vec<(u64, Duration)>) {
+    if let Some(result) = check_small_primes(n) {
+        return (result, Vec::new());
+    }
+
+    let (d, s) = decompose_into_d_and_s(n);
+    let bases = filter_bases_for_n(get_test_bases_for_size(n), n);
+    let mut timings = Vec::with_capacity(bases.len());
+
+    for &a in &bases {
+        let start = Instant::now();
+        let passed = miller_rabin_witness(&BigUint::from(a), &d, s, n, None);
+        timings.push((a, start.elapsed()));
+        if !passed {
+            return (false, timings);
+        }
+    }
+
+    (true, timings)
+}
+
+/// Parses a decimal string and tests it with [`is_probable_prime`].
+///
+/// Surrounding whitespace is ignored, so lines read from a file can be
+/// passed directly.
+///
+/// # Examples
+/// ```
+/// use miller_rabin_tester::is_probable_prime_str;
+///
+/// assert_eq!(is_probable_prime_str("104729"), Ok(true));
+/// assert!(is_probable_prime_str("not a number").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`PrimalityError::ParseError`] if `s` is not a non-negative
+/// decimal integer.
+pub fn is_probable_prime_str(s: &str) -> Result<bool> {
+    let n = BigUint::from_str(s.trim())
+        .map_err(|e| PrimalityError::ParseError(format!("{:?}: {}", s, e)))?;
+    Ok(is_probable_prime(&n))
+}
+
 /// Tests primality with a custom set of bases.
 ///
 /// If `custom_bases` is empty, uses the default deterministic bases.
@@ -213,8 +301,43 @@ pub fn is_probable_prime_parallel_with_progress(
 ) -> bool {
     use std::sync::atomic::AtomicBool;
 
+    // Only a witness sets the flag here, so the test always reaches a verdict
+    let stop_flag = AtomicBool::new(false);
+    is_probable_prime_parallel_full(n, threads, &stop_flag, progress_callback).unwrap_or(true)
+}
+
+/// Parallel primality testing with progress reporting and external
+/// cancellation.
+///
+/// Setting `stop_flag` from another thread (or from the progress callback)
+/// makes every worker return within one exponent bit. Returns `None` when
+/// the test was stopped before it could reach a verdict, and `Some(false)`
+/// if a witness had already proved `n` composite. The flag is also set by
+/// the test itself once a witness is found.
+///
+/// # Examples
+/// ```
+/// use miller_rabin_tester::is_probable_prime_parallel_full;
+/// use num_bigint::BigUint;
+/// use std::sync::atomic::AtomicBool;
+///
+/// let n = BigUint::from(104729u32);
+/// let stop = AtomicBool::new(false);
+/// assert_eq!(
+///     is_probable_prime_parallel_full(&n, 4, &stop, &|_current, _total| {}),
+///     Some(true)
+/// );
+/// ```
+pub fn is_probable_prime_parallel_full(
+    n: &BigUint,
+    threads: usize,
+    stop_flag: &std::sync::atomic::AtomicBool,
+    progress_callback: &ProgressCallback,
+) -> Option<bool> {
+    use std::sync::atomic::Ordering;
+
     if let Some(result) = check_small_primes(n) {
-        return result;
+        return Some(result);
     }
 
     let effective_threads = if threads <= 1 { 1 } else { threads };
@@ -222,16 +345,72 @@ pub fn is_probable_prime_parallel_with_progress(
     let (d, s) = decompose_into_d_and_s(n);
     let bases = filter_bases_for_n(get_test_bases_for_size(n), n);
 
-    let stop_flag = AtomicBool::new(false);
-    parallel::test_bases_parallel_with_progress(
+    let passed = parallel::test_bases_parallel_with_progress(
         n,
         &d,
         s,
         &bases,
         effective_threads,
-        &stop_flag,
+        stop_flag,
         progress_callback,
-    )
+    );
+
+    // Workers also report `true` when stopped early; without a witness,
+    // a set flag means some bases were never checked
+    if passed && stop_flag.load(Ordering::Relaxed) {
+        None
+    } else {
+        Some(passed)
+    }
+}
+
+/// [`is_probable_prime_parallel_full`] with a time limit.
+///
+/// The test runs on a worker thread while the caller waits up to `timeout`;
+/// past that, the stop flag is set and the worker gives up within one
+/// exponent bit of the modular exponentiation it is in, so even a
+/// multi-thousand-digit n stops promptly.
+///
+/// Returns `None` when the time ran out before a verdict. A witness found
+/// before the stop still proves `n` composite, so `Some(false)` can come
+/// back even after the limit passed.
+///
+/// # Examples
+/// ```
+/// use miller_rabin_tester::is_probable_prime_with_timeout;
+/// use num_bigint::BigUint;
+/// use std::time::Duration;
+///
+/// let n = BigUint::from(104729u32);
+/// let verdict = is_probable_prime_with_timeout(&n, 2, Duration::from_secs(10), &|_, _| {});
+/// assert_eq!(verdict, Some(true));
+/// ```
+pub fn is_probable_prime_with_timeout(
+    n: &BigUint,
+    threads: usize,
+    timeout: std::time::Duration,
+    progress_callback: &ProgressCallback,
+) -> Option<bool> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc;
+
+    let stop_flag = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            let result = is_probable_prime_parallel_full(n, threads, &stop_flag, progress_callback);
+            let _ = sender.send(result);
+        });
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(_) => {
+                stop_flag.store(true, Ordering::Relaxed);
+                receiver.recv().ok().flatten()
+            }
+        }
+    })
 }
 
 /// Parallel primality testing with custom bases.
@@ -257,6 +436,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_signed() {
+        for n in [-5, 0, 1] {
+            assert!(!is_probable_prime_signed(&BigInt::from(n)), "{}", n);
+        }
+        for n in [7, 11] {
+            assert!(is_probable_prime_signed(&BigInt::from(n)), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_timed_one_entry_per_tested_base() {
+        // M127, prime, so every filtered base runs
+        let n = (BigUint::one() << 127) - BigUint::one();
+        let (is_prime, timings) = is_probable_prime_timed(&n);
+        assert!(is_prime);
+        let bases = filter_bases_for_n(get_test_bases_for_size(&n), &n);
+        let timed: Vec<u64> = timings.iter().map(|&(base, _)| base).collect();
+        assert_eq!(timed, bases);
+
+        // 3215031751 = 151·751·28351 is a strong pseudoprime to 2, 3, 5, 7,
+        // so it gets past four bases before 11 witnesses it
+        let (is_prime, timings) = is_probable_prime_timed(&BigUint::from(3_215_031_751u64));
+        assert!(!is_prime);
+        assert_eq!(timings.len(), 5);
+        assert_eq!(timings.last().map(|&(base, _)| base), Some(11));
+
+        // Settled by the small-prime checks before any base
+        assert_eq!(
+            is_probable_prime_timed(&BigUint::from(5u32)),
+            (true, vec![])
+        );
+        assert_eq!(
+            is_probable_prime_timed(&BigUint::from(1000u32)),
+            (false, vec![])
+        );
+    }
+
+    #[test]
+    fn test_is_probable_prime_str() {
+        // M127
+        assert_eq!(
+            is_probable_prime_str("170141183460469231731687303715884105727"),
+            Ok(true)
+        );
+        // F7 = 2^128 + 1 = 59649589127497217 × 5704689200685129054721
+        assert_eq!(
+            is_probable_prime_str("340282366920938463463374607431768211457"),
+            Ok(false)
+        );
+        assert_eq!(is_probable_prime_str(" 104729\n"), Ok(true));
+        assert_eq!(is_probable_prime_str("1"), Ok(false));
+
+        for bad in ["", "abc", "12a", "-7", "1.5"] {
+            assert!(
+                matches!(
+                    is_probable_prime_str(bad),
+                    Err(PrimalityError::ParseError(_))
+                ),
+                "{:?} should fail to parse",
+                bad
+            );
+        }
+    }
+
     #[test]
     fn test_small_composites() {
         let composites = [
@@ -373,6 +617,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parallel_full_stops_early() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::{Arc, Mutex};
+
+        // M4423 is prime, so without the stop every one of its 19 bases runs
+        let n = (BigUint::one() << 4423) - BigUint::one();
+        let stop = Arc::new(AtomicBool::new(false));
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let stop_clone = Arc::clone(&stop);
+        let reports_clone = Arc::clone(&reports);
+        let result = is_probable_prime_parallel_full(&n, 2, &stop, &move |current, total| {
+            reports_clone.lock().unwrap().push((current, total));
+            // Cancel on the first report; each worker abandons its current base
+            stop_clone.store(true, Ordering::Relaxed);
+        });
+
+        // No witness was found before the stop, so there is no verdict
+        assert_eq!(result, None);
+        let reports = reports.lock().unwrap();
+        let &(last, total) = reports.last().expect("progress was never reported");
+        assert!(last < total, "stopped test reported {}/{}", last, total);
+        assert!(reports.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
+
+    #[test]
+    fn test_timeout_gives_up_on_large_prime() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        // M4423 is prime; a single one of its 19 bases takes over a second
+        // unoptimized, so a 1 ms timeout stops the test partway through
+        let n = (BigUint::one() << 4423) - BigUint::one();
+        for threads in [1, 2] {
+            let finished = Arc::new(AtomicBool::new(false));
+            let finished_ref = Arc::clone(&finished);
+            let verdict = is_probable_prime_with_timeout(
+                &n,
+                threads,
+                Duration::from_millis(1),
+                &move |current, total| {
+                    if current == total {
+                        finished_ref.store(true, Ordering::Relaxed);
+                    }
+                },
+            );
+            assert_eq!(verdict, None, "threads={}", threads);
+            // Returning at all shows the workers stopped; none reached the end
+            assert!(!finished.load(Ordering::Relaxed), "threads={}", threads);
+        }
+    }
+
+    #[test]
+    fn test_timeout_not_reached() {
+        use std::time::Duration;
+
+        let limit = Duration::from_secs(60);
+        let prime = BigUint::from(104729u32);
+        assert_eq!(
+            is_probable_prime_with_timeout(&prime, 2, limit, &|_, _| {}),
+            Some(true)
+        );
+        let composite = BigUint::from(561u32);
+        assert_eq!(
+            is_probable_prime_with_timeout(&composite, 1, limit, &|_, _| {}),
+            Some(false)
+        );
+    }
+
     #[test]
     fn test_64_bit_deterministic() {
         let threshold = BigUint::from(3_474_749_660_399u64);
@@ -7,14 +7,18 @@ use clap::Parser;
 use miller_rabin_tester::{
     get_test_bases_for_size, is_probable_prime, is_probable_prime_parallel,
     is_probable_prime_parallel_with_bases, is_probable_prime_parallel_with_progress,
-    is_probable_prime_with_bases, is_probable_prime_with_progress, ProgressCallback,
+    is_probable_prime_timed, is_probable_prime_with_bases, is_probable_prime_with_progress,
+    is_probable_prime_with_timeout, witness_trace_with_bases, ProgressBar, ProgressCallback,
+    WitnessTrace,
 };
 use num_bigint::{BigUint, ToBigUint};
 use serde_json::json;
 use std::fs;
 use std::io::{self, Write};
 use std::str::FromStr;
+use std::sync::Arc;
 use std::thread;
+use std::time::Duration;
 
 /// Get the number of available CPU cores
 fn get_available_threads() -> usize {
@@ -29,7 +33,7 @@ fn get_available_threads() -> usize {
 #[command(about = "Miller-Rabin primality tester for large integers")]
 #[command(version)]
 struct Args {
-    /// Number to test (as string)
+    /// Number to test: decimal, or hex/binary with a 0x/0b prefix
     #[arg(short, long)]
     number: Option<String>,
 
@@ -46,7 +50,8 @@ struct Args {
     parallel: bool,
 
     /// Run batch test on range
-    #[arg(short, long)]
+    // No short flag: -b belongs to --bases
+    #[arg(long)]
     batch_test: bool,
 
     /// Start of range for batch test
@@ -81,11 +86,76 @@ struct Args {
     /// Show progress bar for large number tests
     #[arg(long, help = "Show progress bar for large number tests")]
     show_progress: bool,
+
+    /// Give up on a single number after this many seconds
+    #[arg(
+        long,
+        value_name = "SECS",
+        help = "Give up on --number after SECS seconds and report TIMEOUT"
+    )]
+    timeout: Option<u64>,
+
+    /// Show the working behind the --number verdict
+    #[arg(
+        long,
+        help = "Explain the --number verdict: n-1 = d*2^s, the bases tried and any witness"
+    )]
+    explain: bool,
 }
 
-/// Parse a BigUint from string
+/// Human-readable account of a witness trace for n
+fn format_explanation(n: &BigUint, trace: &WitnessTrace) -> String {
+    let bases: Vec<String> = trace.bases.iter().map(u64::to_string).collect();
+    let mut lines = vec![
+        format!(
+            "n - 1 = {} * 2^{}  (d = {}, s = {})",
+            trace.d, trace.s, trace.d, trace.s
+        ),
+        format!(
+            "Bases tried: {}",
+            if bases.is_empty() {
+                "none".to_string()
+            } else {
+                bases.join(", ")
+            }
+        ),
+    ];
+
+    match &trace.witness {
+        Some((a, residues)) => {
+            lines.push(format!("Witness: {}", a));
+            for (i, x) in residues.iter().enumerate() {
+                lines.push(format!("  {}^({} * 2^{}) mod {} = {}", a, trace.d, i, n, x));
+            }
+            lines.push(format!(
+                "{}^{} mod {} is not 1 and no square reaches {}, so {} is composite",
+                a,
+                trace.d,
+                n,
+                n - 1u32,
+                n
+            ));
+        }
+        None => lines.push(format!(
+            "No base is a witness: each gave a^{} = 1 or reached {} by squaring",
+            trace.d,
+            n - 1u32
+        )),
+    }
+
+    lines.join("\n")
+}
+
+/// Parse a BigUint from string: decimal, or hex/binary with a `0x`/`0b`
+/// prefix (either case)
 fn parse_big_uint(s: &str) -> Result<BigUint, String> {
-    BigUint::from_str(s).map_err(|e| e.to_string())
+    let (digits, radix) = match s.get(..2) {
+        Some("0x" | "0X") => (&s[2..], 16),
+        Some("0b" | "0B") => (&s[2..], 2),
+        _ => return BigUint::from_str(s).map_err(|e| e.to_string()),
+    };
+    BigUint::parse_bytes(digits.as_bytes(), radix)
+        .ok_or_else(|| format!("invalid base-{} number", radix))
 }
 
 /// Parse comma-separated list of bases
@@ -147,40 +217,16 @@ fn format_duration(ms: f64) -> String {
     }
 }
 
-/// Render a progress bar
-fn render_progress_bar(current: usize, total: usize, width: usize) -> String {
-    if total == 0 {
-        return "[>".to_string();
-    }
-    let filled = (current * width) / total;
-    let empty = width.saturating_sub(filled);
-    format!("[{}{}]", "█".repeat(filled.min(width)), "░".repeat(empty))
-}
-
-/// Print progress to stderr
-///
-/// Uses a shared AtomicBool to ensure the 100% line is rendered and
-/// finalized with a newline exactly once.
-fn print_progress(current: usize, total: usize, finished: &std::sync::atomic::AtomicBool) {
-    use std::sync::atomic::Ordering;
-
-    if total == 0 {
-        return;
-    }
-
-    // Once we've printed the final 100% + newline, suppress all further output.
-    if finished.load(Ordering::Relaxed) {
-        return;
-    }
+/// Exit status when `--timeout` expires, as with coreutils `timeout`
+const TIMEOUT_EXIT_CODE: i32 = 124;
 
-    let bar = render_progress_bar(current, total, 30);
-    let percent = (current * 100) / total;
-    eprint!("\r  Progress: {} {:>3}%", bar, percent);
-    let _ = std::io::stderr().flush();
+/// Steps on the `--show-progress` bar, one per percent
+const PROGRESS_STEPS: usize = 100;
 
-    if current >= total {
-        finished.store(true, Ordering::Relaxed);
-        eprintln!();
+/// Move `bar` to `current` out of `total` units of exponent bits.
+fn report_progress(bar: &ProgressBar, current: usize, total: usize) {
+    if total > 0 {
+        bar.set_position((current as u128 * bar.total() as u128 / total as u128) as usize);
     }
 }
 
@@ -285,12 +331,55 @@ fn main() {
                 let custom_bases: Vec<u64> =
                     args.bases.as_ref().map_or(Vec::new(), |s| parse_bases(s));
 
-                let result = if args.show_progress {
-                    use std::sync::atomic::AtomicBool;
-                    use std::sync::Arc;
-                    let finished = Arc::new(AtomicBool::new(false));
+                let result = if let Some(secs) = args.timeout {
+                    if !custom_bases.is_empty() {
+                        eprintln!("Warning: Custom bases ignored with --timeout");
+                    }
+                    metrics.bases_tested = get_test_bases_for_size(&n).len();
+                    let bar = args
+                        .show_progress
+                        .then(|| Arc::new(ProgressBar::new(PROGRESS_STEPS, "  Progress")));
+                    let b = bar.clone();
+                    let progress_fn: &ProgressCallback = &move |current, total| {
+                        if let Some(bar) = &b {
+                            report_progress(bar, current, total);
+                        }
+                    };
+
+                    let verdict = is_probable_prime_with_timeout(
+                        &n,
+                        metrics.threads_used,
+                        Duration::from_secs(secs),
+                        progress_fn,
+                    );
+                    if let Some(bar) = bar {
+                        bar.finish();
+                    }
+
+                    match verdict {
+                        Some(result) => result,
+                        None => {
+                            eprintln!("Gave up after {} s", secs);
+                            // JSON consumers read stdout, so only one result goes there
+                            if args.output_format == "json" {
+                                output_json(
+                                    &metrics,
+                                    json!({
+                                        "number": n_str.to_string(),
+                                        "is_prime": null,
+                                        "timed_out": true
+                                    }),
+                                );
+                            } else {
+                                println!("Result: TIMEOUT");
+                            }
+                            std::process::exit(TIMEOUT_EXIT_CODE);
+                        }
+                    }
+                } else if args.show_progress {
+                    let bar = Arc::new(ProgressBar::new(PROGRESS_STEPS, "  Progress"));
 
-                    if args.parallel {
+                    let result = if args.parallel {
                         let bases = get_test_bases_for_size(&n);
                         metrics.bases_tested = bases.len();
                         eprintln!(
@@ -298,19 +387,21 @@ fn main() {
                             bases.len(),
                             threads
                         );
-                        let f = Arc::clone(&finished);
+                        let b = Arc::clone(&bar);
                         let progress_fn: &ProgressCallback =
-                            &move |current, total| print_progress(current, total, &f);
+                            &move |current, total| report_progress(&b, current, total);
                         is_probable_prime_parallel_with_progress(&n, threads, progress_fn)
                     } else {
                         let bases = get_test_bases_for_size(&n);
                         metrics.bases_tested = bases.len();
                         eprintln!("Running Miller-Rabin with {} bases...", bases.len());
-                        let f = Arc::clone(&finished);
+                        let b = Arc::clone(&bar);
                         let progress_fn: &ProgressCallback =
-                            &move |current, total| print_progress(current, total, &f);
+                            &move |current, total| report_progress(&b, current, total);
                         is_probable_prime_with_progress(&n, progress_fn)
-                    }
+                    };
+                    bar.finish();
+                    result
                 } else if !custom_bases.is_empty() && args.parallel {
                     is_probable_prime_parallel_with_bases(&n, threads, &custom_bases)
                         .unwrap_or(false)
@@ -322,6 +413,17 @@ fn main() {
                     let bases = get_test_bases_for_size(&n);
                     metrics.bases_tested = bases.len();
                     is_probable_prime_parallel(&n, threads, &[]).unwrap_or(false)
+                } else if args.verbose {
+                    let (result, timings) = is_probable_prime_timed(&n);
+                    metrics.bases_tested = timings.len();
+                    for (base, elapsed) in &timings {
+                        eprintln!(
+                            "  base {}: {}",
+                            base,
+                            format_duration(elapsed.as_secs_f64() * 1000.0)
+                        );
+                    }
+                    result
                 } else {
                     let bases = get_test_bases_for_size(&n);
                     metrics.bases_tested = bases.len();
@@ -334,6 +436,20 @@ fn main() {
                     println!("Result: COMPOSITE");
                 }
 
+                if args.explain {
+                    // Trace the bases the verdict came from; the timeout and
+                    // progress paths always use the defaults
+                    let explained_bases = if args.timeout.is_some() || args.show_progress {
+                        &[][..]
+                    } else {
+                        &custom_bases[..]
+                    };
+                    match witness_trace_with_bases(&n, explained_bases) {
+                        Ok(trace) => println!("{}", format_explanation(&n, &trace)),
+                        Err(e) => eprintln!("Cannot explain {}: {}", n, e),
+                    }
+                }
+
                 if args.verbose || args.output_format == "json" {
                     let elapsed = metrics.elapsed_ms();
                     eprintln!(
@@ -393,7 +509,7 @@ fn main() {
             }
         } else {
             // Parallel batch processing
-            let chunk_size = (total_numbers + threads - 1) / threads;
+            let chunk_size = total_numbers.div_ceil(threads);
             let mut handles: Vec<_> = Vec::with_capacity(threads);
 
             for t in 0..threads {
@@ -572,6 +688,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_definition() {
+        use clap::CommandFactory;
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_big_uint_prefixes() {
+        let n = parse_big_uint("0xFF").unwrap();
+        assert_eq!(n, BigUint::from(255u32));
+        assert!(!is_probable_prime(&n));
+
+        assert_eq!(parse_big_uint("0Xff").unwrap(), BigUint::from(255u32));
+        assert_eq!(parse_big_uint("0b1101").unwrap(), BigUint::from(13u32));
+        assert_eq!(parse_big_uint("0B111").unwrap(), BigUint::from(7u32));
+        assert_eq!(parse_big_uint("104729").unwrap(), BigUint::from(104729u32));
+        // 2^61 - 1 (Mersenne prime M61)
+        let m61 = parse_big_uint("0x1FFFFFFFFFFFFFFF").unwrap();
+        assert_eq!(m61, BigUint::from((1u64 << 61) - 1));
+        assert!(is_probable_prime(&m61));
+
+        for bad in ["0x", "0xZZ", "0b102", "12a", ""] {
+            assert!(parse_big_uint(bad).is_err(), "{:?} should not parse", bad);
+        }
+    }
+
     #[test]
     fn test_parse_bases() {
         assert_eq!(parse_bases("2,3,5,7"), vec![2, 3, 5, 7]);
@@ -582,6 +724,24 @@ mod tests {
         assert_eq!(parse_bases("0,1,2,3"), vec![2, 3]);
     }
 
+    #[test]
+    fn test_explain_carmichael() {
+        // 561 = 3 * 11 * 17 fools Fermat but not Miller-Rabin
+        let n = BigUint::from(561u32);
+        let trace = witness_trace_with_bases(&n, &[]).unwrap();
+        let text = format_explanation(&n, &trace);
+        assert!(text.contains("d = 35"), "{}", text);
+        assert!(text.contains("s = 4"), "{}", text);
+
+        let (a, _) = trace.witness.as_ref().expect("561 is composite");
+        assert!(text.contains(&format!("Witness: {}", a)), "{}", text);
+        assert!(!miller_rabin_tester::miller_rabin_test(
+            &BigUint::from(*a),
+            &n
+        ));
+        assert!(text.contains("561 is composite"), "{}", text);
+    }
+
     #[test]
     fn test_format_duration() {
         assert_eq!(format_duration(0.5), "0.500 ms");
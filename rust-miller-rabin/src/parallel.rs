@@ -6,12 +6,25 @@
 use crate::bases::{filter_bases_for_n, get_test_bases_for_size};
 use crate::error::Result;
 use crate::progress::ProgressCallback;
-use crate::witness::{decompose_into_d_and_s, miller_rabin_witness, mod_pow, witness_check};
+use crate::witness::{
+    decompose_into_d_and_s, miller_rabin_witness, mod_pow_unless_stopped, witness_check,
+};
 
 use num_bigint::BigUint;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 
+/// Inputs narrower than this many bits are tested sequentially by
+/// [`is_probable_prime_parallel`]: below it, spawning threads costs more
+/// than the modular exponentiations they would share.
+pub const PARALLEL_MIN_BITS: u64 = 512;
+
+#[cfg(test)]
+thread_local! {
+    /// Threads spawned by `test_bases_parallel` from the current thread
+    static THREADS_SPAWNED: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
 /// Parallel Miller-Rabin witness testing with early termination
 ///
 /// Tests multiple bases in parallel, stopping early if any base proves
@@ -44,13 +57,16 @@ pub fn test_bases_parallel(
             let bases_ref = bases;
             let stop_ref = stop_flag;
 
+            #[cfg(test)]
+            THREADS_SPAWNED.with(|count| count.set(count.get() + 1));
+
             handles.push(scope.spawn(move || -> bool {
-                for j in start_idx..end_idx {
+                for &a in &bases_ref[start_idx..end_idx] {
                     if stop_ref.load(Ordering::Relaxed) {
                         return true; // Another thread found a witness
                     }
 
-                    let a_big = BigUint::from(bases_ref[j]);
+                    let a_big = BigUint::from(a);
                     if !miller_rabin_witness(&a_big, d_ref, s, n_ref, None) {
                         stop_ref.store(true, Ordering::Relaxed);
                         return false;
@@ -155,15 +171,24 @@ pub fn test_bases_parallel_with_progress(
             let completed = Arc::clone(&completed_bits);
 
             handles.push(scope.spawn(move || -> bool {
-                for j in start_idx..end_idx {
+                for &a in &bases_ref[start_idx..end_idx] {
                     if stop_ref.load(Ordering::Relaxed) {
                         return true;
                     }
 
-                    let a_big = BigUint::from(bases_ref[j]);
-
-                    // Use the single shared mod_pow with progress tracking
-                    let x = mod_pow(a_big, d_ref, n_ref, Some(&completed));
+                    let a_big = BigUint::from(a);
+
+                    // Use the single shared mod_pow with progress tracking,
+                    // abandoning it as soon as the stop flag is set
+                    let Some(x) = mod_pow_unless_stopped(
+                        a_big,
+                        d_ref,
+                        n_ref,
+                        Some(&completed),
+                        Some(stop_ref),
+                    ) else {
+                        return true;
+                    };
 
                     // Use the shared witness_check
                     if !witness_check(x, s, n_ref) {
@@ -210,11 +235,14 @@ fn test_bases_sequential_with_progress(
         let a_big = BigUint::from(*a);
 
         // Use the shared mod_pow with progress tracking
-        let x = mod_pow(a_big, d, n, Some(&completed_bits));
+        let Some(x) = mod_pow_unless_stopped(a_big, d, n, Some(&completed_bits), Some(stop_flag))
+        else {
+            return true;
+        };
 
         // Report progress after each mod_pow completes
         let bits = completed_bits.load(Ordering::Relaxed);
-        if bits % update_interval == 0 || bits >= total_bits_all_bases {
+        if bits.is_multiple_of(update_interval) || bits >= total_bits_all_bases {
             progress_callback(bits.min(total_bits_all_bases), total_bits_all_bases);
         }
 
@@ -244,6 +272,9 @@ fn collect_results(handles: Vec<std::thread::ScopedJoinHandle<'_, bool>>) -> boo
 }
 
 /// High-level interface for parallel primality testing
+///
+/// Numbers below [`PARALLEL_MIN_BITS`] bits fall back to the sequential
+/// [`crate::is_probable_prime_with_bases`] without spawning any threads.
 pub fn is_probable_prime_parallel(
     n: &BigUint,
     threads: usize,
@@ -258,6 +289,10 @@ pub fn is_probable_prime_parallel(
         return Ok(result);
     }
 
+    if n.bits() < PARALLEL_MIN_BITS {
+        return Ok(crate::is_probable_prime_with_bases(n, custom_bases));
+    }
+
     let (d, s) = decompose_into_d_and_s(n);
     let bases: Vec<u64> = if custom_bases.is_empty() {
         filter_bases_for_n(get_test_bases_for_size(n), n)
@@ -289,6 +324,39 @@ mod tests {
         assert!(!result);
     }
 
+    fn threads_spawned() -> usize {
+        THREADS_SPAWNED.with(|count| count.get())
+    }
+
+    #[test]
+    fn test_small_n_falls_back_to_sequential() {
+        let before = threads_spawned();
+        for p in [2u32, 3, 5, 7, 97, 7919, 104729, 2147483647] {
+            let n = BigUint::from(p);
+            assert!(is_probable_prime_parallel(&n, 4, &[]).unwrap(), "{}", p);
+        }
+        for c in [1u32, 4, 561, 1105, 104730] {
+            let n = BigUint::from(c);
+            assert!(!is_probable_prime_parallel(&n, 4, &[]).unwrap(), "{}", c);
+        }
+        // Custom bases still apply on the sequential path: 2047 fools base 2
+        let n = BigUint::from(2047u32);
+        assert!(is_probable_prime_parallel(&n, 4, &[2]).unwrap());
+        assert!(!is_probable_prime_parallel(&n, 4, &[3]).unwrap());
+        assert_eq!(threads_spawned(), before);
+    }
+
+    #[test]
+    fn test_large_n_spawns_threads() {
+        // M607 = 2^607 - 1 is prime and above the threshold
+        let n = (BigUint::from(1u32) << 607u32) - 1u32;
+        assert!(n.bits() >= PARALLEL_MIN_BITS);
+
+        let before = threads_spawned();
+        assert!(is_probable_prime_parallel(&n, 4, &[]).unwrap());
+        assert_eq!(threads_spawned() - before, 4);
+    }
+
     #[test]
     fn test_early_termination() {
         let n = BigUint::from(561u32);
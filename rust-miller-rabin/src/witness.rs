@@ -5,7 +5,7 @@
 
 use num_bigint::BigUint;
 use num_traits::{One, Zero};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 /// Decomposes n-1 into d * 2^s where d is odd.
 ///
@@ -57,13 +57,27 @@ pub fn decompose_into_d_and_s(n: &BigUint) -> (BigUint, usize) {
 /// assert_eq!(result, BigUint::from(4u32)); // 3^10 mod 7 = 4
 /// ```
 pub fn mod_pow(
-    mut base: BigUint,
+    base: BigUint,
     exp: &BigUint,
     modulus: &BigUint,
     progress: Option<&AtomicUsize>,
 ) -> BigUint {
+    mod_pow_unless_stopped(base, exp, modulus, progress, None)
+        .expect("mod_pow without a stop flag always finishes")
+}
+
+/// [`mod_pow`] that checks `stop` before each exponent bit and returns
+/// `None` once it is set, so a cancelled test on a huge n does not have to
+/// finish the exponentiation it is in the middle of.
+pub(crate) fn mod_pow_unless_stopped(
+    mut base: BigUint,
+    exp: &BigUint,
+    modulus: &BigUint,
+    progress: Option<&AtomicUsize>,
+    stop: Option<&AtomicBool>,
+) -> Option<BigUint> {
     if modulus.is_one() {
-        return BigUint::zero();
+        return Some(BigUint::zero());
     }
 
     let mut result = BigUint::one();
@@ -72,6 +86,10 @@ pub fn mod_pow(
     let total_bits = exp.bits();
 
     for i in 0..total_bits {
+        if stop.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+            return None;
+        }
+
         // Check bit i of the exponent -- O(1), no allocation
         if exp.bit(i) {
             result = (&result * &base) % modulus;
@@ -83,7 +101,7 @@ pub fn mod_pow(
         }
     }
 
-    result
+    Some(result)
 }
 
 /// Tests if 'a' is a Miller-Rabin witness for the compositeness of n.
@@ -125,11 +143,16 @@ pub fn miller_rabin_witness(
 
 /// The squaring phase of the Miller-Rabin witness test.
 ///
-/// Given x = a^d mod n, performs up to s squarings to determine
+/// Given x = a^d mod n, performs up to s-1 squarings to determine
 /// if the base is a witness for n's compositeness.
 ///
 /// Returns true if n may be prime (not a witness),
 /// false if n is definitely composite (witness found).
+///
+/// Only x^(2^r) for 0 <= r < s need comparing against n-1: the s-th square
+/// is a^(n-1), which is never n-1 for odd n (every prime factor p would need
+/// 2^(s+1) | p-1, forcing 2^(s+1) | n-1). Both the sequential and parallel
+/// paths share this function so the bound lives in one place.
 pub fn witness_check(x: BigUint, s: usize, n: &BigUint) -> bool {
     let one = BigUint::one();
     let n_minus_1 = n - &one;
@@ -158,6 +181,11 @@ pub fn witness_check(x: BigUint, s: usize, n: &BigUint) -> bool {
 /// Returns true if 'a' is NOT a witness (n may be prime relative to base a),
 /// returns false if 'a' IS a witness (n is definitely composite).
 ///
+/// n = 2 and n = 3 are prime, n < 2 and even n > 2 are rejected before the
+/// n-1 = d * 2^s decomposition. A meaningful base satisfies 2 <= a <= n-2;
+/// any other base is first reduced mod n, and if it lands on 0, 1 or n-1
+/// it cannot witness compositeness, so the result is true for every n >= 2.
+///
 /// # Examples
 /// ```
 /// use miller_rabin_tester::miller_rabin_test;
@@ -168,11 +196,24 @@ pub fn witness_check(x: BigUint, s: usize, n: &BigUint) -> bool {
 /// assert!(miller_rabin_test(&a, &n)); // 2 is not a witness for this prime
 /// ```
 pub fn miller_rabin_test(a: &BigUint, n: &BigUint) -> bool {
-    if n <= a || a.is_zero() {
-        return *n == BigUint::from(2u32);
+    let two = BigUint::from(2u32);
+    if *n < two {
+        return false;
     }
+    if *n <= BigUint::from(3u32) {
+        return true;
+    }
+    if !n.bit(0) {
+        return false;
+    }
+
+    let a = a % n;
+    if a.is_zero() || a.is_one() || a == n - BigUint::one() {
+        return true;
+    }
+
     let (d, s) = decompose_into_d_and_s(n);
-    miller_rabin_witness(a, &d, s, n, None)
+    miller_rabin_witness(&a, &d, s, n, None)
 }
 
 #[cfg(test)]
@@ -253,6 +294,95 @@ mod tests {
         assert!(miller_rabin_witness(&a, &d, s, &n, None));
     }
 
+    #[test]
+    fn test_miller_rabin_test_tiny_n() {
+        let two = BigUint::from(2u32);
+        let three = BigUint::from(3u32);
+        assert!(miller_rabin_test(&two, &two));
+        assert!(miller_rabin_test(&two, &three));
+        assert!(miller_rabin_test(&three, &three));
+        assert!(!miller_rabin_test(&two, &BigUint::zero()));
+        assert!(!miller_rabin_test(&two, &BigUint::one()));
+    }
+
+    #[test]
+    fn test_miller_rabin_test_even_n() {
+        for n in [4u32, 6, 100, 1024] {
+            let n = BigUint::from(n);
+            assert!(!miller_rabin_test(&BigUint::from(3u32), &n), "{}", n);
+            assert!(!miller_rabin_test(&BigUint::one(), &n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_miller_rabin_test_base_zero_and_one() {
+        // Bases 0 and 1 carry no information: never a witness
+        for n in [7u32, 561, 104729] {
+            let n = BigUint::from(n);
+            assert!(miller_rabin_test(&BigUint::zero(), &n), "{}", n);
+            assert!(miller_rabin_test(&BigUint::one(), &n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_miller_rabin_test_base_larger_than_n() {
+        // Bases are reduced mod n: 9 ≡ 2 (mod 7), 563 ≡ 2 (mod 561)
+        assert!(miller_rabin_test(
+            &BigUint::from(9u32),
+            &BigUint::from(7u32)
+        ));
+        assert!(!miller_rabin_test(
+            &BigUint::from(563u32),
+            &BigUint::from(561u32)
+        ));
+        // a ≡ 0 and a ≡ n-1 are trivial bases
+        assert!(miller_rabin_test(
+            &BigUint::from(7u32),
+            &BigUint::from(7u32)
+        ));
+        assert!(miller_rabin_test(
+            &BigUint::from(1121u32),
+            &BigUint::from(561u32)
+        ));
+    }
+
+    #[test]
+    fn test_witness_check_squares_up_to_s_minus_1() {
+        // 65537 = 2^16 + 1 with base 3 (a primitive root): d = 1, s = 16 and
+        // x = 3 only reaches n-1 on the last permitted squaring, 3^(2^15).
+        let n = BigUint::from(65537u32);
+        let (d, s) = decompose_into_d_and_s(&n);
+        assert_eq!(s, 16);
+        let x = mod_pow(BigUint::from(3u32), &d, &n, None);
+        assert!(witness_check(x.clone(), s, &n));
+        // One squaring short would reject a prime
+        assert!(!witness_check(x, s - 1, &n));
+    }
+
+    #[test]
+    fn test_witness_check_strong_pseudoprimes() {
+        // 3277 = 29 * 113 (s = 2) and 65281 = 97 * 673 (s = 8) are strong
+        // pseudoprimes to base 2; 3277 only passes on its last squaring.
+        for (n, s_expected) in [(3277u32, 2), (65281, 8)] {
+            let n = BigUint::from(n);
+            let (d, s) = decompose_into_d_and_s(&n);
+            assert_eq!(s, s_expected);
+
+            let x = mod_pow(BigUint::from(2u32), &d, &n, None);
+            assert!(witness_check(x, s, &n), "2 is a strong liar for {}", n);
+            assert!(miller_rabin_test(&BigUint::from(2u32), &n));
+
+            let x = mod_pow(BigUint::from(3u32), &d, &n, None);
+            assert!(!witness_check(x, s, &n), "3 is a witness for {}", n);
+            assert!(!miller_rabin_test(&BigUint::from(3u32), &n));
+        }
+
+        let n = BigUint::from(3277u32);
+        let (d, s) = decompose_into_d_and_s(&n);
+        let x = mod_pow(BigUint::from(2u32), &d, &n, None);
+        assert!(!witness_check(x, s - 1, &n));
+    }
+
     #[test]
     fn test_witness_check_separated() {
         // Verify witness_check produces the same result as miller_rabin_witness
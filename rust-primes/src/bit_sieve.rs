@@ -0,0 +1,128 @@
+//! A bit-packed sieve that iterates its primes
+//!
+//! Stores one bit per odd number, so sieving below n costs n/16 bytes, eight
+//! times less than the `Vec<bool>` sieves. Primes are then read back a word
+//! at a time, jumping straight to each set bit with `trailing_zeros`.
+
+/// Iterator over the primes below `n`, in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use primes::BitSieve;
+///
+/// let primes: Vec<usize> = BitSieve::new(20).collect();
+/// assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitSieve {
+    /// Bit i of the packed words is set when 2i + 1 is prime
+    bits: Vec<u64>,
+    /// Index of the word `current` was taken from
+    word: usize,
+    /// Set bits of the current word not yet yielded
+    current: u64,
+    /// 2 is the one even prime, so it has no bit and is yielded first
+    two_pending: bool,
+}
+
+impl BitSieve {
+    /// Sieve every number below `n`.
+    pub fn new(n: usize) -> Self {
+        // Odd numbers below n: 1, 3, ..., one bit each
+        let odd_count = n / 2;
+        let mut bits = vec![u64::MAX; odd_count.div_ceil(64)];
+
+        // Clear the padding bits past the last odd number, then 1 itself
+        if !odd_count.is_multiple_of(64) {
+            if let Some(last) = bits.last_mut() {
+                *last = (1 << (odd_count % 64)) - 1;
+            }
+        }
+        if let Some(first) = bits.first_mut() {
+            *first &= !1;
+        }
+
+        let mut p = 3;
+        while p * p < n {
+            let idx = p / 2;
+            if bits[idx / 64] & (1 << (idx % 64)) != 0 {
+                let mut multiple = p * p;
+                while multiple < n {
+                    let m_idx = multiple / 2;
+                    bits[m_idx / 64] &= !(1 << (m_idx % 64));
+                    multiple += 2 * p;
+                }
+            }
+            p += 2;
+        }
+
+        let current = bits.first().copied().unwrap_or(0);
+        Self {
+            bits,
+            word: 0,
+            current,
+            two_pending: n > 2,
+        }
+    }
+}
+
+impl Iterator for BitSieve {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.two_pending {
+            self.two_pending = false;
+            return Some(2);
+        }
+
+        while self.current == 0 {
+            self.word += 1;
+            self.current = *self.bits.get(self.word)?;
+        }
+
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit
+        self.current &= self.current - 1;
+        Some(2 * (self.word * 64 + bit) + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sieve_of_eratosthenes;
+
+    #[test]
+    fn test_matches_sieve_of_eratosthenes() {
+        assert_eq!(
+            BitSieve::new(100).collect::<Vec<_>>(),
+            sieve_of_eratosthenes(100).unwrap()
+        );
+        assert_eq!(
+            BitSieve::new(100_000).collect::<Vec<_>>(),
+            sieve_of_eratosthenes(100_000).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_small_and_word_boundary_bounds() {
+        // 127 and 131 straddle the first 64-bit word (odd numbers 1..=127)
+        for n in (0..300).chain([127, 128, 129, 131, 132]) {
+            assert_eq!(
+                BitSieve::new(n).collect::<Vec<_>>(),
+                sieve_of_eratosthenes(n).unwrap(),
+                "Failed for n={}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_bound_is_exclusive() {
+        assert_eq!(BitSieve::new(7).last(), Some(5));
+        assert_eq!(BitSieve::new(8).last(), Some(7));
+        assert_eq!(BitSieve::new(2).next(), None);
+        assert_eq!(BitSieve::new(3).collect::<Vec<_>>(), vec![2]);
+    }
+}
@@ -0,0 +1,107 @@
+//! Prime constellations (prime k-tuples)
+//!
+//! A constellation is an offset pattern such as `[0, 2, 6, 8]` (prime
+//! quadruplets) whose members are all prime. Twin, cousin and sexy pairs are
+//! the two-member patterns `[0, 2]`, `[0, 4]` and `[0, 6]`.
+
+use crate::{generate_primes, PrimeGenError};
+
+/// Find every `start < n` for which `start + offset` is prime for each
+/// offset in `pattern`, returning the matching tuples in order of `start`.
+///
+/// Members may reach past `n`; only the starting point is bounded.
+///
+/// # Examples
+///
+/// ```
+/// use primes::find_constellations;
+///
+/// let quadruplets = find_constellations(200, &[0, 2, 6, 8]).unwrap();
+/// assert_eq!(quadruplets.len(), 4);
+/// assert_eq!(quadruplets[1], vec![11, 13, 17, 19]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `InvalidInput` if `pattern` is empty.
+pub fn find_constellations(n: usize, pattern: &[usize]) -> Result<Vec<Vec<usize>>, PrimeGenError> {
+    let (Some(&min_offset), Some(&max_offset)) = (pattern.iter().min(), pattern.iter().max())
+    else {
+        return Err(PrimeGenError::InvalidInput(
+            "constellation pattern must not be empty".to_string(),
+        ));
+    };
+
+    // Sieve far enough to cover the last member of the last candidate.
+    let limit = n.saturating_add(max_offset);
+    let primes = generate_primes(limit, false, None, None, None)?;
+
+    let mut is_prime = vec![false; limit];
+    for &p in &primes {
+        is_prime[p] = true;
+    }
+
+    // Every start is some prime minus the smallest offset.
+    let constellations = primes
+        .iter()
+        .filter_map(|&p| p.checked_sub(min_offset))
+        .take_while(|&start| start < n)
+        .filter(|&start| pattern.iter().all(|&offset| is_prime[start + offset]))
+        .map(|start| pattern.iter().map(|&offset| start + offset).collect())
+        .collect();
+
+    Ok(constellations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::is_prime;
+
+    #[test]
+    fn test_twin_pattern_matches_twin_primes() {
+        let twins = find_constellations(1_000, &[0, 2]).unwrap();
+        let expected: Vec<Vec<usize>> = (0..1_000)
+            .filter(|&p| is_prime(p) && is_prime(p + 2))
+            .map(|p| vec![p, p + 2])
+            .collect();
+        assert_eq!(twins, expected);
+        assert_eq!(twins.len(), 35);
+    }
+
+    #[test]
+    fn test_quadruplets() {
+        let quadruplets = find_constellations(1_000, &[0, 2, 6, 8]).unwrap();
+        assert!(quadruplets.contains(&vec![11, 13, 17, 19]));
+        let starts: Vec<usize> = quadruplets.iter().map(|q| q[0]).collect();
+        assert_eq!(starts, vec![5, 11, 101, 191, 821]);
+    }
+
+    #[test]
+    fn test_start_bound_is_exclusive() {
+        // (11, 13, 17, 19) starts at 11, so needs n > 11 but not n > 19
+        assert!(!find_constellations(11, &[0, 2, 6, 8])
+            .unwrap()
+            .contains(&vec![11, 13, 17, 19]));
+        assert!(find_constellations(12, &[0, 2, 6, 8])
+            .unwrap()
+            .contains(&vec![11, 13, 17, 19]));
+    }
+
+    #[test]
+    fn test_pattern_without_zero_offset() {
+        // [2, 4] is the twin pattern shifted: starts 1, 3, 9 and 15 below 20
+        assert_eq!(
+            find_constellations(20, &[2, 4]).unwrap(),
+            vec![vec![3, 5], vec![5, 7], vec![11, 13], vec![17, 19]]
+        );
+    }
+
+    #[test]
+    fn test_empty_pattern_rejected() {
+        assert!(matches!(
+            find_constellations(100, &[]),
+            Err(PrimeGenError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,101 @@
+//! Prime Number Theorem estimates of π(x)
+//!
+//! Compares the exact prime count with the two classical approximations,
+//! x/ln(x) and the offset logarithmic integral Li(x) = ∫₂ˣ dt/ln(t). Li(x)
+//! is by far the closer of the two: at 10⁶ it is off by about 130, while
+//! x/ln(x) is off by about 6100.
+
+use crate::{count_primes, PrimeGenError, DEFAULT_SEGMENT_SIZE, MAX_N};
+
+/// Simpson intervals used for Li(x); even, as Simpson's rule requires
+const LI_INTERVALS: usize = 10_000;
+
+/// The offset logarithmic integral Li(x) = ∫₂ˣ dt/ln(t), or 0 for x <= 2.
+///
+/// Integrated with Simpson's rule after substituting t = eᵘ, which turns
+/// the integrand into the smooth eᵘ/u over [ln 2, ln x].
+pub fn logarithmic_integral(x: f64) -> f64 {
+    if x <= 2.0 {
+        return 0.0;
+    }
+
+    let (a, b) = (2f64.ln(), x.ln());
+    let h = (b - a) / LI_INTERVALS as f64;
+    let f = |u: f64| u.exp() / u;
+
+    let interior: f64 = (1..LI_INTERVALS)
+        .map(|i| {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            weight * f(a + i as f64 * h)
+        })
+        .sum();
+
+    (f(a) + interior + f(b)) * h / 3.0
+}
+
+/// π(x) alongside its estimates: `(π(x), Li(x), x/ln(x))`.
+///
+/// π(x) counts the primes `<= x`, using [`count_primes`]. Both estimates
+/// are reported as 0 for x < 2.
+///
+/// # Examples
+///
+/// ```
+/// use primes::prime_count_estimates;
+///
+/// let (actual, li, x_ln_x) = prime_count_estimates(1000).unwrap();
+/// assert_eq!(actual, 168);
+/// assert!((li - 176.6).abs() < 0.1);
+/// assert!((x_ln_x - 144.8).abs() < 0.1);
+/// ```
+///
+/// # Errors
+///
+/// Returns `InvalidInput` if x is at least `MAX_N`.
+pub fn prime_count_estimates(x: usize) -> Result<(usize, f64, f64), PrimeGenError> {
+    if x >= MAX_N {
+        return Err(PrimeGenError::InvalidInput(format!(
+            "x ({}) must be below {}",
+            x, MAX_N
+        )));
+    }
+
+    let actual = count_primes(x + 1, DEFAULT_SEGMENT_SIZE, None)?;
+    if x < 2 {
+        return Ok((actual, 0.0, 0.0));
+    }
+
+    let x = x as f64;
+    Ok((actual, logarithmic_integral(x), x / x.ln()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_li_closer_than_x_over_ln_x() {
+        let (actual, li, x_ln_x) = prime_count_estimates(1_000_000).unwrap();
+        assert_eq!(actual, 78_498);
+        assert!((actual as f64 - li).abs() < (actual as f64 - x_ln_x).abs());
+    }
+
+    #[test]
+    fn test_logarithmic_integral_known_values() {
+        // li(x) - li(2), from published li values and li(2) ≈ 1.04516
+        assert_eq!(logarithmic_integral(2.0), 0.0);
+        assert!((logarithmic_integral(1_000.0) - 176.5645).abs() < 1e-3);
+        assert!((logarithmic_integral(1e6) - 78626.5043).abs() < 1e-2);
+        assert!((logarithmic_integral(1e9) - 50849233.91).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_small_x() {
+        assert_eq!(prime_count_estimates(0).unwrap(), (0, 0.0, 0.0));
+        assert_eq!(prime_count_estimates(1).unwrap(), (0, 0.0, 0.0));
+        // π counts x itself when prime
+        assert_eq!(prime_count_estimates(2).unwrap().0, 1);
+        assert_eq!(prime_count_estimates(7).unwrap().0, 4);
+        assert!(prime_count_estimates(MAX_N).is_err());
+    }
+}
@@ -0,0 +1,274 @@
+//! Primality testing and factorization by trial division
+//!
+//! Intended for single numbers (tooltips, classification). For bulk work over
+//! a range, sieving is far faster than factoring each number independently.
+
+/// Test whether `n` is prime by trial division with 6k ± 1 candidates.
+///
+/// # Examples
+///
+/// ```
+/// use primes::is_prime;
+///
+/// assert!(is_prime(97));
+/// assert!(!is_prime(91)); // 7 * 13
+/// assert!(!is_prime(1));
+/// ```
+#[must_use]
+pub fn is_prime(n: usize) -> bool {
+    if n < 4 {
+        return n >= 2;
+    }
+    if n.is_multiple_of(2) || n.is_multiple_of(3) {
+        return false;
+    }
+
+    let mut d = 5;
+    while d <= n / d {
+        if n.is_multiple_of(d) || n.is_multiple_of(d + 2) {
+            return false;
+        }
+        d += 6;
+    }
+    true
+}
+
+/// The smallest prime greater than `n`, found by testing odd candidates
+/// with [`is_prime`].
+///
+/// # Panics
+///
+/// If no prime greater than `n` fits in `usize`.
+///
+/// # Examples
+///
+/// ```
+/// use primes::next_prime;
+///
+/// assert_eq!(next_prime(13), 17);
+/// assert_eq!(next_prime(1), 2);
+/// ```
+#[must_use]
+pub fn next_prime(n: usize) -> usize {
+    if n < 2 {
+        return 2;
+    }
+
+    let no_prime = "no prime above n fits in usize";
+    // The first odd number above n; 2 is already handled
+    let mut candidate = n.checked_add(1 + n % 2).expect(no_prime);
+    while !is_prime(candidate) {
+        candidate = candidate.checked_add(2).expect(no_prime);
+    }
+    candidate
+}
+
+/// The largest prime less than `n`, or `None` when `n <= 2`.
+///
+/// # Examples
+///
+/// ```
+/// use primes::prev_prime;
+///
+/// assert_eq!(prev_prime(17), Some(13));
+/// assert_eq!(prev_prime(3), Some(2));
+/// assert_eq!(prev_prime(2), None);
+/// ```
+#[must_use]
+pub fn prev_prime(n: usize) -> Option<usize> {
+    if n <= 3 {
+        return (n == 3).then_some(2);
+    }
+
+    // The last odd number below n; 3 is prime, so the walk stops by then
+    let mut candidate = n - 1 - n % 2;
+    while !is_prime(candidate) {
+        candidate -= 2;
+    }
+    Some(candidate)
+}
+
+/// Factor `n` into sorted (prime, exponent) pairs using trial division.
+///
+/// Divides out 2 first, then odd candidates up to the square root of the
+/// remaining cofactor. Any cofactor left above 1 is itself prime.
+///
+/// # Examples
+///
+/// ```
+/// use primes::factorize;
+///
+/// assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factorize(97), vec![(97, 1)]);
+/// assert!(factorize(1).is_empty());
+/// ```
+#[must_use]
+pub fn factorize(n: usize) -> Vec<(usize, u32)> {
+    let mut factors = Vec::new();
+    if n < 2 {
+        return factors;
+    }
+
+    let mut remaining = n;
+
+    let twos = remaining.trailing_zeros();
+    if twos > 0 {
+        factors.push((2, twos));
+        remaining >>= twos;
+    }
+
+    let mut d = 3;
+    while d <= remaining / d {
+        if remaining.is_multiple_of(d) {
+            let mut exponent = 0;
+            while remaining.is_multiple_of(d) {
+                remaining /= d;
+                exponent += 1;
+            }
+            factors.push((d, exponent));
+        }
+        d += 2;
+    }
+
+    if remaining > 1 {
+        factors.push((remaining, 1));
+    }
+
+    factors
+}
+
+/// Return the distinct prime factors of `n` in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use primes::distinct_prime_factors;
+///
+/// assert_eq!(distinct_prime_factors(12), vec![2, 3]);
+/// assert!(distinct_prime_factors(1).is_empty());
+/// ```
+#[must_use]
+pub fn distinct_prime_factors(n: usize) -> Vec<usize> {
+    factorize(n).into_iter().map(|(p, _)| p).collect()
+}
+
+/// Return the radical of `n`: the product of its distinct prime factors.
+///
+/// By convention `radical(1) == 1`; `radical(0)` is also reported as 1
+/// since 0 has no prime factorization.
+///
+/// # Examples
+///
+/// ```
+/// use primes::radical;
+///
+/// assert_eq!(radical(360), 30);
+/// assert_eq!(radical(1), 1);
+/// ```
+#[must_use]
+pub fn radical(n: usize) -> usize {
+    factorize(n).into_iter().map(|(p, _)| p).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_prime_matches_sieve() {
+        let sieve = crate::sieve_of_eratosthenes(10_000).unwrap();
+        let trial: Vec<usize> = (0..10_000).filter(|&n| is_prime(n)).collect();
+        assert_eq!(trial, sieve);
+    }
+
+    #[test]
+    fn test_is_prime_large() {
+        assert!(is_prime(999_983));
+        assert!(is_prime(1_000_000_007));
+        assert!(!is_prime(999_983 * 1_000_003));
+        assert!(!is_prime(usize::MAX));
+    }
+
+    #[test]
+    fn test_next_and_prev_prime() {
+        assert_eq!(next_prime(13), 17);
+        assert_eq!(next_prime(1), 2);
+        assert_eq!(next_prime(0), 2);
+        assert_eq!(next_prime(2), 3);
+        assert_eq!(prev_prime(2), None);
+        assert_eq!(prev_prime(0), None);
+        assert_eq!(prev_prime(3), Some(2));
+        assert_eq!(prev_prime(1_000_000_008), Some(1_000_000_007));
+
+        // Consecutive primes step into each other
+        let primes = crate::sieve_of_eratosthenes(10_000).unwrap();
+        for pair in primes.windows(2) {
+            assert_eq!(next_prime(pair[0]), pair[1]);
+            assert_eq!(prev_prime(pair[1]), Some(pair[0]));
+        }
+        for n in 3..10_000 {
+            let next = next_prime(n);
+            assert!(next > n && is_prime(next), "next_prime({})", n);
+            assert!((n + 1..next).all(|k| !is_prime(k)), "next_prime({})", n);
+        }
+    }
+
+    #[test]
+    fn test_factorize_small() {
+        assert!(factorize(0).is_empty());
+        assert!(factorize(1).is_empty());
+        assert_eq!(factorize(2), vec![(2, 1)]);
+        assert_eq!(factorize(12), vec![(2, 2), (3, 1)]);
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+    }
+
+    #[test]
+    fn test_factorize_prime() {
+        for &p in &[3, 13, 97, 7919, 999_983] {
+            assert_eq!(factorize(p), vec![(p, 1)], "Failed for p={}", p);
+        }
+    }
+
+    #[test]
+    fn test_factorize_roundtrip() {
+        for n in 2..5000usize {
+            let factors = factorize(n);
+            let product: usize = factors.iter().map(|&(p, e)| p.pow(e)).product();
+            assert_eq!(product, n, "Failed for n={}", n);
+            assert!(
+                factors.windows(2).all(|w| w[0].0 < w[1].0),
+                "Factors not sorted for n={}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_factorize_large_semiprime() {
+        // 999_983 * 1_000_003, both prime
+        assert_eq!(
+            factorize(999_983 * 1_000_003),
+            vec![(999_983, 1), (1_000_003, 1)]
+        );
+    }
+
+    #[test]
+    fn test_distinct_prime_factors() {
+        assert!(distinct_prime_factors(0).is_empty());
+        assert!(distinct_prime_factors(1).is_empty());
+        assert_eq!(distinct_prime_factors(12), vec![2, 3]);
+        assert_eq!(distinct_prime_factors(360), vec![2, 3, 5]);
+        assert_eq!(distinct_prime_factors(1024), vec![2]);
+    }
+
+    #[test]
+    fn test_radical() {
+        assert_eq!(radical(1), 1);
+        assert_eq!(radical(360), 30);
+        assert_eq!(radical(1024), 2);
+        // Squarefree numbers are their own radical
+        for &n in &[2, 6, 30, 210, 2310] {
+            assert_eq!(radical(n), n, "Failed for n={}", n);
+        }
+    }
+}
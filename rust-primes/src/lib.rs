@@ -6,9 +6,42 @@
 //! - Parallel Segmented Sieve (best for n >= 100M)
 //!
 //! All algorithms use odd-only sieves for 2x memory and work reduction.
+//!
+//! Single-number helpers such as trial-division factorization live in
+//! the [`factor`] module; prime pair families live in [`pairs`], and
+//! general prime k-tuples in [`constellation`]. [`PrimeSet`] pairs a prime
+//! list with a compact membership bitset, and [`estimates`] compares π(x)
+//! with the Prime Number Theorem approximations. [`BitSieve`] iterates the
+//! primes below n from a one-bit-per-odd-number sieve, and [`pi_table`]
+//! saves prime counts at checkpoints for fast repeated π(x) queries.
+//! [`SievePool`] lets repeated sieve runs reuse their flag buffers.
 
 use std::cmp::min;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub mod bit_sieve;
+pub mod constellation;
+pub mod estimates;
+pub mod factor;
+pub mod pairs;
+pub mod pi_table;
+pub mod prime_set;
+pub mod sieve_pool;
+
+pub use bit_sieve::BitSieve;
+pub use constellation::find_constellations;
+pub use estimates::{logarithmic_integral, prime_count_estimates};
+pub use factor::{distinct_prime_factors, factorize, is_prime, next_prime, prev_prime, radical};
+pub use pairs::{
+    count_cousin_primes, count_sexy_primes, count_twin_primes, generate_twin_primes_parallel,
+    generate_twin_primes_up_to,
+};
+pub use pi_table::{build_pi_table, load_pi_table, pi_from_table, save_pi_table};
+pub use prime_set::PrimeSet;
+pub use sieve_pool::SievePool;
 
 /// Default segment size for segmented sieve (1M elements)
 pub const DEFAULT_SEGMENT_SIZE: usize = 1_000_000;
@@ -44,6 +77,37 @@ impl std::fmt::Display for PrimeGenError {
     }
 }
 
+/// Sieve chosen by [`generate_primes`] for a given input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// [`sieve_of_eratosthenes`], for n below `DEFAULT_SEGMENT_SIZE`
+    Classic,
+    /// [`segmented_sieve`]
+    Segmented,
+    /// [`parallel_segmented_sieve`], for parallel runs from `PARALLEL_THRESHOLD`
+    Parallel,
+}
+
+impl std::fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Algorithm::Classic => write!(f, "classic"),
+            Algorithm::Segmented => write!(f, "segmented"),
+            Algorithm::Parallel => write!(f, "parallel"),
+        }
+    }
+}
+
+/// What a [`generate_primes_with_report`] run did
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SieveReport {
+    pub algorithm: Algorithm,
+    /// Wall-clock time of the sieve itself, excluding input validation
+    pub elapsed: Duration,
+    /// Number of primes found
+    pub count: usize,
+}
+
 /// Estimate the number of primes up to n using the Prime Number Theorem.
 /// Returns a safe capacity for Vec::with_capacity (at least 1).
 #[must_use]
@@ -71,7 +135,7 @@ pub fn estimate_prime_count(n: usize) -> usize {
 /// though odd-only indexing means only ~50% are needed.
 ///
 /// Returns primes found in [max(low, 2), high).
-fn sieve_segment_odd_only(
+pub(crate) fn sieve_segment_odd_only(
     low: usize,
     high: usize,
     base_primes_odd: &[usize],
@@ -156,14 +220,53 @@ pub fn sieve_of_eratosthenes(n: usize) -> Result<Vec<usize>, PrimeGenError> {
         return Ok(Vec::new());
     }
 
+    Ok(classic_sieve(n, None))
+}
+
+/// The classic sieve for n >= 3, with its flags taken from `pool` if given.
+fn classic_sieve(n: usize, pool: Option<&SievePool>) -> Vec<usize> {
     if n <= 3 {
-        return Ok(vec![2]);
+        return vec![2];
+    }
+
+    let mut sieve = take_buffer(pool, odd_only_sieve_len(n));
+    odd_only_sieve_into(n, &mut sieve);
+
+    // Extract primes with safe capacity estimation
+    let mut primes = Vec::with_capacity(estimate_prime_count(n));
+    primes.push(2);
+    for (i, &is_p) in sieve.iter().enumerate() {
+        if is_p {
+            let prime = 2 * i + 3;
+            if prime < n {
+                primes.push(prime);
+            }
+        }
     }
 
-    // Odd-only sieve: index i represents number 2*i + 3
-    let sieve_size = (n - 3).div_ceil(2); // count of odd numbers in [3, n)
-    let sieve_size = sieve_size.max(1);
-    let mut sieve = vec![true; sieve_size];
+    release_buffer(pool, sieve);
+    primes
+}
+
+/// Odd-only sieve flags for the classic sieve; n must be at least 4.
+///
+/// Index i represents the number 2*i + 3. The last flag may stand for n
+/// itself when n is odd, so callers must still bound results by n.
+fn odd_only_sieve(n: usize) -> Vec<bool> {
+    let mut sieve = vec![true; odd_only_sieve_len(n)];
+    odd_only_sieve_into(n, &mut sieve);
+    sieve
+}
+
+/// Flag count of [`odd_only_sieve`] for n.
+fn odd_only_sieve_len(n: usize) -> usize {
+    // count of odd numbers in [3, n)
+    (n - 3).div_ceil(2).max(1)
+}
+
+/// Sieve into `sieve`, which must hold `odd_only_sieve_len(n)` true flags.
+fn odd_only_sieve_into(n: usize, sieve: &mut [bool]) {
+    let sieve_size = sieve.len();
 
     let limit = (n as f64).sqrt() as usize;
     let mut current = 3;
@@ -181,27 +284,131 @@ pub fn sieve_of_eratosthenes(n: usize) -> Result<Vec<usize>, PrimeGenError> {
         }
         current += 2;
     }
+}
 
-    // Extract primes with safe capacity estimation
-    let mut primes = Vec::with_capacity(estimate_prime_count(n));
-    primes.push(2);
-    for (i, &is_p) in sieve.iter().enumerate() {
-        if is_p {
-            let prime = 2 * i + 3;
-            if prime < n {
-                primes.push(prime);
+/// Primality flags for every number below `n`: `mask[i]` is true exactly
+/// when i is prime.
+///
+/// Built from the same odd-only sieve as [`sieve_of_eratosthenes`], then
+/// expanded to one flag per number so membership is a single index. Costs
+/// n bytes, so prefer the prime list when n is large.
+///
+/// # Examples
+///
+/// ```
+/// use primes::prime_mask;
+///
+/// let mask = prime_mask(10);
+/// assert_eq!(mask.len(), 10);
+/// assert!(mask[7]);
+/// assert!(!mask[9]);
+/// ```
+pub fn prime_mask(n: usize) -> Vec<bool> {
+    let mut mask = vec![false; n];
+    if n <= 2 {
+        return mask;
+    }
+
+    mask[2] = true;
+    if n > 3 {
+        for (i, &is_p) in odd_only_sieve(n).iter().enumerate() {
+            let number = 2 * i + 3;
+            if number < n {
+                mask[number] = is_p;
             }
         }
     }
 
-    Ok(primes)
+    mask
+}
+
+/// The primes up to sqrt(n) that the segmented sieves cross off with.
+///
+/// [`segmented_sieve`] and [`parallel_segmented_sieve`] compute these
+/// themselves; callers running both for the same `n` can compute them once
+/// and pass the slice to the `_with_base_primes` variants.
+///
+/// # Examples
+///
+/// ```
+/// use primes::{
+///     compute_base_primes, parallel_segmented_sieve_with_base_primes,
+///     segmented_sieve_with_base_primes,
+/// };
+///
+/// let base_primes = compute_base_primes(1000);
+/// assert_eq!(base_primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31]);
+///
+/// let sequential = segmented_sieve_with_base_primes(1000, 100, &base_primes, None).unwrap();
+/// let parallel =
+///     parallel_segmented_sieve_with_base_primes(1000, 2, 100, &base_primes, None).unwrap();
+/// assert_eq!(sequential, parallel);
+/// ```
+#[must_use]
+pub fn compute_base_primes(n: usize) -> Vec<usize> {
+    let base_limit = (n as f64).sqrt() as usize;
+    // sqrt(n) + 1 is always a valid bound, so the classic sieve cannot fail
+    sieve_of_eratosthenes(base_limit + 1).unwrap_or_default()
+}
+
+/// Reusable flag buffer for sieving `[0, n)` in segments of `segment_size`.
+///
+/// No segment is longer than n, so a segment size far above a small n
+/// does not cost a segment-sized allocation.
+pub(crate) fn segment_buffer(n: usize, segment_size: usize) -> Vec<bool> {
+    vec![true; min(segment_size, n)]
+}
+
+/// `len` true flags, from `pool` when there is one.
+fn take_buffer(pool: Option<&SievePool>, len: usize) -> Vec<bool> {
+    match pool {
+        Some(pool) => pool.take(len),
+        None => vec![true; len],
+    }
+}
+
+/// Hand a [`take_buffer`] buffer back to `pool`, or drop it without one.
+fn release_buffer(pool: Option<&SievePool>, buffer: Vec<bool>) {
+    if let Some(pool) = pool {
+        pool.give(buffer);
+    }
+}
+
+/// Check that `base_primes` can sieve everything below `n`.
+///
+/// Every prime p with p² < n has to be there, so the first prime past the
+/// largest one given must square to at least n.
+fn check_base_primes(n: usize, base_primes: &[usize]) -> Result<(), PrimeGenError> {
+    let largest = base_primes.iter().copied().max().unwrap_or(1);
+    let next = (largest.saturating_add(1)..)
+        .find(|&k| is_prime(k))
+        .unwrap_or(usize::MAX);
+    if next.saturating_mul(next) < n {
+        return Err(PrimeGenError::InvalidInput(format!(
+            "base primes up to {} cannot sieve below {}: {} is missing",
+            largest, n, next
+        )));
+    }
+    Ok(())
+}
+
+/// The odd base primes a segment is sieved with: `base_primes` when given,
+/// otherwise computed for `n`, with 2 dropped either way.
+pub(crate) fn odd_base_primes(n: usize, base_primes: Option<&[usize]>) -> Vec<usize> {
+    match base_primes {
+        Some(primes) => primes.iter().copied().filter(|&p| p > 2).collect(),
+        None => compute_base_primes(n)
+            .into_iter()
+            .filter(|&p| p > 2)
+            .collect(),
+    }
 }
 
 /// Segmented Sieve of Eratosthenes (odd-only)
 /// Best for n >= 1,000,000
 ///
 /// Memory: O(sqrt(n) + segment_size). Uses odd-only indexing to store only odd numbers,
-/// allocating `segment_size` bools but using ~`segment_size/2` for actual data.
+/// allocating `min(segment_size, n)` bools but using ~`segment_size/2` for actual data.
 /// This simplifies implementation by avoiding dynamic per-segment allocation.
 ///
 /// # Arguments
@@ -228,23 +435,308 @@ pub fn segmented_sieve(
     segment_size: usize,
     progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
 ) -> Result<Vec<usize>, PrimeGenError> {
+    sieve_segments(n, segment_size, None, progress)
+}
+
+/// [`segmented_sieve`] crossing off with precomputed `base_primes`, such as
+/// a [`compute_base_primes`] result for `n` or a larger bound.
+///
+/// # Errors
+///
+/// Returns `InvalidInput` if `base_primes` stops short of sqrt(n).
+pub fn segmented_sieve_with_base_primes(
+    n: usize,
+    segment_size: usize,
+    base_primes: &[usize],
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Result<Vec<usize>, PrimeGenError> {
+    check_base_primes(n, base_primes)?;
+    sieve_segments(n, segment_size, Some(base_primes), progress)
+}
+
+/// Shared body of [`segmented_sieve`] and its base-primes variant.
+fn sieve_segments(
+    n: usize,
+    segment_size: usize,
+    base_primes: Option<&[usize]>,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Result<Vec<usize>, PrimeGenError> {
+    let primes = segmented_sieve_core(n, segment_size, base_primes, None, None, |_, _| {
+        if let Some(ref callback) = progress {
+            callback(1);
+        }
+    })?;
+    Ok(primes.unwrap_or_default())
+}
+
+/// Segmented sieve reporting running totals after each segment.
+///
+/// Identical to [`segmented_sieve`], but the callback receives
+/// `(segments_done, primes_found)` so callers can estimate progress on both
+/// the segment count and the prime count. `segments_done` counts up to
+/// `n.div_ceil(segment_size)`; `primes_found` ends at the returned length.
+///
+/// # Examples
+///
+/// ```
+/// use primes::segmented_sieve_with_stats;
+/// use std::sync::Arc;
+///
+/// let stats = Arc::new(|segments_done: usize, primes_found: usize| {
+///     let _ = (segments_done, primes_found);
+/// });
+/// let primes = segmented_sieve_with_stats(1000, 100, Some(stats)).unwrap();
+/// assert_eq!(primes.len(), 168);
+/// ```
+pub fn segmented_sieve_with_stats(
+    n: usize,
+    segment_size: usize,
+    progress: Option<Arc<dyn Fn(usize, usize) + Send + Sync>>,
+) -> Result<Vec<usize>, PrimeGenError> {
+    let primes = segmented_sieve_core(
+        n,
+        segment_size,
+        None,
+        None,
+        None,
+        |segments_done, primes_found| {
+            if let Some(ref callback) = progress {
+                callback(segments_done, primes_found);
+            }
+        },
+    )?;
+    Ok(primes.unwrap_or_default())
+}
+
+/// Segmented sieve that can be cancelled between segments.
+///
+/// `stop` is checked before each segment; once it is set the sieve returns
+/// `None` without finishing. Otherwise behaves like [`segmented_sieve`] and
+/// returns `Some(primes)`.
+///
+/// # Examples
+///
+/// ```
+/// use primes::segmented_sieve_cancellable;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// let stop = AtomicBool::new(false);
+/// let primes = segmented_sieve_cancellable(100, 10, &stop, None).unwrap();
+/// assert_eq!(primes.len(), 25);
+///
+/// stop.store(true, Ordering::Relaxed);
+/// assert!(segmented_sieve_cancellable(100, 10, &stop, None).is_none());
+/// ```
+pub fn segmented_sieve_cancellable(
+    n: usize,
+    segment_size: usize,
+    stop: &AtomicBool,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Option<Vec<usize>> {
+    // The base-prime sieve cannot fail, so cancellation is the only way to get None.
+    segmented_sieve_core(n, segment_size, None, Some(stop), None, |_, _| {
+        if let Some(ref callback) = progress {
+            callback(1);
+        }
+    })
+    .ok()
+    .flatten()
+}
+
+/// [`segmented_sieve_cancellable`] with its segment buffer taken from
+/// `pool`, for callers that restart the sieve often.
+///
+/// # Examples
+///
+/// ```
+/// use primes::{segmented_sieve_cancellable_pooled, SievePool};
+/// use std::sync::atomic::AtomicBool;
+///
+/// let pool = SievePool::new();
+/// let stop = AtomicBool::new(false);
+/// for n in [100, 1000, 500] {
+///     assert!(segmented_sieve_cancellable_pooled(n, 100, &stop, None, &pool).is_some());
+/// }
+/// assert_eq!(pool.buffers(), 1);
+/// ```
+pub fn segmented_sieve_cancellable_pooled(
+    n: usize,
+    segment_size: usize,
+    stop: &AtomicBool,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    pool: &SievePool,
+) -> Option<Vec<usize>> {
+    segmented_sieve_core(n, segment_size, None, Some(stop), Some(pool), |_, _| {
+        if let Some(ref callback) = progress {
+            callback(1);
+        }
+    })
+    .ok()
+    .flatten()
+}
+
+/// Count primes below `n` without storing them.
+///
+/// Segments are sieved one at a time and only their prime counts are kept,
+/// so memory stays at O(sqrt(n) + segment_size) however large `n` is. After
+/// each segment `checkpoint` receives `(segment_high, count_so_far)`: the
+/// count of primes below `segment_high`. Persisting the last pair lets a long
+/// count continue later through [`resume_count_primes`].
+///
+/// # Examples
+///
+/// ```
+/// use primes::count_primes;
+/// use std::sync::Arc;
+///
+/// let checkpoint = Arc::new(|segment_high: usize, count_so_far: usize| {
+///     println!("pi({}) = {}", segment_high, count_so_far);
+/// });
+/// assert_eq!(count_primes(1000, 100, Some(checkpoint)).unwrap(), 168);
+/// ```
+pub fn count_primes(
+    n: usize,
+    segment_size: usize,
+    checkpoint: Option<Arc<dyn Fn(usize, usize) + Send + Sync>>,
+) -> Result<usize, PrimeGenError> {
+    resume_count_primes(n, segment_size, (0, 0), checkpoint)
+}
+
+/// Continue a [`count_primes`] run from a saved `(segment_high, count_so_far)`
+/// checkpoint. `(0, 0)` starts from the beginning.
+///
+/// # Examples
+///
+/// ```
+/// use primes::resume_count_primes;
+///
+/// // 25 primes below 100
+/// assert_eq!(resume_count_primes(1000, 100, (100, 25), None).unwrap(), 168);
+/// ```
+pub fn resume_count_primes(
+    n: usize,
+    segment_size: usize,
+    from: (usize, usize),
+    checkpoint: Option<Arc<dyn Fn(usize, usize) + Send + Sync>>,
+) -> Result<usize, PrimeGenError> {
+    if n > MAX_N {
+        return Err(PrimeGenError::InvalidInput(format!(
+            "n ({}) exceeds maximum supported value {}",
+            n, MAX_N
+        )));
+    }
+    if segment_size == 0 {
+        return Err(PrimeGenError::InvalidInput(
+            "segment size must be greater than 0".to_string(),
+        ));
+    }
+
+    let (mut low, mut count) = from;
+    if n <= 2 || low >= n {
+        return Ok(count);
+    }
+
+    let base_primes_odd = odd_base_primes(n, None);
+
+    let mut is_prime = segment_buffer(n, segment_size);
+
+    while low < n {
+        let high = min(low + segment_size, n);
+        count += sieve_segment_odd_only(low, high, &base_primes_odd, &mut is_prime).len();
+
+        if let Some(ref callback) = checkpoint {
+            callback(high, count);
+        }
+        low = high;
+    }
+
+    Ok(count)
+}
+
+/// Count the primes below `n` in each `bucket`-wide window.
+///
+/// Element k is the number of primes in `[k * bucket, (k + 1) * bucket)`,
+/// with the last window cut off at `n`. Like [`count_primes`] this sieves
+/// one segment at a time and keeps only the counts, so memory is
+/// O(sqrt(n) + segment_size + n / bucket).
+///
+/// # Examples
+///
+/// ```
+/// use primes::prime_histogram;
+///
+/// // 2, 3, 5, 7 | 11, 13, 17, 19 | 23, 29 | 31, 37 | 41, 43, 47
+/// assert_eq!(prime_histogram(50, 10).unwrap(), vec![4, 4, 2, 2, 3]);
+/// ```
+pub fn prime_histogram(n: usize, bucket: usize) -> Result<Vec<usize>, PrimeGenError> {
+    if n > MAX_N {
+        return Err(PrimeGenError::InvalidInput(format!(
+            "n ({}) exceeds maximum supported value {}",
+            n, MAX_N
+        )));
+    }
+    if bucket == 0 {
+        return Err(PrimeGenError::InvalidInput(
+            "bucket width must be greater than 0".to_string(),
+        ));
+    }
+
+    let mut counts = vec![0; n.div_ceil(bucket)];
+    if n <= 2 {
+        return Ok(counts);
+    }
+
+    let base_primes_odd = odd_base_primes(n, None);
+    let mut is_prime = segment_buffer(n, DEFAULT_SEGMENT_SIZE);
+
+    let mut low = 0;
+    while low < n {
+        let high = min(low + DEFAULT_SEGMENT_SIZE, n);
+        for p in sieve_segment_odd_only(low, high, &base_primes_odd, &mut is_prime) {
+            counts[p / bucket] += 1;
+        }
+        low = high;
+    }
+
+    Ok(counts)
+}
+
+/// Shared sequential segment loop.
+///
+/// `on_segment` is called after each sieved segment with
+/// `(segments_done, primes_found)`. Returns `Ok(None)` if `stop` is set
+/// before the last segment is sieved. The segment buffer comes from `pool`
+/// when given and goes back to it either way.
+fn segmented_sieve_core(
+    n: usize,
+    segment_size: usize,
+    base_primes: Option<&[usize]>,
+    stop: Option<&AtomicBool>,
+    pool: Option<&SievePool>,
+    mut on_segment: impl FnMut(usize, usize),
+) -> Result<Option<Vec<usize>>, PrimeGenError> {
+    let stopped = || stop.is_some_and(|flag| flag.load(Ordering::Relaxed));
+
     if n <= 2 || segment_size == 0 {
-        return Ok(Vec::new());
+        return Ok((!stopped()).then(Vec::new));
     }
 
-    let base_limit = (n as f64).sqrt() as usize;
-    let all_base_primes = sieve_of_eratosthenes(base_limit + 1)?;
     // Odd base primes only (exclude 2) for segment sieving
-    let base_primes_odd: Vec<usize> = all_base_primes.into_iter().filter(|&p| p > 2).collect();
+    let base_primes_odd = odd_base_primes(n, base_primes);
 
     let segments = n.div_ceil(segment_size);
     // Safe capacity estimation using Prime Number Theorem: π(n) ≈ n / ln(n)
     let mut primes = Vec::with_capacity(estimate_prime_count(n));
 
     // Reusable buffer for segments
-    let mut is_prime = vec![true; segment_size];
+    let mut is_prime = take_buffer(pool, min(segment_size, n));
 
     for seg_idx in 0..segments {
+        if stopped() {
+            release_buffer(pool, is_prime);
+            return Ok(None);
+        }
+
         let low = seg_idx * segment_size;
         let high = min(low + segment_size, n);
 
@@ -255,12 +747,11 @@ pub fn segmented_sieve(
         let seg_primes = sieve_segment_odd_only(low, high, &base_primes_odd, &mut is_prime);
         primes.extend(seg_primes);
 
-        if let Some(ref callback) = progress {
-            callback(1);
-        }
+        on_segment(seg_idx + 1, primes.len());
     }
 
-    Ok(primes)
+    release_buffer(pool, is_prime);
+    Ok(Some(primes))
 }
 
 /// Parallel Segmented Sieve (odd-only)
@@ -296,86 +787,165 @@ pub fn parallel_segmented_sieve(
     workers: usize,
     segment_size: usize,
     progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Result<Vec<usize>, PrimeGenError> {
+    parallel_sieve(n, workers, segment_size, None, progress, None)
+}
+
+/// [`parallel_segmented_sieve`] crossing off with precomputed
+/// `base_primes`, such as a [`compute_base_primes`] result for `n` or a
+/// larger bound.
+///
+/// # Errors
+///
+/// Returns `InvalidInput` if `base_primes` stops short of sqrt(n).
+pub fn parallel_segmented_sieve_with_base_primes(
+    n: usize,
+    workers: usize,
+    segment_size: usize,
+    base_primes: &[usize],
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Result<Vec<usize>, PrimeGenError> {
+    check_base_primes(n, base_primes)?;
+    parallel_sieve(n, workers, segment_size, Some(base_primes), progress, None)
+}
+
+/// [`parallel_segmented_sieve`], with each worker's segment buffer taken
+/// from `pool` when given.
+fn parallel_sieve(
+    n: usize,
+    workers: usize,
+    segment_size: usize,
+    base_primes: Option<&[usize]>,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    pool: Option<&SievePool>,
 ) -> Result<Vec<usize>, PrimeGenError> {
     if n <= 2 || segment_size == 0 {
         return Ok(Vec::new());
     }
 
-    let base_limit = (n as f64).sqrt() as usize;
-    let all_base_primes = sieve_of_eratosthenes(base_limit + 1)?;
     // Odd base primes only (exclude 2) for segment sieving
-    let base_primes_odd: Vec<usize> = all_base_primes.into_iter().filter(|&p| p > 2).collect();
+    let base_primes_odd = odd_base_primes(n, base_primes);
 
     let segments = n.div_ceil(segment_size);
     let num_workers = min(workers, segments);
 
+    // Each worker owns one contiguous range of segments
     let chunk_size = segments.div_ceil(num_workers);
+    let assignments: Vec<Range<usize>> = (0..segments)
+        .step_by(chunk_size)
+        .map(|start| start..min(start + chunk_size, segments))
+        .collect();
+
+    sieve_assigned_segments(
+        n,
+        segment_size,
+        &base_primes_odd,
+        assignments,
+        &progress,
+        pool,
+    )
+}
 
+/// Sieve the segments in `assignments`, one worker thread per entry.
+///
+/// Results are merged by segment index, so the output is sorted whatever
+/// segments each worker was given, as long as every segment of `[0, n)`
+/// appears exactly once. Workers take their segment indices as iterators,
+/// so a contiguous range costs nothing however many segments it spans.
+fn sieve_assigned_segments<A>(
+    n: usize,
+    segment_size: usize,
+    base_primes_odd: &[usize],
+    assignments: Vec<A>,
+    progress: &Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    pool: Option<&SievePool>,
+) -> Result<Vec<usize>, PrimeGenError>
+where
+    A: IntoIterator<Item = usize> + Send,
+{
     std::thread::scope(|s| {
-        let mut handles = Vec::new();
-
-        for worker_idx in 0..num_workers {
-            let start_seg = worker_idx * chunk_size;
-            let end_seg = min(start_seg + chunk_size, segments);
-
-            if start_seg >= segments {
-                break;
-            }
-
-            // Share base_primes by reference instead of cloning --
-            // std::thread::scope guarantees they outlive the spawned threads
-            let base_primes_ref = &base_primes_odd;
-            let progress_ref = &progress;
-
-            handles.push(s.spawn(move || {
-                // Each worker accumulates all primes from its contiguous range
-                // into a single Vec (already sorted since segments are contiguous)
-                let mut worker_primes = Vec::new();
-                let mut is_prime = vec![true; segment_size];
-
-                for seg_idx in start_seg..end_seg {
-                    let low = seg_idx * segment_size;
-                    let high = min(low + segment_size, n);
-
-                    if high <= 2 {
-                        continue;
+        let handles: Vec<_> = assignments
+            .into_iter()
+            .map(|seg_indices| {
+                s.spawn(move || {
+                    // Keep each segment's primes with its index for the merge
+                    let seg_indices = seg_indices.into_iter();
+                    let mut runs = Vec::with_capacity(seg_indices.size_hint().0);
+                    let mut is_prime = take_buffer(pool, min(segment_size, n));
+
+                    for seg_idx in seg_indices {
+                        let low = seg_idx * segment_size;
+                        let high = min(low + segment_size, n);
+
+                        if high <= 2 {
+                            continue;
+                        }
+
+                        let seg_primes =
+                            sieve_segment_odd_only(low, high, base_primes_odd, &mut is_prime);
+                        runs.push((seg_idx, seg_primes));
+
+                        if let Some(ref callback) = progress {
+                            callback(1);
+                        }
                     }
 
-                    let seg_primes =
-                        sieve_segment_odd_only(low, high, base_primes_ref, &mut is_prime);
-                    worker_primes.extend(seg_primes);
-
-                    if let Some(ref callback) = progress_ref {
-                        callback(1);
-                    }
-                }
+                    release_buffer(pool, is_prime);
+                    runs
+                })
+            })
+            .collect();
 
-                worker_primes
-            }));
-        }
-
-        // Workers process contiguous segment ranges, so results are already
-        // in order. Just concatenate worker vectors in order.
-        let mut all_primes = Vec::with_capacity(estimate_prime_count(n));
+        // Every handle is joined, even after a failure: an unjoined panicked
+        // thread would make `thread::scope` itself panic on exit.
+        let mut runs = Vec::new();
+        let mut first_error = None;
         for handle in handles {
             match handle.join() {
-                Ok(worker_primes) => all_primes.extend(worker_primes),
-                Err(e) => {
-                    return Err(PrimeGenError::WorkerThreadPanic(
-                        e.downcast::<String>()
-                            .map(|s| s.to_string())
-                            .unwrap_or_else(|_| "Unknown panic".to_string()),
-                    ));
+                Ok(worker_runs) => runs.extend(worker_runs),
+                Err(payload) => {
+                    first_error.get_or_insert_with(|| panic_message(payload));
                 }
             }
         }
+        if let Some(msg) = first_error {
+            return Err(PrimeGenError::WorkerThreadPanic(msg));
+        }
+
+        // Segments cover disjoint ascending ranges, so ordering the runs by
+        // segment index orders the primes
+        runs.sort_unstable_by_key(|&(seg_idx, _)| seg_idx);
+        let mut all_primes = Vec::with_capacity(estimate_prime_count(n));
+        for (_, seg_primes) in runs {
+            all_primes.extend(seg_primes);
+        }
+        debug_assert!(
+            all_primes.windows(2).all(|w| w[0] < w[1]),
+            "merged primes are not strictly increasing"
+        );
 
         Ok(all_primes)
     })
 }
 
+/// Extract the message from a worker panic payload.
+///
+/// `panic!` with a literal yields a `&'static str`; with format arguments a `String`.
+pub(crate) fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .unwrap_or_else(|| "Unknown panic".to_string()),
+    }
+}
+
 /// Auto-select algorithm based on n
 ///
+/// Returns the primes strictly less than `n`: `generate_primes(7, ...)` stops
+/// at 5. Use [`generate_primes_inclusive`] to include `n` itself.
+///
 /// # Arguments
 /// * `n` - Upper bound (exclusive) for prime generation
 /// * `parallel` - Enable parallel processing for large inputs
@@ -416,8 +986,87 @@ pub fn generate_primes(
     segment_size: Option<usize>,
     progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
 ) -> Result<Vec<usize>, PrimeGenError> {
+    generate_primes_with_report(n, parallel, workers, segment_size, progress)
+        .map(|(primes, _)| primes)
+}
+
+/// Like [`generate_primes`], also reporting which sieve ran, how long it
+/// took and how many primes it found.
+///
+/// # Examples
+///
+/// ```
+/// use primes::{generate_primes_with_report, Algorithm};
+///
+/// let (primes, report) = generate_primes_with_report(100, false, None, None, None)?;
+/// assert_eq!(report.algorithm, Algorithm::Classic);
+/// assert_eq!(report.count, primes.len());
+/// # Ok::<_, primes::PrimeGenError>(())
+/// ```
+pub fn generate_primes_with_report(
+    n: usize,
+    parallel: bool,
+    workers: Option<usize>,
+    segment_size: Option<usize>,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Result<(Vec<usize>, SieveReport), PrimeGenError> {
+    select_and_sieve(
+        n,
+        parallel,
+        workers,
+        segment_size,
+        progress,
+        None,
+        PARALLEL_THRESHOLD,
+    )
+}
+
+/// Like [`generate_primes`], taking the sieve's flag buffers from `pool`
+/// and returning them to it afterwards.
+///
+/// Sieving the same or a smaller n again with the same pool allocates no
+/// new flag buffers.
+pub fn generate_primes_pooled(
+    n: usize,
+    parallel: bool,
+    workers: Option<usize>,
+    segment_size: Option<usize>,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    pool: &SievePool,
+) -> Result<Vec<usize>, PrimeGenError> {
+    select_and_sieve(
+        n,
+        parallel,
+        workers,
+        segment_size,
+        progress,
+        Some(pool),
+        PARALLEL_THRESHOLD,
+    )
+    .map(|(primes, _)| primes)
+}
+
+/// Shared body of the `generate_primes` variants.
+///
+/// Parallel runs start at `parallel_threshold`, which is always
+/// [`PARALLEL_THRESHOLD`] outside of tests that need the parallel path on a
+/// small n.
+fn select_and_sieve(
+    n: usize,
+    parallel: bool,
+    workers: Option<usize>,
+    segment_size: Option<usize>,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    pool: Option<&SievePool>,
+    parallel_threshold: usize,
+) -> Result<(Vec<usize>, SieveReport), PrimeGenError> {
     if n <= 2 {
-        return Ok(Vec::new());
+        let report = SieveReport {
+            algorithm: Algorithm::Classic,
+            elapsed: Duration::ZERO,
+            count: 0,
+        };
+        return Ok((Vec::new(), report));
     }
 
     if n > MAX_N {
@@ -442,13 +1091,62 @@ pub fn generate_primes(
 
     let segment_size = segment_size.unwrap_or(DEFAULT_SEGMENT_SIZE);
 
-    if parallel && n >= PARALLEL_THRESHOLD {
-        parallel_segmented_sieve(n, workers, segment_size, progress)
+    let algorithm = if parallel && n >= parallel_threshold {
+        Algorithm::Parallel
     } else if n >= DEFAULT_SEGMENT_SIZE {
-        segmented_sieve(n, segment_size, progress)
+        Algorithm::Segmented
     } else {
-        sieve_of_eratosthenes(n)
-    }
+        Algorithm::Classic
+    };
+
+    let start = Instant::now();
+    let primes = match algorithm {
+        Algorithm::Parallel => parallel_sieve(n, workers, segment_size, None, progress, pool)?,
+        Algorithm::Segmented => {
+            let on_segment = |_, _| {
+                if let Some(ref callback) = progress {
+                    callback(1);
+                }
+            };
+            segmented_sieve_core(n, segment_size, None, None, pool, on_segment)?.unwrap_or_default()
+        }
+        Algorithm::Classic => classic_sieve(n, pool),
+    };
+    let report = SieveReport {
+        algorithm,
+        elapsed: start.elapsed(),
+        count: primes.len(),
+    };
+
+    Ok((primes, report))
+}
+
+/// Like [`generate_primes`], but includes `n` itself when it is prime.
+///
+/// # Examples
+///
+/// ```
+/// use primes::{generate_primes, generate_primes_inclusive};
+///
+/// assert_eq!(generate_primes_inclusive(7, false, None, None, None)?, vec![2, 3, 5, 7]);
+/// assert_eq!(generate_primes(7, false, None, None, None)?, vec![2, 3, 5]);
+/// # Ok::<_, primes::PrimeGenError>(())
+/// ```
+pub fn generate_primes_inclusive(
+    n: usize,
+    parallel: bool,
+    workers: Option<usize>,
+    segment_size: Option<usize>,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Result<Vec<usize>, PrimeGenError> {
+    // usize::MAX saturates and is rejected by the MAX_N check.
+    generate_primes(
+        n.saturating_add(1),
+        parallel,
+        workers,
+        segment_size,
+        progress,
+    )
 }
 
 #[cfg(test)]
@@ -464,6 +1162,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_prime_mask() {
+        let marked: Vec<usize> = prime_mask(10)
+            .iter()
+            .enumerate()
+            .filter(|&(_, &is_p)| is_p)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(marked, vec![2, 3, 5, 7]);
+
+        assert!(prime_mask(0).is_empty());
+        assert_eq!(prime_mask(3), vec![false, false, true]);
+        // Exclusive of n, like the prime lists
+        assert_eq!(prime_mask(7).len(), 7);
+        assert!(!prime_mask(8)[1]);
+    }
+
+    #[test]
+    fn test_prime_mask_matches_sieve() {
+        for n in [4, 5, 9, 10, 11, 100, 1_001, 10_000] {
+            let mask = prime_mask(n);
+            let primes = sieve_of_eratosthenes(n).unwrap();
+            assert_eq!(mask.len(), n);
+            assert_eq!(mask.iter().filter(|&&is_p| is_p).count(), primes.len());
+            assert!(primes.iter().all(|&p| mask[p]), "Failed for n={}", n);
+        }
+    }
+
     #[test]
     fn test_sieve_empty() {
         assert_eq!(sieve_of_eratosthenes(0).unwrap(), Vec::<usize>::new());
@@ -479,6 +1205,33 @@ mod tests {
         assert_eq!(sieve_of_eratosthenes(6).unwrap(), vec![2, 3, 5]);
     }
 
+    #[test]
+    fn test_sieve_tiny_n() {
+        // n <= 2 and n = 3 return before the odd-only sieve; 4 is its
+        // smallest input, a single flag for 3
+        let expected: [&[usize]; 5] = [&[], &[], &[], &[2], &[2, 3]];
+        for (n, primes) in expected.iter().enumerate() {
+            assert_eq!(sieve_of_eratosthenes(n).unwrap(), *primes, "n={}", n);
+            assert_eq!(segmented_sieve(n, 7, None).unwrap(), *primes, "n={}", n);
+        }
+        assert_eq!(odd_only_sieve(4), vec![true]);
+    }
+
+    #[test]
+    fn test_segment_buffer_bounded_by_n() {
+        assert_eq!(segment_buffer(10, 1_000_000).len(), 10);
+        assert_eq!(segment_buffer(1_000_000, 100).len(), 100);
+        assert_eq!(
+            segmented_sieve(10, 1_000_000, None).unwrap(),
+            vec![2, 3, 5, 7]
+        );
+        assert_eq!(
+            parallel_segmented_sieve(10, 2, 1_000_000, None).unwrap(),
+            vec![2, 3, 5, 7]
+        );
+        assert_eq!(count_primes(10, 1_000_000, None).unwrap(), 4);
+    }
+
     #[test]
     fn test_segmented_matches_classic() {
         for &n in &[100, 500, 1000, 5000] {
@@ -497,6 +1250,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_precomputed_base_primes_match_internal() {
+        for &n in &[3, 10, 100, 1000, 10_007, 100_000] {
+            let base_primes = compute_base_primes(n);
+            assert_eq!(
+                segmented_sieve_with_base_primes(n, 100, &base_primes, None).unwrap(),
+                segmented_sieve(n, 100, None).unwrap(),
+                "segmented, n={}",
+                n
+            );
+            assert_eq!(
+                parallel_segmented_sieve_with_base_primes(n, 3, 100, &base_primes, None).unwrap(),
+                parallel_segmented_sieve(n, 3, 100, None).unwrap(),
+                "parallel, n={}",
+                n
+            );
+        }
+
+        // Base primes for a larger bound also cover n
+        let base_primes = compute_base_primes(1_000_000);
+        assert_eq!(
+            segmented_sieve_with_base_primes(5000, 100, &base_primes, None).unwrap(),
+            sieve_of_eratosthenes(5000).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_short_base_primes_rejected() {
+        // Primes up to 10 miss 11, and 11² < 1_000_000
+        let base_primes = compute_base_primes(100);
+        assert!(matches!(
+            segmented_sieve_with_base_primes(1_000_000, 1000, &base_primes, None),
+            Err(PrimeGenError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            parallel_segmented_sieve_with_base_primes(1_000_000, 2, 1000, &base_primes, None),
+            Err(PrimeGenError::InvalidInput(_))
+        ));
+        assert!(segmented_sieve_with_base_primes(1_000_000, 1000, &[], None).is_err());
+
+        // 7 is the last prime below sqrt(100) even though 7² < 100
+        assert!(segmented_sieve_with_base_primes(100, 10, &[2, 3, 5, 7], None).is_ok());
+        // 121 = 11² is the first bound that needs 11
+        assert!(segmented_sieve_with_base_primes(121, 10, &[2, 3, 5, 7], None).is_ok());
+        assert!(segmented_sieve_with_base_primes(122, 10, &[2, 3, 5, 7], None).is_err());
+    }
+
+    #[test]
+    fn test_prime_histogram_sums_to_count() {
+        for &(n, bucket) in &[
+            (1_000, 10),
+            (1_000, 7),
+            (100_000, 1_000),
+            (2_500_001, 100_000),
+        ] {
+            let histogram = prime_histogram(n, bucket).unwrap();
+            assert_eq!(histogram.len(), n.div_ceil(bucket));
+            assert_eq!(
+                histogram.iter().sum::<usize>(),
+                count_primes(n, DEFAULT_SEGMENT_SIZE, None).unwrap(),
+                "n={} bucket={}",
+                n,
+                bucket
+            );
+        }
+
+        let histogram = prime_histogram(1_000, 10).unwrap();
+        assert_eq!(histogram[0], 4); // 2, 3, 5, 7
+        assert_eq!(histogram[9], 1); // 97
+    }
+
+    #[test]
+    fn test_prime_histogram_edges() {
+        assert_eq!(prime_histogram(0, 10).unwrap(), Vec::<usize>::new());
+        assert_eq!(prime_histogram(3, 10).unwrap(), vec![1]);
+        // The bound is exclusive: 11 is left out of the second window
+        assert_eq!(prime_histogram(11, 10).unwrap(), vec![4, 0]);
+        assert_eq!(
+            prime_histogram(10, 1).unwrap(),
+            vec![0, 0, 1, 1, 0, 1, 0, 1, 0, 0]
+        );
+        assert!(prime_histogram(100, 0).is_err());
+    }
+
     #[test]
     fn test_large_input() {
         let primes = segmented_sieve(1_000_000, DEFAULT_SEGMENT_SIZE, None).unwrap();
@@ -530,6 +1367,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_segmented_with_stats_running_count() {
+        use std::sync::Mutex;
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reports);
+        let callback: Arc<dyn Fn(usize, usize) + Send + Sync> =
+            Arc::new(move |segments_done, primes_found| {
+                sink.lock().unwrap().push((segments_done, primes_found));
+            });
+
+        let primes = segmented_sieve_with_stats(10_000, 1000, Some(callback)).unwrap();
+        assert_eq!(primes, segmented_sieve(10_000, 1000, None).unwrap());
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 10);
+        for window in reports.windows(2) {
+            assert_eq!(window[1].0, window[0].0 + 1);
+            assert!(window[1].1 >= window[0].1);
+        }
+        assert_eq!(*reports.last().unwrap(), (10, primes.len()));
+    }
+
+    #[test]
+    fn test_segmented_cancellable() {
+        let stop = AtomicBool::new(false);
+        let primes = segmented_sieve_cancellable(10_000, 100, &stop, None);
+        assert_eq!(primes, Some(segmented_sieve(10_000, 100, None).unwrap()));
+
+        stop.store(true, Ordering::Relaxed);
+        assert_eq!(segmented_sieve_cancellable(10_000, 100, &stop, None), None);
+        assert_eq!(segmented_sieve_cancellable(0, 100, &stop, None), None);
+    }
+
+    #[test]
+    fn test_segmented_cancellable_mid_run() {
+        use std::sync::atomic::AtomicUsize;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let segments = Arc::new(AtomicUsize::new(0));
+        let (flag, count) = (Arc::clone(&stop), Arc::clone(&segments));
+        let progress: Arc<dyn Fn(usize) + Send + Sync> = Arc::new(move |delta| {
+            if count.fetch_add(delta, Ordering::Relaxed) + delta == 3 {
+                flag.store(true, Ordering::Relaxed);
+            }
+        });
+
+        assert_eq!(
+            segmented_sieve_cancellable(10_000, 100, &stop, Some(progress)),
+            None
+        );
+        assert_eq!(segments.load(Ordering::Relaxed), 3);
+    }
+
     #[test]
     fn test_parallel_various_workers() {
         let expected = segmented_sieve(10000, 100, None).unwrap();
@@ -539,6 +1430,148 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_report_names_algorithm() {
+        let (primes, report) =
+            generate_primes_with_report(DEFAULT_SEGMENT_SIZE - 1, true, None, None, None).unwrap();
+        assert_eq!(report.algorithm, Algorithm::Classic);
+        assert_eq!(report.count, primes.len());
+
+        let (_, report) =
+            generate_primes_with_report(DEFAULT_SEGMENT_SIZE, false, None, None, None).unwrap();
+        assert_eq!(report.algorithm, Algorithm::Segmented);
+
+        // Parallel only from PARALLEL_THRESHOLD; a lowered threshold reaches
+        // the parallel path without sieving 10^8
+        let (_, report) =
+            generate_primes_with_report(DEFAULT_SEGMENT_SIZE, true, Some(4), None, None).unwrap();
+        assert_eq!(report.algorithm, Algorithm::Segmented);
+
+        let threshold = 2 * DEFAULT_SEGMENT_SIZE;
+        let (primes, report) =
+            select_and_sieve(threshold + 1, true, Some(4), None, None, None, threshold).unwrap();
+        assert_eq!(report.algorithm, Algorithm::Parallel);
+        assert_eq!(report.count, 148_933);
+        assert_eq!(primes.len(), report.count);
+    }
+
+    #[test]
+    fn test_parallel_merge_with_shuffled_assignment() {
+        let (n, segment_size) = (10_000, 100);
+        let expected = segmented_sieve(n, segment_size, None).unwrap();
+        let base_primes_odd = odd_base_primes(n, None);
+        let segments = n.div_ceil(segment_size);
+
+        // Round-robin, plus a fixed pseudo-random shuffle of the segments
+        let round_robin: Vec<Vec<usize>> =
+            (0..3).map(|w| (w..segments).step_by(3).collect()).collect();
+        let mut order: Vec<usize> = (0..segments).collect();
+        let mut state: u64 = 12_345;
+        for i in (1..order.len()).rev() {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1);
+            order.swap(i, (state >> 33) as usize % (i + 1));
+        }
+        let shuffled: Vec<Vec<usize>> = order.chunks(7).map(|c| c.to_vec()).collect();
+
+        for assignments in [round_robin, shuffled] {
+            let primes = sieve_assigned_segments(
+                n,
+                segment_size,
+                &base_primes_odd,
+                assignments,
+                &None,
+                None,
+            )
+            .unwrap();
+            assert_eq!(primes, expected);
+        }
+    }
+
+    #[test]
+    fn test_parallel_worker_panic_is_returned() {
+        // A panicking progress callback runs inside every worker thread
+        let progress: Arc<dyn Fn(usize) + Send + Sync> = Arc::new(|_| panic!("worker failed"));
+        let result = parallel_segmented_sieve(10_000, 4, 100, Some(progress));
+        match result {
+            Err(PrimeGenError::WorkerThreadPanic(msg)) => assert_eq!(msg, "worker failed"),
+            other => panic!("expected WorkerThreadPanic, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_panic_message_payloads() {
+        assert_eq!(panic_message(Box::new("literal")), "literal");
+        assert_eq!(panic_message(Box::new(String::from("owned"))), "owned");
+        assert_eq!(panic_message(Box::new(42)), "Unknown panic");
+    }
+
+    #[test]
+    fn test_count_primes_checkpoints() {
+        let checkpoints = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&checkpoints);
+        let total = count_primes(
+            1000,
+            128,
+            Some(Arc::new(move |high: usize, count: usize| {
+                sink.lock().unwrap().push((high, count));
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(total, 168);
+        let checkpoints = checkpoints.lock().unwrap();
+        assert_eq!(checkpoints.len(), 1000usize.div_ceil(128));
+        assert_eq!(checkpoints.last(), Some(&(1000, total)));
+
+        // Each checkpoint is π(segment_high - 1), and resuming from it
+        // reaches the same total
+        for &(high, count) in checkpoints.iter() {
+            assert_eq!(count, sieve_of_eratosthenes(high).unwrap().len());
+            assert_eq!(
+                resume_count_primes(1000, 128, (high, count), None).unwrap(),
+                168
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_primes_matches_sieve() {
+        for n in [0, 1, 2, 3, 10, 100, 1_000, 123_457] {
+            assert_eq!(
+                count_primes(n, 1_000, None).unwrap(),
+                sieve_of_eratosthenes(n).unwrap().len(),
+                "Failed for n={}",
+                n
+            );
+        }
+        assert!(count_primes(100, 0, None).is_err());
+        assert!(count_primes(MAX_N + 1, 1_000, None).is_err());
+    }
+
+    #[test]
+    fn test_generate_primes_inclusive() {
+        let inclusive = generate_primes_inclusive(7, false, None, None, None).unwrap();
+        let exclusive = generate_primes(7, false, None, None, None).unwrap();
+        assert!(inclusive.contains(&7));
+        assert!(!exclusive.contains(&7));
+
+        // Composite bounds behave the same either way
+        assert_eq!(
+            generate_primes_inclusive(8, false, None, None, None).unwrap(),
+            generate_primes(8, false, None, None, None).unwrap()
+        );
+        assert_eq!(
+            generate_primes_inclusive(2, false, None, None, None).unwrap(),
+            vec![2]
+        );
+        assert!(generate_primes_inclusive(1, false, None, None, None)
+            .unwrap()
+            .is_empty());
+        assert!(generate_primes_inclusive(usize::MAX, false, None, None, None).is_err());
+    }
+
     #[test]
     fn test_all_algorithms_exclusive_of_n() {
         let n = 7;
@@ -0,0 +1,287 @@
+//! Prime pair families (twin primes differ by 2, cousin primes by 4,
+//! sexy primes by 6)
+
+use std::cmp::min;
+
+use crate::{
+    generate_primes, odd_base_primes, panic_message, segment_buffer, sieve_segment_odd_only,
+    PrimeGenError, DEFAULT_SEGMENT_SIZE, MAX_N,
+};
+
+/// Generate every prime p <= `max_value` that belongs to a twin prime pair,
+/// i.e. p - 2 or p + 2 is also prime.
+///
+/// Membership is decided by the partner's primality, not by whether the
+/// partner falls within `max_value`, so 17 is included for `max_value = 18`.
+///
+/// # Examples
+///
+/// ```
+/// use primes::generate_twin_primes_up_to;
+///
+/// let twins = generate_twin_primes_up_to(20).unwrap();
+/// assert_eq!(twins, vec![3, 5, 7, 11, 13, 17, 19]);
+/// ```
+pub fn generate_twin_primes_up_to(max_value: usize) -> Result<Vec<usize>, PrimeGenError> {
+    // Sieve far enough to see the upper partner of the largest candidate.
+    let primes = generate_primes(max_value.saturating_add(3), false, None, None, None)?;
+
+    let twins = primes
+        .iter()
+        .enumerate()
+        .filter(|&(i, &p)| {
+            p <= max_value
+                && ((i > 0 && primes[i - 1] + 2 == p)
+                    || primes.get(i + 1).is_some_and(|&q| q == p + 2))
+        })
+        .map(|(_, &p)| p)
+        .collect();
+
+    Ok(twins)
+}
+
+/// Parallel [`generate_twin_primes_up_to`]: the same primes, sieved across
+/// `threads` workers without first collecting every prime.
+///
+/// # Examples
+///
+/// ```
+/// use primes::{generate_twin_primes_parallel, generate_twin_primes_up_to};
+///
+/// assert_eq!(
+///     generate_twin_primes_parallel(10_000, 4).unwrap(),
+///     generate_twin_primes_up_to(10_000).unwrap()
+/// );
+/// ```
+pub fn generate_twin_primes_parallel(
+    max_value: usize,
+    threads: usize,
+) -> Result<Vec<usize>, PrimeGenError> {
+    twin_primes_segmented(max_value, threads, DEFAULT_SEGMENT_SIZE)
+}
+
+/// Each worker sieves a contiguous run of segments over `[0, max_value]`.
+///
+/// A twin pair can straddle two segments, or two workers, so every segment
+/// is sieved two further on each side than it reports: a prime at either
+/// edge of `[low, high)` then sees its partner, and the pair is found by
+/// both neighbours without any exchange between them.
+fn twin_primes_segmented(
+    max_value: usize,
+    threads: usize,
+    segment_size: usize,
+) -> Result<Vec<usize>, PrimeGenError> {
+    if threads == 0 {
+        return Err(PrimeGenError::InvalidInput(
+            "threads must be at least 1".to_string(),
+        ));
+    }
+    if segment_size == 0 {
+        return Err(PrimeGenError::InvalidInput(
+            "segment size must be greater than 0".to_string(),
+        ));
+    }
+
+    // Candidates are p <= max_value; the sieve must still reach p + 2
+    if max_value > MAX_N - 3 {
+        return Err(PrimeGenError::InvalidInput(format!(
+            "max_value ({}) exceeds maximum supported value {}",
+            max_value,
+            MAX_N - 3
+        )));
+    }
+    let limit = max_value + 1;
+    let bound = max_value + 3;
+
+    let base_primes_odd = odd_base_primes(bound, None);
+    let segments = limit.div_ceil(segment_size);
+    let workers = min(threads, segments);
+    let chunk_size = segments.div_ceil(workers);
+
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|worker_idx| {
+                let start_seg = worker_idx * chunk_size;
+                let end_seg = min(start_seg + chunk_size, segments);
+                let base_primes_ref = &base_primes_odd;
+
+                s.spawn(move || {
+                    let mut twins = Vec::new();
+                    // Segments are sieved up to 4 wider than they report
+                    let mut is_prime = segment_buffer(bound, segment_size + 4);
+
+                    for seg_idx in start_seg..end_seg {
+                        let low = seg_idx * segment_size;
+                        let high = min(low + segment_size, limit);
+                        let primes = sieve_segment_odd_only(
+                            low.saturating_sub(2),
+                            min(high + 2, bound),
+                            base_primes_ref,
+                            &mut is_prime,
+                        );
+
+                        twins.extend(primes.iter().enumerate().filter_map(|(i, &p)| {
+                            let twin = (i > 0 && primes[i - 1] + 2 == p)
+                                || primes.get(i + 1).is_some_and(|&q| q == p + 2);
+                            (low <= p && p < high && twin).then_some(p)
+                        }));
+                    }
+
+                    twins
+                })
+            })
+            .collect();
+
+        // Workers cover contiguous ranges, so concatenating keeps the order.
+        // Join them all so a panicked worker cannot panic the scope itself.
+        let mut all_twins = Vec::new();
+        let mut first_error = None;
+        for handle in handles {
+            match handle.join() {
+                Ok(twins) => all_twins.extend(twins),
+                Err(payload) => {
+                    first_error.get_or_insert_with(|| panic_message(payload));
+                }
+            }
+        }
+
+        match first_error {
+            Some(msg) => Err(PrimeGenError::WorkerThreadPanic(msg)),
+            None => Ok(all_twins),
+        }
+    })
+}
+
+/// Count twin prime pairs (p, p + 2) with both members below `n`.
+///
+/// # Examples
+///
+/// ```
+/// use primes::count_twin_primes;
+///
+/// assert_eq!(count_twin_primes(100).unwrap(), 8);
+/// ```
+pub fn count_twin_primes(n: usize) -> Result<usize, PrimeGenError> {
+    count_prime_pairs(n, 2)
+}
+
+/// Count cousin prime pairs (p, p + 4) with both members below `n`.
+pub fn count_cousin_primes(n: usize) -> Result<usize, PrimeGenError> {
+    count_prime_pairs(n, 4)
+}
+
+/// Count sexy prime pairs (p, p + 6) with both members below `n`.
+///
+/// Other primes may lie between the pair members: (5, 11) is counted.
+pub fn count_sexy_primes(n: usize) -> Result<usize, PrimeGenError> {
+    count_prime_pairs(n, 6)
+}
+
+/// Count pairs (p, p + gap) of primes below `n` in one pass over the
+/// sorted prime list, advancing a second cursor to the partner position.
+fn count_prime_pairs(n: usize, gap: usize) -> Result<usize, PrimeGenError> {
+    let primes = generate_primes(n, false, None, None, None)?;
+
+    let mut count = 0;
+    let mut j = 0;
+    for &p in &primes {
+        while j < primes.len() && primes[j] < p + gap {
+            j += 1;
+        }
+        if primes.get(j) == Some(&(p + gap)) {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_twin_primes_small() {
+        assert!(generate_twin_primes_up_to(0).unwrap().is_empty());
+        assert!(generate_twin_primes_up_to(2).unwrap().is_empty());
+        assert_eq!(generate_twin_primes_up_to(3).unwrap(), vec![3]);
+        assert_eq!(
+            generate_twin_primes_up_to(20).unwrap(),
+            vec![3, 5, 7, 11, 13, 17, 19]
+        );
+    }
+
+    #[test]
+    fn test_twin_primes_partner_beyond_bound() {
+        // 17 pairs with 19 even when 19 is out of range
+        assert_eq!(
+            generate_twin_primes_up_to(18).unwrap(),
+            vec![3, 5, 7, 11, 13, 17]
+        );
+    }
+
+    #[test]
+    fn test_twin_primes_excludes_isolated() {
+        let twins = generate_twin_primes_up_to(100).unwrap();
+        for &p in &[2, 23, 37, 47, 53, 67, 79, 83, 89, 97] {
+            assert!(!twins.contains(&p), "{} is not a twin prime", p);
+        }
+        assert_eq!(twins.len(), 15);
+    }
+
+    #[test]
+    fn test_twin_primes_parallel_matches_sequential() {
+        for max_value in [0, 2, 3, 4, 5, 17, 18, 19, 100, 1_000, 10_007] {
+            let expected = generate_twin_primes_up_to(max_value).unwrap();
+            for threads in [1, 2, 3, 8] {
+                // Segments of 1 and 2 put every pair across a boundary
+                for segment_size in [1, 2, 3, 7, 64, 1_000] {
+                    assert_eq!(
+                        twin_primes_segmented(max_value, threads, segment_size).unwrap(),
+                        expected,
+                        "max_value={} threads={} segment={}",
+                        max_value,
+                        threads,
+                        segment_size
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            generate_twin_primes_parallel(200_000, 4).unwrap(),
+            generate_twin_primes_up_to(200_000).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_twin_primes_parallel_invalid_input() {
+        assert!(generate_twin_primes_parallel(100, 0).is_err());
+        assert!(twin_primes_segmented(100, 2, 0).is_err());
+        assert!(generate_twin_primes_parallel(usize::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn test_count_pairs_below_100() {
+        assert_eq!(count_twin_primes(100).unwrap(), 8);
+        assert_eq!(count_cousin_primes(100).unwrap(), 8);
+        assert_eq!(count_sexy_primes(100).unwrap(), 15);
+    }
+
+    #[test]
+    fn test_count_pairs_exclusive_bound() {
+        assert_eq!(count_twin_primes(0).unwrap(), 0);
+        assert_eq!(count_twin_primes(5).unwrap(), 0);
+        assert_eq!(count_twin_primes(6).unwrap(), 1);
+        assert_eq!(count_cousin_primes(7).unwrap(), 0);
+        assert_eq!(count_cousin_primes(8).unwrap(), 1);
+        assert_eq!(count_sexy_primes(11).unwrap(), 0);
+        assert_eq!(count_sexy_primes(12).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_count_pairs_known_values() {
+        // Twin prime pairs below 10^3 and 10^4
+        assert_eq!(count_twin_primes(1_000).unwrap(), 35);
+        assert_eq!(count_twin_primes(10_000).unwrap(), 205);
+    }
+}
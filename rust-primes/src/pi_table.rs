@@ -0,0 +1,221 @@
+//! Precomputed prime counts at regular checkpoints
+//!
+//! A table holds the number of primes below 0, step, 2·step, ..., so any
+//! count below the last checkpoint needs only a sieve over the distance to
+//! the nearest one: O(step) per query after a one-time O(n) build.
+//!
+//! Tables are saved in a small little-endian binary format: the 8-byte
+//! magic `PITABLE1`, then step, entry count and the entries as u64 each.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{
+    odd_base_primes, prime_histogram, segment_buffer, sieve_segment_odd_only, PrimeGenError,
+};
+
+/// Leading bytes of a saved table
+const MAGIC: &[u8; 8] = b"PITABLE1";
+
+/// Prime counts below every multiple of `step` up to `n`.
+///
+/// Entry k is the number of primes below k·step, the same bound convention
+/// as [`crate::count_primes`], so entry 0 is always 0. The last entry is
+/// for the largest multiple of `step` not above `n`.
+///
+/// # Examples
+///
+/// ```
+/// use primes::build_pi_table;
+///
+/// // Primes below 0, 10, 20, 30: 0, 4, 8, 10
+/// assert_eq!(build_pi_table(35, 10).unwrap(), vec![0, 4, 8, 10]);
+/// ```
+pub fn build_pi_table(n: usize, step: usize) -> Result<Vec<usize>, PrimeGenError> {
+    if step == 0 {
+        return Err(PrimeGenError::InvalidInput(
+            "step must be greater than 0".to_string(),
+        ));
+    }
+
+    // Per-window counts, summed into running totals
+    let windows = prime_histogram(n / step * step, step)?;
+    let mut table = Vec::with_capacity(windows.len() + 1);
+    table.push(0);
+    for count in windows {
+        table.push(table[table.len() - 1] + count);
+    }
+
+    Ok(table)
+}
+
+/// Number of primes below `x`, from a [`build_pi_table`] table.
+///
+/// Starts from whichever checkpoint is closer to `x` and sieves only the
+/// gap, adding primes past a lower checkpoint or subtracting those before
+/// an upper one.
+///
+/// # Examples
+///
+/// ```
+/// use primes::{build_pi_table, pi_from_table};
+///
+/// let table = build_pi_table(1000, 100).unwrap();
+/// assert_eq!(pi_from_table(&table, 100, 1000).unwrap(), 168);
+/// // 541 is the 100th prime
+/// assert_eq!(pi_from_table(&table, 100, 541).unwrap(), 99);
+/// ```
+///
+/// # Errors
+///
+/// Returns `InvalidInput` if `step` is 0 or `x` is past the last checkpoint.
+pub fn pi_from_table(table: &[usize], step: usize, x: usize) -> Result<usize, PrimeGenError> {
+    if step == 0 {
+        return Err(PrimeGenError::InvalidInput(
+            "step must be greater than 0".to_string(),
+        ));
+    }
+    let last = table.len().saturating_sub(1).saturating_mul(step);
+    if table.is_empty() || x > last {
+        return Err(PrimeGenError::InvalidInput(format!(
+            "x ({}) is past the table's last checkpoint {}",
+            x, last
+        )));
+    }
+
+    let k = x / step;
+    let below = k * step;
+    if x - below <= step / 2 || k + 1 >= table.len() {
+        Ok(table[k] + count_in(below, x))
+    } else {
+        Ok(table[k + 1] - count_in(x, below + step))
+    }
+}
+
+/// Primes in `[low, high)`, sieved as a single short segment.
+fn count_in(low: usize, high: usize) -> usize {
+    if high <= low {
+        return 0;
+    }
+    let base_primes_odd = odd_base_primes(high, None);
+    let mut is_prime = segment_buffer(high, high - low);
+    sieve_segment_odd_only(low, high, &base_primes_odd, &mut is_prime).len()
+}
+
+/// Write `table` and its `step` to `path`, replacing any existing file.
+pub fn save_pi_table(path: impl AsRef<Path>, step: usize, table: &[usize]) -> io::Result<()> {
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 8 * (table.len() + 2));
+    bytes.extend_from_slice(MAGIC);
+    for value in [step, table.len()].iter().chain(table) {
+        bytes.extend_from_slice(&(*value as u64).to_le_bytes());
+    }
+    fs::write(path, bytes)
+}
+
+/// Read a table saved by [`save_pi_table`], returning `(step, table)`.
+///
+/// # Errors
+///
+/// Returns `InvalidData` if the file is not a table, is truncated, or holds
+/// a value that does not fit in `usize`.
+pub fn load_pi_table(path: impl AsRef<Path>) -> io::Result<(usize, Vec<usize>)> {
+    let bytes = fs::read(path)?;
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+    let body = bytes
+        .strip_prefix(MAGIC.as_slice())
+        .ok_or_else(|| invalid("not a pi table"))?;
+    if body.len() % 8 != 0 {
+        return Err(invalid("truncated pi table"));
+    }
+    let mut values = body.chunks_exact(8).map(|chunk| {
+        let value = u64::from_le_bytes(chunk.try_into().expect("chunks are 8 bytes"));
+        usize::try_from(value).map_err(|_| invalid("pi table value exceeds usize"))
+    });
+
+    let mut header = || {
+        values
+            .next()
+            .unwrap_or_else(|| Err(invalid("truncated pi table")))
+    };
+    let step = header()?;
+    let len = header()?;
+    let table = values.collect::<io::Result<Vec<usize>>>()?;
+    if table.len() != len {
+        return Err(invalid("pi table length does not match its header"));
+    }
+
+    Ok((step, table))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::count_primes;
+
+    #[test]
+    fn test_table_matches_count_primes() {
+        let step = 1_000;
+        let table = build_pi_table(100_500, step).unwrap();
+        assert_eq!(table.len(), 101);
+        for (k, &count) in table.iter().enumerate() {
+            assert_eq!(
+                count,
+                count_primes(k * step, 1_000, None).unwrap(),
+                "k={}",
+                k
+            );
+        }
+    }
+
+    #[test]
+    fn test_pi_from_table_matches_count_primes() {
+        let step = 100;
+        let table = build_pi_table(10_000, step).unwrap();
+        for x in (0..=10_000)
+            .step_by(7)
+            .chain([1, 2, 3, 4, 99, 100, 101, 150, 151])
+        {
+            assert_eq!(
+                pi_from_table(&table, step, x).unwrap(),
+                count_primes(x, 1_000, None).unwrap(),
+                "x={}",
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn test_pi_from_table_rejects_out_of_range() {
+        let table = build_pi_table(1_000, 100).unwrap();
+        assert!(pi_from_table(&table, 100, 1_001).is_err());
+        assert!(pi_from_table(&table, 0, 10).is_err());
+        assert!(pi_from_table(&[], 100, 0).is_err());
+        assert!(build_pi_table(1_000, 0).is_err());
+        assert_eq!(build_pi_table(99, 100).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let path = std::env::temp_dir().join(format!("pi_table_test_{}.bin", std::process::id()));
+        let table = build_pi_table(50_000, 1_000).unwrap();
+
+        save_pi_table(&path, 1_000, &table).unwrap();
+        let (step, loaded) = load_pi_table(&path).unwrap();
+        assert_eq!(step, 1_000);
+        assert_eq!(loaded, table);
+
+        // Corrupt the magic, then cut the file short
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[0] = b'X';
+        fs::write(&path, &bytes).unwrap();
+        assert!(load_pi_table(&path).is_err());
+        bytes[0] = MAGIC[0];
+        bytes.truncate(bytes.len() - 8);
+        fs::write(&path, &bytes).unwrap();
+        assert!(load_pi_table(&path).is_err());
+
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,122 @@
+//! A sorted prime list with fast membership tests
+//!
+//! Callers that need both to iterate primes in order and to ask "is n
+//! prime?" often keep a `Vec` and a `HashSet` side by side. `PrimeSet`
+//! keeps only the `Vec` and builds a one-bit-per-number bitset the first
+//! time membership is queried.
+
+use std::sync::OnceLock;
+
+use crate::{generate_primes, PrimeGenError};
+
+/// Sorted primes plus a lazily built membership bitset.
+#[derive(Debug, Clone, Default)]
+pub struct PrimeSet {
+    primes: Vec<usize>,
+    bits: OnceLock<Vec<u64>>,
+}
+
+impl PrimeSet {
+    /// Every prime below `n`, as produced by [`generate_primes`].
+    pub fn generate(n: usize) -> Result<Self, PrimeGenError> {
+        Ok(Self::from(generate_primes(n, false, None, None, None)?))
+    }
+
+    /// Whether `n` is one of the primes in the set.
+    ///
+    /// The first call builds the bitset, costing (largest prime + 1) bits;
+    /// later calls are a single word lookup.
+    pub fn contains(&self, n: usize) -> bool {
+        let bits = self.bits.get_or_init(|| {
+            let words = self.primes.last().map_or(0, |&max| max / 64 + 1);
+            let mut bits = vec![0u64; words];
+            for &p in &self.primes {
+                bits[p / 64] |= 1 << (p % 64);
+            }
+            bits
+        });
+        bits.get(n / 64)
+            .is_some_and(|word| word & (1 << (n % 64)) != 0)
+    }
+
+    /// The primes in ascending order.
+    pub fn as_slice(&self) -> &[usize] {
+        &self.primes
+    }
+
+    pub fn len(&self) -> usize {
+        self.primes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.primes.is_empty()
+    }
+}
+
+impl From<Vec<usize>> for PrimeSet {
+    /// Wrap an ascending list of primes, such as the output of any of the
+    /// sieves. The list is not re-checked for primality.
+    fn from(primes: Vec<usize>) -> Self {
+        debug_assert!(
+            primes.windows(2).all(|w| w[0] < w[1]),
+            "primes must be strictly ascending"
+        );
+        Self {
+            primes,
+            bits: OnceLock::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_contains_matches_hashset() {
+        let set = PrimeSet::generate(10_000).unwrap();
+        // Reference built by trial division, independent of the sieve
+        let reference: HashSet<usize> = (0..10_000).filter(|&n| crate::is_prime(n)).collect();
+        for n in 0..10_000 {
+            assert_eq!(
+                set.contains(n),
+                reference.contains(&n),
+                "Failed for n={}",
+                n
+            );
+        }
+        // Past the largest prime
+        assert!(!set.contains(10_007));
+        assert!(!set.contains(usize::MAX));
+    }
+
+    #[test]
+    fn test_as_slice_and_len() {
+        let set = PrimeSet::generate(30).unwrap();
+        assert_eq!(set.as_slice(), &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(set.len(), 10);
+        assert!(!set.is_empty());
+    }
+
+    #[test]
+    fn test_empty() {
+        let set = PrimeSet::generate(2).unwrap();
+        assert!(set.is_empty());
+        assert!(!set.contains(0));
+        assert!(!set.contains(2));
+        assert!(!PrimeSet::default().contains(7));
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        // 61 is in the first 64-bit word, 67 in the second
+        let set = PrimeSet::from(vec![2, 61, 67, 127, 131]);
+        for n in [2, 61, 67, 127, 131] {
+            assert!(set.contains(n), "{} should be in the set", n);
+        }
+        for n in [0, 1, 63, 64, 65, 128, 130, 132] {
+            assert!(!set.contains(n), "{} should NOT be in the set", n);
+        }
+    }
+}
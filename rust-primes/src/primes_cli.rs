@@ -1,15 +1,37 @@
-use clap::Parser;
-use std::io::Write;
+use clap::{Parser, ValueEnum};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::thread;
-use std::time::Instant;
 
-use primes::{generate_primes, PARALLEL_THRESHOLD};
-use progress::ProgressBar;
-
-pub const DEFAULT_PROGRESS_SEGMENT_SIZE: usize = 100_000;
+use cli_progress::ProgressBar;
+use miller_rabin_tester::is_probable_prime;
+use num_bigint::BigUint;
+use primes::{generate_primes_with_report, PARALLEL_THRESHOLD};
+
+/// Primes checked by --verify; larger lists are sampled at even spacing
+/// unless --verify-all is given
+pub const VERIFY_SAMPLE_SIZE: usize = 10_000;
+
+/// Separator between primes in the list output
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum Delimiter {
+    #[default]
+    Comma,
+    Newline,
+    Space,
+}
 
-mod progress;
+impl Delimiter {
+    fn as_str(self) -> &'static str {
+        match self {
+            Delimiter::Comma => ", ",
+            Delimiter::Newline => "\n",
+            Delimiter::Space => " ",
+        }
+    }
+}
 
 /// Prime Number Generator - High-performance CLI
 #[derive(Parser, Debug)]
@@ -39,6 +61,26 @@ struct Args {
     /// Only print count (no prime list)
     #[arg(long)]
     quiet: bool,
+
+    /// Write the prime list to this file instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Separator between primes in the list
+    #[arg(short, long, value_enum, default_value_t = Delimiter::Comma)]
+    delimiter: Delimiter,
+
+    /// Cross-check a sample of the primes with Miller-Rabin
+    #[arg(long)]
+    verify: bool,
+
+    /// Cross-check every prime with Miller-Rabin (implies --verify)
+    #[arg(long)]
+    verify_all: bool,
+
+    /// List only every K-th prime; the total still counts them all
+    #[arg(long, value_name = "K")]
+    sample: Option<usize>,
 }
 
 const DEFAULT_SEGMENT_SIZE_CLI: usize = 1_000_000;
@@ -53,6 +95,13 @@ fn main() {
         std::process::exit(1);
     }
 
+    let sample = args.sample.unwrap_or(1);
+
+    if sample == 0 {
+        eprintln!("Error: --sample must be greater than 0");
+        std::process::exit(1);
+    }
+
     let n = match args.n {
         Some(v) => v,
         None => {
@@ -94,29 +143,28 @@ fn main() {
     let algorithm_segment = args.segment.unwrap_or(DEFAULT_SEGMENT_SIZE_CLI);
 
     let progress_ticks = if args.progress {
-        n.div_ceil(DEFAULT_PROGRESS_SEGMENT_SIZE)
+        progress_ticks(n, algorithm_segment)
     } else {
         0
     };
 
-    let compute_start = Instant::now();
-
-    let primes: Vec<usize> = if args.progress {
+    let (primes, report) = if args.progress {
         // note: progress bar completes immediately when classic sieve is used
-        // since generate_primes doesn't invoke the callback in that code path.
+        // since generate_primes doesn't invoke the callback in that code path;
+        // the ETA is only meaningful on the segmented/parallel paths.
         let progress_bar = Arc::new(ProgressBar::new(progress_ticks.max(1), "Generating primes"));
 
         let progress_callback = Arc::clone(&progress_bar);
 
         let handle = thread::spawn(move || {
             let progress_bar = Arc::clone(&progress_bar);
-            let result = generate_primes(
+            let result = generate_primes_with_report(
                 n,
                 args.parallel && n >= PARALLEL_THRESHOLD,
                 Some(workers),
                 Some(algorithm_segment),
                 Some(Arc::new(move |delta: usize| {
-                    progress_callback.update(delta);
+                    progress_callback.inc(delta);
                 })),
             );
 
@@ -126,7 +174,7 @@ fn main() {
 
         match handle.join() {
             Ok(result) => match result {
-                Ok(primes) => primes,
+                Ok(generated) => generated,
                 Err(e) => {
                     eprintln!("Error: Prime generation failed: {:?}", e);
                     std::process::exit(1);
@@ -138,14 +186,14 @@ fn main() {
             }
         }
     } else {
-        match generate_primes(
+        match generate_primes_with_report(
             n,
             args.parallel && n >= PARALLEL_THRESHOLD,
             Some(workers),
             Some(segment),
             None,
         ) {
-            Ok(primes) => primes,
+            Ok(generated) => generated,
             Err(e) => {
                 eprintln!("Error: Prime generation failed: {:?}", e);
                 std::process::exit(1);
@@ -153,24 +201,47 @@ fn main() {
         }
     };
 
-    let compute_time = compute_start.elapsed();
+    let compute_time = report.elapsed;
+
+    if args.verify || args.verify_all {
+        let (checked, failures) = verify_primes(&primes, args.verify_all);
+        if failures.is_empty() {
+            eprintln!(
+                "Verified {} of {} primes with Miller-Rabin: all prime.",
+                checked,
+                primes.len()
+            );
+        } else {
+            eprintln!(
+                "Error: Miller-Rabin rejected {} of {} checked primes: {:?}",
+                failures.len(),
+                checked,
+                failures
+            );
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(path) = &args.output {
+        if let Err(e) = write_primes_to_file(path, n, &primes, args.delimiter, sample) {
+            eprintln!("Error: Failed to write '{}': {}", path.display(), e);
+            std::process::exit(1);
+        }
+    }
 
     if !primes.is_empty() {
-        if !args.quiet {
+        if args.quiet {
+            println!("{}", primes.len());
+        } else if args.output.is_none() {
             // Stream output with BufWriter to avoid building a huge String in memory
-            let stdout = std::io::stdout();
-            let mut writer = std::io::BufWriter::new(stdout.lock());
-            writeln!(writer, "Primes less than {}:", n).unwrap();
-            for (i, &p) in primes.iter().enumerate() {
-                if i > 0 {
-                    write!(writer, ", ").unwrap();
-                }
-                write!(writer, "{}", p).unwrap();
+            let stdout = io::stdout();
+            let mut writer = BufWriter::new(stdout.lock());
+            if let Err(e) = write_primes(&mut writer, n, &primes, args.delimiter, sample)
+                .and_then(|()| writer.flush())
+            {
+                eprintln!("Error: Failed to write primes: {}", e);
+                std::process::exit(1);
             }
-            writeln!(writer).unwrap();
-            writeln!(writer, "Total primes: {}", primes.len()).unwrap();
-        } else {
-            println!("{}", primes.len());
         }
     } else {
         println!("No primes less than {}", n);
@@ -180,23 +251,105 @@ fn main() {
 
     if primes.is_empty() {
         eprintln!(
-            "Done! Generated 0 primes in {:.3}s (0 primes/s).",
-            compute_time.as_secs_f64()
+            "Done! Generated 0 primes in {:.3}s (0 primes/s, {} sieve).",
+            compute_time.as_secs_f64(),
+            report.algorithm
         );
     } else {
         let last_prime = *primes.last().unwrap();
         let rate_str = format_number(rate as usize);
         eprintln!(
-            "Done! Largest prime < {} is {}. Generated {} primes in {:.3}s ({} primes/s).",
+            "Done! Largest prime < {} is {}. Generated {} primes in {:.3}s ({} primes/s, {} sieve).",
             n,
             last_prime,
-            primes.len(),
+            report.count,
             compute_time.as_secs_f64(),
-            rate_str
+            rate_str,
+            report.algorithm
         );
     }
 }
 
+/// Confirm sieve output with Miller-Rabin.
+///
+/// Checks every prime when `all` is set or the list is at most
+/// `VERIFY_SAMPLE_SIZE` long, and otherwise an evenly spaced sample that
+/// always includes the largest prime. Returns how many primes were checked
+/// and those Miller-Rabin rejected, which would point to a sieve bug.
+fn verify_primes(primes: &[usize], all: bool) -> (usize, Vec<usize>) {
+    let stride = if all || primes.len() <= VERIFY_SAMPLE_SIZE {
+        1
+    } else {
+        primes.len().div_ceil(VERIFY_SAMPLE_SIZE)
+    };
+
+    let mut sample: Vec<usize> = primes.iter().step_by(stride).copied().collect();
+    if let Some(&last) = primes.last() {
+        if sample.last() != Some(&last) {
+            sample.push(last);
+        }
+    }
+
+    let failures = sample
+        .iter()
+        .copied()
+        .filter(|&p| !is_probable_prime(&BigUint::from(p)))
+        .collect();
+    (sample.len(), failures)
+}
+
+/// Every `k`-th prime: the k-th, 2k-th, 3k-th, ... For k = 1, all of them.
+fn sample_primes(primes: &[usize], k: usize) -> impl Iterator<Item = usize> + '_ {
+    primes.iter().skip(k - 1).step_by(k).copied()
+}
+
+/// Write the prime list block: a header, the primes separated by
+/// `delimiter`, and a total line.
+///
+/// With `sample` above 1 only every `sample`-th prime is listed, and the
+/// header says so; the total line still counts every prime.
+fn write_primes(
+    writer: &mut impl Write,
+    n: usize,
+    primes: &[usize],
+    delimiter: Delimiter,
+    sample: usize,
+) -> io::Result<()> {
+    if sample > 1 {
+        writeln!(writer, "Primes less than {} (1 in {}):", n, sample)?;
+    } else {
+        writeln!(writer, "Primes less than {}:", n)?;
+    }
+    for (i, p) in sample_primes(primes, sample).enumerate() {
+        if i > 0 {
+            write!(writer, "{}", delimiter.as_str())?;
+        }
+        write!(writer, "{}", p)?;
+    }
+    writeln!(writer)?;
+    writeln!(writer, "Total primes: {}", primes.len())
+}
+
+/// Write the prime list block to a new file at `path`, replacing any
+/// existing file.
+fn write_primes_to_file(
+    path: &Path,
+    n: usize,
+    primes: &[usize],
+    delimiter: Delimiter,
+    sample: usize,
+) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_primes(&mut writer, n, primes, delimiter, sample)?;
+    writer.flush()
+}
+
+/// Progress bar total for sieving below n: the sieve ticks once per
+/// segment of `segment_size` numbers
+fn progress_ticks(n: usize, segment_size: usize) -> usize {
+    n.div_ceil(segment_size)
+}
+
 /// Format a number with comma separators (e.g., 1234567 -> "1,234,567")
 fn format_number(n: usize) -> String {
     let s = n.to_string();
@@ -218,6 +371,140 @@ fn format_number(n: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use primes::generate_primes;
+
+    #[test]
+    fn test_write_primes_to_file() {
+        let path = std::env::temp_dir().join(format!("primes_cli_test_{}.txt", std::process::id()));
+        write_primes_to_file(
+            &path,
+            20,
+            &[2, 3, 5, 7, 11, 13, 17, 19],
+            Delimiter::Comma,
+            1,
+        )
+        .unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            content,
+            "Primes less than 20:\n2, 3, 5, 7, 11, 13, 17, 19\nTotal primes: 8\n"
+        );
+    }
+
+    #[test]
+    fn test_write_primes_to_file_bad_path() {
+        let path = std::env::temp_dir()
+            .join("primes_cli_missing_dir")
+            .join("primes.txt");
+        assert!(write_primes_to_file(&path, 10, &[2, 3, 5, 7], Delimiter::Comma, 1).is_err());
+    }
+
+    #[test]
+    fn test_verify_primes() {
+        let primes = generate_primes(100_000, false, None, None, None).unwrap();
+
+        let (checked, failures) = verify_primes(&primes, true);
+        assert_eq!(checked, primes.len());
+        assert!(failures.is_empty(), "{:?}", failures);
+
+        // 9592 primes fit in one sample, so sampling checks them all too
+        assert_eq!(verify_primes(&primes, false), (primes.len(), Vec::new()));
+    }
+
+    #[test]
+    fn test_verify_primes_samples_large_lists() {
+        let primes = generate_primes(1_000_000, false, None, None, None).unwrap();
+        let (checked, failures) = verify_primes(&primes, false);
+        assert!(checked <= VERIFY_SAMPLE_SIZE + 1);
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn test_verify_primes_reports_composites() {
+        let (checked, failures) = verify_primes(&[2, 3, 5, 7, 9, 11, 561], true);
+        assert_eq!(checked, 7);
+        assert_eq!(failures, vec![9, 561]);
+    }
+
+    #[test]
+    fn test_write_primes_delimiters() {
+        let format = |delimiter| {
+            let mut out = Vec::new();
+            write_primes(&mut out, 10, &[2, 3, 5, 7], delimiter, 1).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        assert_eq!(
+            format(Delimiter::Comma),
+            "Primes less than 10:\n2, 3, 5, 7\nTotal primes: 4\n"
+        );
+        assert_eq!(
+            format(Delimiter::Newline),
+            "Primes less than 10:\n2\n3\n5\n7\nTotal primes: 4\n"
+        );
+        assert_eq!(
+            format(Delimiter::Space),
+            "Primes less than 10:\n2 3 5 7\nTotal primes: 4\n"
+        );
+        assert_eq!(Delimiter::default(), Delimiter::Comma);
+    }
+
+    #[test]
+    fn test_sample_primes_every_third() {
+        let primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+        assert_eq!(
+            sample_primes(&primes, 3).collect::<Vec<_>>(),
+            vec![5, 13, 23]
+        );
+        assert_eq!(sample_primes(&primes, 1).collect::<Vec<_>>(), primes);
+        assert_eq!(sample_primes(&primes, 11).count(), 0);
+    }
+
+    #[test]
+    fn test_write_primes_sampled_keeps_true_total() {
+        let mut out = Vec::new();
+        write_primes(
+            &mut out,
+            30,
+            &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29],
+            Delimiter::Comma,
+            3,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Primes less than 30 (1 in 3):\n5, 13, 23\nTotal primes: 10\n"
+        );
+    }
+
+    #[test]
+    fn test_progress_ticks_match_sieve() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let n = 3_000_017;
+        for segment_size in [100_000, DEFAULT_SEGMENT_SIZE_CLI] {
+            let ticks = Arc::new(AtomicUsize::new(0));
+            let counter = Arc::clone(&ticks);
+            generate_primes(
+                n,
+                false,
+                None,
+                Some(segment_size),
+                Some(Arc::new(move |delta: usize| {
+                    counter.fetch_add(delta, Ordering::Relaxed);
+                })),
+            )
+            .unwrap();
+            assert_eq!(
+                ticks.load(Ordering::Relaxed),
+                progress_ticks(n, segment_size),
+                "segment_size={}",
+                segment_size
+            );
+        }
+    }
 
     #[test]
     fn test_format_number() {
@@ -0,0 +1,130 @@
+//! Reusable sieve flag buffers
+//!
+//! Every sieve run allocates a flag buffer: n/2 bools for the classic
+//! sieve, one segment per thread for the segmented ones. A caller that
+//! sieves over and over, such as a GUI regenerating primes while a slider
+//! moves, can hand the same [`SievePool`] to each run so those buffers are
+//! allocated once and then reused.
+
+use std::sync::Mutex;
+
+/// Flag buffers kept between sieve runs.
+///
+/// A run takes one buffer per thread and returns it when done, so the pool
+/// grows to the most threads used at once and holds their memory until it
+/// is dropped. Safe to share between threads and concurrent runs.
+///
+/// # Examples
+///
+/// ```
+/// use primes::{generate_primes_pooled, SievePool};
+///
+/// let pool = SievePool::new();
+/// let first = generate_primes_pooled(10_000, false, None, None, None, &pool)?;
+/// let again = generate_primes_pooled(10_000, false, None, None, None, &pool)?;
+/// assert_eq!(first, again);
+/// assert_eq!(pool.buffers(), 1);
+/// # Ok::<_, primes::PrimeGenError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct SievePool {
+    buffers: Mutex<Vec<Vec<bool>>>,
+}
+
+impl SievePool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of idle buffers in the pool.
+    pub fn buffers(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Combined capacity, in flags, of the idle buffers.
+    pub fn capacity(&self) -> usize {
+        self.lock().iter().map(Vec::capacity).sum()
+    }
+
+    /// A buffer of `len` true flags, reusing an idle one when there is one.
+    ///
+    /// Takes the largest idle buffer, so a shorter run after a longer one
+    /// does not reallocate.
+    pub(crate) fn take(&self, len: usize) -> Vec<bool> {
+        let mut buffers = self.lock();
+        let largest = (0..buffers.len()).max_by_key(|&i| buffers[i].capacity());
+        let mut buffer = largest.map_or_else(Vec::new, |i| buffers.swap_remove(i));
+        buffer.clear();
+        buffer.resize(len, true);
+        buffer
+    }
+
+    /// Return a buffer for a later run to reuse.
+    pub(crate) fn give(&self, buffer: Vec<bool>) {
+        self.lock().push(buffer);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Vec<bool>>> {
+        // A panic while holding the lock cannot leave a buffer list in an
+        // unusable state, so a poisoned pool is still fine to use
+        self.buffers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate_primes, generate_primes_pooled, select_and_sieve, DEFAULT_SEGMENT_SIZE};
+
+    #[test]
+    fn test_take_reuses_largest_buffer() {
+        let pool = SievePool::new();
+        let buffer = pool.take(1_000);
+        assert!(buffer.iter().all(|&flag| flag));
+        let capacity = buffer.capacity();
+        pool.give(buffer);
+        pool.give(Vec::with_capacity(10));
+
+        let buffer = pool.take(500);
+        assert_eq!(buffer.len(), 500);
+        assert_eq!(buffer.capacity(), capacity);
+        assert_eq!(pool.buffers(), 1);
+    }
+
+    #[test]
+    fn test_pooled_matches_unpooled_and_reuses_buffers() {
+        // One buffer serves a whole classic or segmented run
+        for n in [50_000, DEFAULT_SEGMENT_SIZE * 3 + 17] {
+            let expected = generate_primes(n, false, None, None, None).unwrap();
+            let pool = SievePool::new();
+
+            let first = generate_primes_pooled(n, false, None, None, None, &pool).unwrap();
+            let capacity = pool.capacity();
+            assert_eq!(pool.buffers(), 1, "n={}", n);
+
+            let second = generate_primes_pooled(n, false, None, None, None, &pool).unwrap();
+            assert_eq!(first, expected, "n={}", n);
+            assert_eq!(second, expected, "n={}", n);
+            assert_eq!(pool.buffers(), 1, "n={}", n);
+            assert_eq!(pool.capacity(), capacity, "n={}", n);
+        }
+    }
+
+    #[test]
+    fn test_pooled_parallel_buffer_per_worker() {
+        // How many buffers a run leaves depends on how the workers overlap,
+        // but never more than one per worker, each one segment long. The
+        // parallel threshold is lowered so a few segments are enough.
+        let n = 8 * DEFAULT_SEGMENT_SIZE;
+        let expected = generate_primes(n, false, None, None, None).unwrap();
+        let pool = SievePool::new();
+        for _ in 0..2 {
+            let (primes, report) =
+                select_and_sieve(n, true, Some(4), None, None, Some(&pool), n).unwrap();
+            assert_eq!(report.algorithm, crate::Algorithm::Parallel);
+            assert_eq!(primes, expected);
+            assert!((1..=4).contains(&pool.buffers()));
+            assert_eq!(pool.capacity(), pool.buffers() * DEFAULT_SEGMENT_SIZE);
+        }
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Run with: cargo test --test cli_integration
 
+#![cfg(feature = "verify")]
+
 use assert_cmd::cargo_bin_cmd;
 use predicates::prelude::*;
 
@@ -67,6 +69,75 @@ fn test_segment_size_validation() {
         .stderr(predicate::str::contains("segment").or(predicate::str::contains("Segment")));
 }
 
+#[test]
+fn test_output_file() {
+    let path = std::env::temp_dir().join(format!("primes_cli_output_{}.txt", std::process::id()));
+    let mut cmd = cargo_bin_cmd!("primes_cli");
+    cmd.args(["-n", "10", "--output"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicate::str::contains("Done!"));
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        content,
+        "Primes less than 10:\n2, 3, 5, 7\nTotal primes: 4\n"
+    );
+}
+
+#[test]
+fn test_newline_delimiter() {
+    let mut cmd = cargo_bin_cmd!("primes_cli");
+    cmd.args(["-n", "10", "--delimiter", "newline"])
+        .assert()
+        .success()
+        .stdout("Primes less than 10:\n2\n3\n5\n7\nTotal primes: 4\n");
+}
+
+#[test]
+fn test_sample_flag() {
+    let mut cmd = cargo_bin_cmd!("primes_cli");
+    cmd.args(["-n", "30", "--sample", "3"])
+        .assert()
+        .success()
+        .stdout("Primes less than 30 (1 in 3):\n5, 13, 23\nTotal primes: 10\n");
+}
+
+#[test]
+fn test_sample_zero_rejected() {
+    let mut cmd = cargo_bin_cmd!("primes_cli");
+    cmd.args(["-n", "30", "--sample", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--sample"));
+}
+
+#[test]
+fn test_verify_flag() {
+    let mut cmd = cargo_bin_cmd!("primes_cli");
+    cmd.args(["-n", "100000", "--quiet", "--verify"])
+        .assert()
+        .success()
+        .stdout("9592\n")
+        .stderr(predicate::str::contains("Verified 9592 of 9592 primes"));
+}
+
+#[test]
+fn test_output_file_unwritable() {
+    let path = std::env::temp_dir()
+        .join("primes_cli_missing_dir")
+        .join("primes.txt");
+    let mut cmd = cargo_bin_cmd!("primes_cli");
+    cmd.args(["-n", "10", "--output"])
+        .arg(&path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to write"));
+}
+
 #[test]
 fn test_parallel_flag_below_threshold() {
     let mut cmd = cargo_bin_cmd!("primes_cli");
@@ -0,0 +1,81 @@
+//! Every prime generator in the crate must agree on the primes below n
+//!
+//! The sieves share no code beyond the odd-only layout, so this is the
+//! single check that they stay interchangeable.
+//!
+//! Run with: cargo test --test sieve_consistency
+
+use primes::{
+    generate_primes, is_prime, parallel_segmented_sieve, prime_mask, segmented_sieve,
+    sieve_of_eratosthenes, BitSieve, PrimeSet,
+};
+
+/// Bounds around the small-n special cases, 64-bit word edges and segment
+/// edges, plus one past the default segment size.
+const BOUNDS: &[usize] = &[
+    0, 1, 2, 3, 4, 5, 10, 63, 64, 65, 127, 128, 129, 1_000, 9_973, 65_536, 1_000_003,
+];
+
+/// Trial division, independent of every sieve
+fn reference(n: usize) -> Vec<usize> {
+    (0..n).filter(|&k| is_prime(k)).collect()
+}
+
+#[test]
+fn test_all_generators_agree() {
+    for &n in BOUNDS {
+        let expected = sieve_of_eratosthenes(n).unwrap();
+        if n <= 10_000 {
+            assert_eq!(expected, reference(n), "classic sieve, n={}", n);
+        }
+
+        for segment_size in [7, 100, 32_768] {
+            assert_eq!(
+                segmented_sieve(n, segment_size, None).unwrap(),
+                expected,
+                "segmented sieve, n={} segment={}",
+                n,
+                segment_size
+            );
+        }
+        assert_eq!(
+            parallel_segmented_sieve(n, 3, 1_000, None).unwrap(),
+            expected,
+            "parallel sieve, n={}",
+            n
+        );
+        assert_eq!(
+            BitSieve::new(n).collect::<Vec<_>>(),
+            expected,
+            "bit sieve, n={}",
+            n
+        );
+
+        let from_mask: Vec<usize> = prime_mask(n)
+            .iter()
+            .enumerate()
+            .filter_map(|(k, &p)| p.then_some(k))
+            .collect();
+        assert_eq!(from_mask, expected, "prime mask, n={}", n);
+
+        let set = PrimeSet::generate(n).unwrap();
+        assert_eq!(set.as_slice(), &expected[..], "prime set, n={}", n);
+    }
+}
+
+#[test]
+fn test_generate_primes_paths_agree() {
+    // generate_primes picks a sieve by size; each choice must match
+    for &n in BOUNDS {
+        let expected = sieve_of_eratosthenes(n).unwrap();
+        for parallel in [false, true] {
+            assert_eq!(
+                generate_primes(n, parallel, Some(2), Some(1_000), None).unwrap(),
+                expected,
+                "generate_primes, n={} parallel={}",
+                n,
+                parallel
+            );
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Lightweight progress bar shared by the command-line tools
+//!
+//! Draws to stderr with a carriage return and no external dependencies.
+//! The bar is thread-safe, so worker threads can report into one `Arc`
+//! directly, and redraws are throttled to one per update interval.
+
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default progress bar width in characters
+pub const PROGRESS_BAR_WIDTH: usize = 40;
+
+/// Minimum time between progress redraws
+pub const PROGRESS_UPDATE_INTERVAL_MS: u64 = 50;
+
+/// Decides when a redraw is due: at most once per `interval`, except that
+/// a forced redraw (such as reaching the total) always goes through.
+#[derive(Debug)]
+struct Throttle {
+    interval: Duration,
+    last: Instant,
+}
+
+impl Throttle {
+    fn new(interval: Duration, start: Instant) -> Self {
+        Self {
+            interval,
+            last: start,
+        }
+    }
+
+    /// Whether to redraw at `now`, recording it as the last redraw if so.
+    fn ready(&mut self, now: Instant, force: bool) -> bool {
+        if force || now.saturating_duration_since(self.last) >= self.interval {
+            self.last = now;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+struct ProgressState {
+    completed: usize,
+    throttle: Throttle,
+}
+
+/// A thread-safe terminal progress bar.
+///
+/// # Examples
+///
+/// ```
+/// use cli_progress::ProgressBar;
+/// use std::sync::Arc;
+///
+/// let progress = Arc::new(ProgressBar::new(100, "Working"));
+/// progress.inc(10);
+/// progress.set_position(50); // jump straight to 50/100
+/// progress.finish();
+/// ```
+pub struct ProgressBar {
+    total: usize,
+    state: Mutex<ProgressState>,
+    width: usize,
+    description: String,
+    start_time: Instant,
+}
+
+impl ProgressBar {
+    /// Creates a bar counting up to `total`, labelled with `description`.
+    pub fn new(total: usize, description: &str) -> Self {
+        let start_time = Instant::now();
+        Self {
+            total,
+            state: Mutex::new(ProgressState {
+                completed: 0,
+                throttle: Throttle::new(
+                    Duration::from_millis(PROGRESS_UPDATE_INTERVAL_MS),
+                    start_time,
+                ),
+            }),
+            width: PROGRESS_BAR_WIDTH,
+            description: description.to_string(),
+            start_time,
+        }
+    }
+
+    /// The count the bar runs up to.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Advances the bar by `delta` steps.
+    pub fn inc(&self, delta: usize) {
+        self.advance(|completed| completed.saturating_add(delta));
+    }
+
+    /// Advances the bar by `delta` steps; the same as [`inc`](Self::inc),
+    /// under the name the Miller-Rabin bar has always used.
+    pub fn update(&self, delta: usize) {
+        self.inc(delta);
+    }
+
+    /// Moves the bar to `completed` steps, for callers that track an
+    /// absolute position rather than deltas. A position behind the current
+    /// one is ignored, so reports arriving out of order from several
+    /// threads never move the bar backwards.
+    pub fn set_position(&self, completed: usize) {
+        self.advance(|current| current.max(completed));
+    }
+
+    /// Marks the bar as complete: renders it at 100% and ends the line.
+    pub fn finish(&self) {
+        self.render(self.total);
+        eprintln!();
+    }
+
+    fn advance(&self, next: impl FnOnce(usize) -> usize) {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| {
+            eprintln!("[WARN] Progress callback interrupted by thread panic, recovering state");
+            poisoned.into_inner()
+        });
+        state.completed = next(state.completed);
+        let completed = state.completed;
+
+        if state
+            .throttle
+            .ready(Instant::now(), completed >= self.total)
+        {
+            drop(state);
+            self.render(completed);
+        }
+    }
+
+    fn render(&self, completed: usize) {
+        if self.total == 0 {
+            return;
+        }
+
+        let percent = (completed as f64 / self.total as f64).min(1.0);
+        let filled = (percent * self.width as f64) as usize;
+
+        let filled_str = "=".repeat(filled);
+        let empty_str = " ".repeat(self.width.saturating_sub(filled));
+
+        let elapsed = self.start_time.elapsed().as_secs_f64();
+        let rate = if elapsed > 0.0 {
+            completed as f64 / elapsed
+        } else {
+            0.0
+        };
+        let rate_str = format_rate(rate);
+        let eta_str = format_eta(elapsed, percent);
+
+        eprint!(
+            "\r{}: [{}{}] {:3.0}% | {}/{} | {} | {}    ",
+            self.description,
+            filled_str,
+            empty_str,
+            percent * 100.0,
+            completed,
+            self.total,
+            rate_str,
+            eta_str,
+        );
+        let _ = std::io::stderr().flush();
+    }
+}
+
+fn format_rate(rate: f64) -> String {
+    if rate >= 1_000_000.0 {
+        format!("{:.1}M/s", rate / 1_000_000.0)
+    } else if rate >= 1_000.0 {
+        format!("{:.1}K/s", rate / 1_000.0)
+    } else {
+        format!("{:.0}/s", rate)
+    }
+}
+
+/// Estimate the time remaining from `elapsed_secs` and the completed
+/// `fraction` (0.0-1.0), assuming the rate so far holds.
+fn format_eta(elapsed_secs: f64, fraction: f64) -> String {
+    if fraction <= 0.0 || !elapsed_secs.is_finite() {
+        return "ETA --".to_string();
+    }
+
+    let remaining = (elapsed_secs * (1.0 - fraction.min(1.0)) / fraction).round() as u64;
+    let (hours, minutes, seconds) = (remaining / 3600, remaining / 60 % 60, remaining % 60);
+    if hours > 0 {
+        format!("ETA {}h{:02}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("ETA {}m{:02}s", minutes, seconds)
+    } else {
+        format!("ETA {}s", seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_throttle_at_most_once_per_interval() {
+        let start = Instant::now();
+        let mut throttle = Throttle::new(ms(50), start);
+
+        // Updates every 10ms for 200ms redraw on the 50ms marks only
+        let redraws: Vec<u64> = (1..=20)
+            .map(|i| i * 10)
+            .filter(|&t| throttle.ready(start + ms(t), false))
+            .collect();
+        assert_eq!(redraws, vec![50, 100, 150, 200]);
+    }
+
+    #[test]
+    fn test_throttle_interval_counts_from_last_redraw() {
+        let start = Instant::now();
+        let mut throttle = Throttle::new(ms(50), start);
+        assert!(throttle.ready(start + ms(70), false));
+        // 100ms is only 30ms after the redraw at 70ms
+        assert!(!throttle.ready(start + ms(100), false));
+        assert!(throttle.ready(start + ms(120), false));
+    }
+
+    #[test]
+    fn test_throttle_force_always_redraws() {
+        let start = Instant::now();
+        let mut throttle = Throttle::new(ms(50), start);
+        assert!(!throttle.ready(start + ms(1), false));
+        assert!(throttle.ready(start + ms(2), true));
+        // A forced redraw restarts the interval
+        assert!(!throttle.ready(start + ms(40), false));
+        assert!(throttle.ready(start + ms(52), false));
+    }
+
+    #[test]
+    fn test_inc_update_and_set_position() {
+        let bar = ProgressBar::new(100, "Test");
+        bar.inc(10);
+        bar.inc(5);
+        assert_eq!(bar.state.lock().unwrap().completed, 15);
+        // update adds, like inc
+        bar.update(20);
+        assert_eq!(bar.state.lock().unwrap().completed, 35);
+        bar.set_position(60);
+        assert_eq!(bar.state.lock().unwrap().completed, 60);
+        // Never moves backwards
+        bar.set_position(40);
+        assert_eq!(bar.state.lock().unwrap().completed, 60);
+        bar.inc(usize::MAX);
+        assert_eq!(bar.state.lock().unwrap().completed, usize::MAX);
+        assert_eq!(bar.total(), 100);
+    }
+
+    #[test]
+    fn test_format_rate() {
+        assert_eq!(format_rate(0.5), "0/s");
+        assert_eq!(format_rate(1000.0), "1.0K/s");
+        assert_eq!(format_rate(1500000.0), "1.5M/s");
+    }
+
+    #[test]
+    fn test_format_eta_unknown_before_progress() {
+        assert_eq!(format_eta(0.0, 0.0), "ETA --");
+        assert_eq!(format_eta(5.0, 0.0), "ETA --");
+    }
+
+    #[test]
+    fn test_format_eta_done() {
+        assert_eq!(format_eta(12.0, 1.0), "ETA 0s");
+        assert_eq!(format_eta(12.0, 1.5), "ETA 0s");
+    }
+
+    #[test]
+    fn test_format_eta_extrapolates() {
+        // Half done after 10s: 10s to go
+        assert_eq!(format_eta(10.0, 0.5), "ETA 10s");
+        // A quarter done after 30s: 90s to go
+        assert_eq!(format_eta(30.0, 0.25), "ETA 1m30s");
+        // 1% done after 60s: 99 minutes to go
+        assert_eq!(format_eta(60.0, 0.01), "ETA 1h39m");
+    }
+}
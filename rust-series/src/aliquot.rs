@@ -0,0 +1,139 @@
+//! Aliquot sequences
+//!
+//! s(n) = σ₁(n) - n, the sum of the proper divisors of n, iterated from a
+//! starting value: 12 → 16 → 15 → 9 → 4 → 3 → 1 → 0
+//!
+//! Every sequence that has been fully explored either reaches 0, lands on a
+//! perfect number, or falls into an amicable or longer sociable cycle. Some,
+//! starting at 276, have never been followed to an end.
+
+use std::collections::HashSet;
+
+use crate::divisor::sum_proper_divisors;
+
+/// How an aliquot sequence ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AliquotClass {
+    /// Reaches 0 (through a prime and then 1)
+    Terminating,
+    /// Settles on a perfect number, a cycle of length 1
+    Perfect,
+    /// Settles on an amicable pair, a cycle of length 2
+    Amicable,
+    /// Settles on a sociable cycle of the given length (3 or more)
+    Sociable { period: usize },
+    /// Neither ends nor repeats within the step budget
+    Unknown,
+}
+
+/// `n` followed by at most `max_steps` iterates of s.
+///
+/// Stops early at 0, or at the first term that repeats an earlier one; the
+/// repeated term is included so the cycle is visible, e.g. 220, 284, 220.
+///
+/// # Examples
+///
+/// ```
+/// use series::aliquot_sequence;
+///
+/// assert_eq!(aliquot_sequence(12, 20), vec![12, 16, 15, 9, 4, 3, 1, 0]);
+/// assert_eq!(aliquot_sequence(6, 20), vec![6, 6]);
+/// ```
+pub fn aliquot_sequence(n: usize, max_steps: usize) -> Vec<usize> {
+    let mut sequence = vec![n];
+    let mut seen = HashSet::from([n]);
+    let mut current = n;
+
+    for _ in 0..max_steps {
+        if current == 0 {
+            break;
+        }
+        current = sum_proper_divisors(current);
+        sequence.push(current);
+        if !seen.insert(current) {
+            break;
+        }
+    }
+
+    sequence
+}
+
+/// Classify where the aliquot sequence starting at `n` ends up within
+/// `max_steps` iterations.
+///
+/// The class describes the eventual behaviour rather than `n` itself: 25
+/// goes to the perfect number 6 and is reported as `Perfect`, and 562 joins
+/// the 220/284 pair and is reported as `Amicable`.
+pub fn classify_aliquot(n: usize, max_steps: usize) -> AliquotClass {
+    let sequence = aliquot_sequence(n, max_steps);
+    let last = sequence[sequence.len() - 1];
+
+    if last == 0 {
+        return AliquotClass::Terminating;
+    }
+
+    // The only way to end on a non-zero term early is by repeating one
+    match sequence[..sequence.len() - 1]
+        .iter()
+        .position(|&term| term == last)
+    {
+        Some(start) => match sequence.len() - 1 - start {
+            1 => AliquotClass::Perfect,
+            2 => AliquotClass::Amicable,
+            period => AliquotClass::Sociable { period },
+        },
+        None => AliquotClass::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amicable_pair() {
+        assert_eq!(aliquot_sequence(220, 10), vec![220, 284, 220]);
+        assert_eq!(aliquot_sequence(284, 10), vec![284, 220, 284]);
+        assert_eq!(classify_aliquot(220, 10), AliquotClass::Amicable);
+    }
+
+    #[test]
+    fn test_perfect() {
+        assert_eq!(aliquot_sequence(6, 10), vec![6, 6]);
+        assert_eq!(classify_aliquot(6, 1), AliquotClass::Perfect);
+        assert_eq!(classify_aliquot(28, 10), AliquotClass::Perfect);
+        // 25 → 6
+        assert_eq!(classify_aliquot(25, 10), AliquotClass::Perfect);
+    }
+
+    #[test]
+    fn test_terminating() {
+        assert_eq!(aliquot_sequence(0, 10), vec![0]);
+        assert_eq!(aliquot_sequence(1, 10), vec![1, 0]);
+        assert_eq!(aliquot_sequence(7, 10), vec![7, 1, 0]);
+        assert_eq!(classify_aliquot(0, 10), AliquotClass::Terminating);
+        assert_eq!(classify_aliquot(12, 10), AliquotClass::Terminating);
+    }
+
+    #[test]
+    fn test_sociable() {
+        // Poulet's 5-cycle: 12496 → 14288 → 15472 → 14536 → 14264 → 12496
+        assert_eq!(
+            aliquot_sequence(12496, 10),
+            vec![12496, 14288, 15472, 14536, 14264, 12496]
+        );
+        assert_eq!(
+            classify_aliquot(12496, 10),
+            AliquotClass::Sociable { period: 5 }
+        );
+    }
+
+    #[test]
+    fn test_step_budget() {
+        assert_eq!(aliquot_sequence(12, 2), vec![12, 16, 15]);
+        assert_eq!(classify_aliquot(12, 2), AliquotClass::Unknown);
+        assert_eq!(classify_aliquot(6, 0), AliquotClass::Unknown);
+        // 562 → 284 → 220 → 284
+        assert_eq!(classify_aliquot(562, 10), AliquotClass::Amicable);
+    }
+}
@@ -0,0 +1,83 @@
+//! Amicable numbers
+//!
+//! a and b ≠ a are amicable when each is the sum of the other's proper
+//! divisors: s(a) = b and s(b) = a.
+//! Pairs: (220, 284), (1184, 1210), (2620, 2924), (5020, 5564), ...
+
+use crate::divisor::{sum_proper_divisors, sum_proper_divisors_sieve};
+
+/// Whether n is a member of an amicable pair. Perfect numbers, where s(n) = n,
+/// are excluded.
+pub fn is_amicable(n: usize) -> bool {
+    let partner = sum_proper_divisors(n);
+    partner != n && sum_proper_divisors(partner) == n
+}
+
+/// Every amicable pair (a, b) with a < b <= `max_value`, ordered by a.
+pub fn generate_amicable_pairs_up_to(max_value: usize) -> Vec<(usize, usize)> {
+    let sums = sum_proper_divisors_sieve(max_value.saturating_add(1));
+    (2..sums.len())
+        .filter_map(|a| {
+            let b = sums[a];
+            (b > a && b <= max_value && sums[b] == a).then_some((a, b))
+        })
+        .collect()
+}
+
+/// Every amicable number n <= `max_value`.
+///
+/// Membership is decided by the partner, not by whether the partner falls
+/// within `max_value`, so 220 is included for `max_value = 250`.
+pub fn generate_amicable_up_to(max_value: usize) -> Vec<usize> {
+    let sums = sum_proper_divisors_sieve(max_value.saturating_add(1));
+    (2..sums.len())
+        .filter(|&n| {
+            let partner = sums[n];
+            // The partner's sum is only tabulated when it falls in range
+            let partner_sum = sums
+                .get(partner)
+                .copied()
+                .unwrap_or_else(|| sum_proper_divisors(partner));
+            partner != n && partner_sum == n
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_amicable() {
+        for &n in &[220, 284, 1184, 1210, 2620, 2924] {
+            assert!(is_amicable(n), "{} should be amicable", n);
+        }
+
+        // Perfect numbers pair only with themselves
+        for &n in &[0, 1, 6, 28, 221, 496, 1000] {
+            assert!(!is_amicable(n), "{} should NOT be amicable", n);
+        }
+    }
+
+    #[test]
+    fn test_pairs_below_1300() {
+        assert_eq!(
+            generate_amicable_pairs_up_to(1300),
+            vec![(220, 284), (1184, 1210)]
+        );
+        assert!(generate_amicable_pairs_up_to(283).is_empty());
+        assert_eq!(generate_amicable_pairs_up_to(284), vec![(220, 284)]);
+    }
+
+    #[test]
+    fn test_generate_up_to() {
+        assert_eq!(generate_amicable_up_to(250), vec![220]);
+        assert_eq!(generate_amicable_up_to(1300), vec![220, 284, 1184, 1210]);
+    }
+
+    #[test]
+    fn test_generate_up_to_matches_is_amicable() {
+        let expected: Vec<usize> = (0..=10_000).filter(|&n| is_amicable(n)).collect();
+        assert_eq!(generate_amicable_up_to(10_000), expected);
+    }
+}
@@ -2,6 +2,22 @@
 //!
 //! Cₙ = (2n)!/(n!(n+1)!) = binomial(2n, n) / (n+1)
 //! Sequence: 1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862, ...
+//!
+//! The `usize` functions are exact up to C₃₆ = 11959798385860453492, the last
+//! Catalan number that fits in a 64-bit usize. Past that `generate_catalan`
+//! saturates at `usize::MAX` and `generate_catalan_up_to` stops; use
+//! `generate_catalan_big_up_to` for larger values.
+
+use num_bigint::BigUint;
+use num_traits::One;
+
+/// Cᵢ from Cᵢ₋₁ via Cᵢ = Cᵢ₋₁·2(2i−1)/(i+1), or `None` if it exceeds usize.
+///
+/// The product is formed in u128 so it cannot overflow before the division.
+fn next_catalan(prev: usize, i: usize) -> Option<usize> {
+    let next = prev as u128 * (2 * (2 * i as u128 - 1)) / (i as u128 + 1);
+    usize::try_from(next).ok()
+}
 
 pub fn generate_catalan(count: usize) -> Vec<usize> {
     if count == 0 {
@@ -12,8 +28,7 @@ pub fn generate_catalan(count: usize) -> Vec<usize> {
     catalan.push(1);
 
     for i in 1..count {
-        let prev = catalan[i - 1];
-        let next = prev.saturating_mul(2 * (2 * i - 1)) / (i + 1);
+        let next = next_catalan(catalan[i - 1], i).unwrap_or(usize::MAX);
         catalan.push(next);
     }
 
@@ -28,10 +43,8 @@ pub fn generate_catalan_up_to(max_value: usize) -> Vec<usize> {
     let mut catalan: Vec<usize> = vec![1];
     let mut i = 1;
 
-    loop {
-        let prev = catalan[i - 1];
-        let next = prev.saturating_mul(2 * (2 * i - 1)) / (i + 1);
-        if next > max_value || next == 0 {
+    while let Some(next) = next_catalan(catalan[i - 1], i) {
+        if next > max_value {
             break;
         }
         catalan.push(next);
@@ -41,6 +54,33 @@ pub fn generate_catalan_up_to(max_value: usize) -> Vec<usize> {
     catalan
 }
 
+/// The nth Catalan number Cₙ = C(2n, n) / (n + 1), computed exactly.
+///
+/// Uses Cᵢ = Cᵢ₋₁ · 2(2i - 1) / (i + 1) in `BigUint` throughout, so no
+/// intermediate such as 2n can overflow `u64`.
+pub fn nth_catalan(n: u64) -> BigUint {
+    (1..=n).fold(BigUint::one(), |c, i| {
+        let i = BigUint::from(i);
+        c * (&i * 4u32 - 2u32) / (i + 1u32)
+    })
+}
+
+/// Generate Catalan numbers up to `max_value` with exact arithmetic.
+pub fn generate_catalan_big_up_to(max_value: &BigUint) -> Vec<BigUint> {
+    let mut catalan: Vec<BigUint> = Vec::new();
+    let mut current = BigUint::one();
+    let mut i: u64 = 1;
+
+    while &current <= max_value {
+        let next = &current * (2 * (2 * i - 1)) / (i + 1);
+        catalan.push(current);
+        current = next;
+        i += 1;
+    }
+
+    catalan
+}
+
 pub fn is_catalan(n: usize) -> bool {
     if n < 1 {
         return false;
@@ -53,13 +93,13 @@ pub fn is_catalan(n: usize) -> bool {
     let mut i = 1;
 
     while catalan < n {
-        catalan = catalan.saturating_mul(2 * (2 * i - 1)) / (i + 1);
+        match next_catalan(catalan, i) {
+            Some(next) => catalan = next,
+            None => break,
+        }
         if catalan == n {
             return true;
         }
-        if catalan == 0 {
-            break;
-        }
         i += 1;
     }
 
@@ -69,6 +109,7 @@ pub fn is_catalan(n: usize) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::combinatorics::binomial;
 
     #[test]
     fn test_generate_small() {
@@ -88,6 +129,52 @@ mod tests {
         assert_eq!(generate_catalan_up_to(50), vec![1, 1, 2, 5, 14, 42]);
     }
 
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_usize_ceiling() {
+        let c36: usize = 11_959_798_385_860_453_492;
+        let all = generate_catalan_up_to(usize::MAX);
+        assert_eq!(all.len(), 37);
+        assert_eq!(*all.last().unwrap(), c36);
+        assert_eq!(generate_catalan(37)[36], c36);
+        assert_eq!(generate_catalan(38)[37], usize::MAX);
+        assert!(is_catalan(c36));
+        assert!(!is_catalan(c36 + 1));
+
+        let big: Vec<usize> = generate_catalan_big_up_to(&BigUint::from(usize::MAX))
+            .iter()
+            .map(|c| usize::try_from(c).unwrap())
+            .collect();
+        assert_eq!(big, all);
+    }
+
+    #[test]
+    fn test_generate_big_up_to() {
+        let small = |v: &[u32]| v.iter().map(|&c| BigUint::from(c)).collect::<Vec<_>>();
+        assert_eq!(generate_catalan_big_up_to(&BigUint::from(0u32)), vec![]);
+        assert_eq!(
+            generate_catalan_big_up_to(&BigUint::from(100u32)),
+            small(&[1, 1, 2, 5, 14, 42])
+        );
+
+        // C₅₀ = 1978261657756160653623774456, far beyond u64
+        let c50: BigUint = "1978261657756160653623774456".parse().unwrap();
+        let big = generate_catalan_big_up_to(&c50);
+        assert_eq!(big.len(), 51);
+        assert_eq!(big.last(), Some(&c50));
+    }
+
+    #[test]
+    fn test_nth_catalan() {
+        let catalan = generate_catalan(21);
+        for n in 0..=20u64 {
+            assert_eq!(nth_catalan(n), BigUint::from(catalan[n as usize]), "C{}", n);
+            assert_eq!(nth_catalan(n), binomial(2 * n, n) / (n + 1));
+        }
+        let c50: BigUint = "1978261657756160653623774456".parse().unwrap();
+        assert_eq!(nth_catalan(50), c50);
+    }
+
     #[test]
     fn test_is_catalan() {
         for &n in &[1, 2, 5, 14, 42, 132, 429, 1430] {
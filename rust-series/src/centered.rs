@@ -0,0 +1,112 @@
+//! Centered polygonal numbers
+//!
+//! The k-gonal centered number counts the dots in a center dot surrounded
+//! by rings of k-gons, the nth ring holding k(n-1) dots:
+//! Cₖ,ₙ = k·n(n-1)/2 + 1
+//!
+//! Centered hexagonal (k = 6): 1, 7, 19, 37, 61, 91, ... = 3n² - 3n + 1
+//! Centered square (k = 4): 1, 5, 13, 25, 41, 61, ...
+
+use crate::triangular::is_triangular;
+
+/// The first `count` centered `sides`-gonal numbers, starting from the
+/// single center dot 1. Stops early rather than wrap once a term no longer
+/// fits in usize.
+pub fn generate_centered_polygonal(sides: usize, count: usize) -> Vec<usize> {
+    (1..=count)
+        .map_while(|n| {
+            let triangular = n.checked_mul(n - 1)? / 2;
+            triangular.checked_mul(sides)?.checked_add(1)
+        })
+        .collect()
+}
+
+pub fn generate_centered_hexagonal(count: usize) -> Vec<usize> {
+    generate_centered_polygonal(6, count)
+}
+
+/// Centered hexagonal numbers `<= max_value`, including `max_value` itself
+/// when it is centered hexagonal.
+pub fn generate_centered_hexagonal_up_to(max_value: usize) -> Vec<usize> {
+    let mut centered: Vec<usize> = Vec::new();
+    let mut c: usize = 1;
+    let mut n: usize = 1;
+
+    // Cₙ = Cₙ₋₁ + 6(n-1); stop rather than wrap when Cₙ leaves usize
+    while c <= max_value {
+        centered.push(c);
+        match (6 * n).checked_add(c) {
+            Some(next) => c = next,
+            None => break,
+        }
+        n += 1;
+    }
+
+    centered
+}
+
+pub fn is_centered_hexagonal(n: usize) -> bool {
+    // 3k(k-1) + 1 = 6·Tₖ₋₁ + 1, so (n - 1)/6 must be triangular
+    n >= 1 && (n - 1).is_multiple_of(6) && is_triangular((n - 1) / 6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_centered_hexagonal() {
+        assert_eq!(generate_centered_hexagonal(5), vec![1, 7, 19, 37, 61]);
+        assert_eq!(generate_centered_hexagonal(0), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_centered_square() {
+        assert_eq!(
+            generate_centered_polygonal(4, 6),
+            vec![1, 5, 13, 25, 41, 61]
+        );
+    }
+
+    #[test]
+    fn test_centered_polygonal_stops_at_overflow() {
+        // 1, k + 1 and 3k + 1, where 3k + 1 no longer fits
+        let sides = usize::MAX / 2;
+        assert_eq!(generate_centered_polygonal(sides, 5), vec![1, sides + 1]);
+    }
+
+    #[test]
+    fn test_generate_up_to() {
+        assert_eq!(generate_centered_hexagonal_up_to(0), Vec::<usize>::new());
+        assert_eq!(generate_centered_hexagonal_up_to(1), vec![1]);
+        assert_eq!(
+            generate_centered_hexagonal_up_to(91),
+            vec![1, 7, 19, 37, 61, 91]
+        );
+        assert_eq!(generate_centered_hexagonal_up_to(90).last(), Some(&61));
+    }
+
+    #[test]
+    fn test_is_centered_hexagonal() {
+        let members = generate_centered_hexagonal_up_to(10_000);
+        for n in 0..=10_000 {
+            assert_eq!(
+                is_centered_hexagonal(n),
+                members.contains(&n),
+                "Failed for n={}",
+                n
+            );
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_is_centered_hexagonal_large() {
+        // 3n² - 3n + 1 at n = 10⁹, far past the up_to tests
+        let n: usize = 1_000_000_000;
+        let c = 3 * n * (n - 1) + 1;
+        assert!(is_centered_hexagonal(c));
+        assert!(!is_centered_hexagonal(c + 6));
+        assert!(!is_centered_hexagonal(usize::MAX));
+    }
+}
@@ -0,0 +1,68 @@
+//! Circular prime generator
+//!
+//! A circular prime stays prime under every rotation of its base-10 digits
+//! (197 -> 971 -> 719). Sequence: 2, 3, 5, 7, 11, 13, 17, 31, 37, 71, 73, 79, 97, 113, ...
+//!
+//! Only finitely many are known beyond the repunits, so there is no count-based
+//! generator; use `generate_circular_primes_up_to`.
+
+use primes::is_prime;
+
+use crate::digits::{from_digits, to_digits};
+
+pub fn is_circular_prime(n: usize) -> bool {
+    if !is_prime(n) {
+        return false;
+    }
+
+    let mut digits = to_digits(n, 10);
+    // A 0 would rotate into the leading position and silently drop a digit
+    // (101 -> 011 = 11), so any multi-digit number containing one is excluded.
+    if digits.len() > 1 && digits.contains(&0) {
+        return false;
+    }
+
+    for _ in 1..digits.len() {
+        digits.rotate_left(1);
+        // A rotation too large for usize cannot be checked, so it doesn't qualify.
+        match from_digits(&digits, 10) {
+            Some(rotation) if is_prime(rotation) => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
+pub fn generate_circular_primes_up_to(max_value: usize) -> Vec<usize> {
+    (2..=max_value).filter(|&n| is_circular_prime(n)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_circular_prime() {
+        for &n in &[2, 3, 5, 7, 11, 13, 17, 37, 79, 113, 197, 199, 971, 719] {
+            assert!(is_circular_prime(n), "{} should be a circular prime", n);
+        }
+
+        // 19 -> 91 = 7 * 13; 23 -> 32; 137 -> 371 = 7 * 53; 101 and 103 contain a zero
+        for &n in &[0, 1, 4, 19, 23, 101, 103, 137] {
+            assert!(
+                !is_circular_prime(n),
+                "{} should NOT be a circular prime",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_up_to() {
+        assert_eq!(generate_circular_primes_up_to(1), Vec::<usize>::new());
+        assert_eq!(
+            generate_circular_primes_up_to(200),
+            vec![2, 3, 5, 7, 11, 13, 17, 31, 37, 71, 73, 79, 97, 113, 131, 197, 199]
+        );
+    }
+}
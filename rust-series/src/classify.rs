@@ -0,0 +1,107 @@
+//! Every cheap series membership of a single number
+//!
+//! [`classify`] runs the O(1) and O(√n) `is_*` predicates. Series that need
+//! a generator or sieve to decide membership (Collatz, Stern, σₖ) are left
+//! out.
+
+use primes::is_prime;
+
+use crate::{
+    is_amicable, is_catalan, is_centered_hexagonal, is_emirp, is_fibonacci, is_happy, is_harshad,
+    is_hexagonal, is_lucas, is_palindrome, is_pentagonal, is_perfect_square, is_power_of_2,
+    is_self_number, is_triangular,
+};
+
+fn is_twin_prime(n: usize) -> bool {
+    is_prime(n) && ((n >= 2 && is_prime(n - 2)) || n.checked_add(2).is_some_and(is_prime))
+}
+
+type Predicate = fn(usize) -> bool;
+
+/// Label and predicate for each classified series, in the order `classify`
+/// reports them.
+const CLASSES: &[(&str, Predicate)] = &[
+    ("prime", is_prime),
+    ("twin prime", is_twin_prime),
+    ("fibonacci", is_fibonacci),
+    ("lucas", is_lucas),
+    ("triangular", is_triangular),
+    ("square", is_perfect_square),
+    ("power of 2", is_power_of_2),
+    ("catalan", is_catalan),
+    ("hexagonal", is_hexagonal),
+    ("centered hexagonal", is_centered_hexagonal),
+    ("pentagonal", is_pentagonal),
+    ("happy", is_happy),
+    ("harshad", is_harshad),
+    ("self", is_self_number),
+    ("palindrome", |n| is_palindrome(n, 10)),
+    ("emirp", is_emirp),
+    // Odious numbers: the indices of the 1s in the Thue–Morse sequence
+    ("thue-morse", |n| n.count_ones() % 2 == 1),
+    ("amicable", is_amicable),
+];
+
+/// The labels of every series `n` belongs to, e.g. `["prime", "fibonacci"]`.
+///
+/// # Examples
+///
+/// ```
+/// use series::classify;
+///
+/// assert_eq!(
+///     classify(13),
+///     vec!["prime", "twin prime", "fibonacci", "happy", "emirp", "thue-morse"]
+/// );
+/// ```
+pub fn classify(n: usize) -> Vec<&'static str> {
+    CLASSES
+        .iter()
+        .filter(|(_, is_member)| is_member(n))
+        .map(|&(label, _)| label)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_is_in_most_series() {
+        let labels = classify(1);
+        for label in [
+            "fibonacci",
+            "lucas",
+            "triangular",
+            "square",
+            "catalan",
+            "happy",
+        ] {
+            assert!(labels.contains(&label), "1 should be {}", label);
+        }
+        assert!(!labels.contains(&"prime"));
+    }
+
+    #[test]
+    fn test_small_numbers() {
+        // 4 is a square and a power of 2 but not Fibonacci
+        let labels = classify(4);
+        assert!(labels.contains(&"square"));
+        assert!(labels.contains(&"power of 2"));
+        assert!(!labels.contains(&"fibonacci"));
+
+        // 14 is Catalan and odious, but neither happy nor harshad (1 + 4 = 5 ∤ 14)
+        assert_eq!(classify(14), vec!["catalan", "thue-morse"]);
+        assert_eq!(classify(220), vec!["harshad", "thue-morse", "amicable"]);
+    }
+
+    #[test]
+    fn test_matches_each_predicate() {
+        for n in 0..2_000 {
+            let labels = classify(n);
+            assert_eq!(labels.contains(&"prime"), is_prime(n), "n={}", n);
+            assert_eq!(labels.contains(&"happy"), is_happy(n), "n={}", n);
+            assert_eq!(labels.contains(&"triangular"), is_triangular(n), "n={}", n);
+        }
+    }
+}
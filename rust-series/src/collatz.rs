@@ -4,9 +4,32 @@
 //! - If n is even: n → n/2
 //! - If n is odd: n → 3n+1
 //!
-//! Output is stopping times for 1, 2, 3, 4, 5, ...
-//! Sequence: 0, 1, 7, 2, 5, 8, 16, 3, 19, 6, 14, 9, 9, 17, 17, 4, 12, 20, ...
+//! Output is stopping times indexed by starting value 0, 1, 2, 3, ...; the
+//! start 0 never reaches 1 and is reported as 0 steps.
+//! Sequence: 0, 0, 1, 7, 2, 5, 8, 16, 3, 19, 6, 14, 9, 9, 17, 17, 4, 12, 20, ...
 
+use std::sync::Arc;
+
+use num_bigint::BigUint;
+use num_traits::One;
+
+/// The value after n in its trajectory: n/2 for even n, 3n+1 for odd n.
+///
+/// `None` when 3n+1 does not fit in usize.
+pub fn collatz_step(n: usize) -> Option<usize> {
+    if n.is_multiple_of(2) {
+        Some(n / 2)
+    } else {
+        n.checked_mul(3).and_then(|t| t.checked_add(1))
+    }
+}
+
+/// Steps for n to reach 1.
+///
+/// Runs in u64 while the trajectory fits. A 3n+1 step overflows once the
+/// value exceeds (u64::MAX - 1) / 3 (about 6.1 * 10^18); from there the rest
+/// of the trajectory continues in `collatz_stopping_time_big`, so large
+/// starts never wrap or saturate into a wrong answer.
 pub fn collatz_stopping_time(n: usize) -> usize {
     if n == 0 {
         return 0;
@@ -22,7 +45,31 @@ pub fn collatz_stopping_time(n: usize) -> usize {
         if current.is_multiple_of(2) {
             current /= 2;
         } else {
-            current = 3 * current + 1;
+            match current.checked_mul(3).and_then(|c| c.checked_add(1)) {
+                Some(next) => current = next,
+                None => return steps + collatz_stopping_time_big(&BigUint::from(current)),
+            }
+        }
+        steps += 1;
+    }
+
+    steps
+}
+
+/// Steps for n to reach 1, with exact arithmetic for arbitrarily large n.
+pub fn collatz_stopping_time_big(n: &BigUint) -> usize {
+    if n <= &BigUint::one() {
+        return 0;
+    }
+
+    let mut current = n.clone();
+    let mut steps = 0;
+
+    while !current.is_one() {
+        if current.bit(0) {
+            current = current * 3u32 + 1u32;
+        } else {
+            current >>= 1;
         }
         steps += 1;
     }
@@ -31,17 +78,159 @@ pub fn collatz_stopping_time(n: usize) -> usize {
 }
 
 pub fn generate_collatz_times(count: usize) -> Vec<usize> {
-    (0..count).map(collatz_stopping_time).collect()
+    generate_collatz_times_with_progress(count, None)
+}
+
+/// Generate stopping times for 0..count, invoking `progress` with a delta
+/// of 1 for each term computed.
+pub fn generate_collatz_times_with_progress(
+    count: usize,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Vec<usize> {
+    (0..count)
+        .map(|n| {
+            let steps = collatz_stopping_time(n);
+            if let Some(ref callback) = progress {
+                callback(1);
+            }
+            steps
+        })
+        .collect()
 }
 
+/// Stopping times for the starting values `0..=max_value`, so element i is
+/// `collatz_stopping_time(i)`.
+///
+/// `max_value` bounds the starting value, not the stopping time: unlike the
+/// other `_up_to` generators, the returned values may exceed it.
 pub fn generate_collatz_times_up_to(max_value: usize) -> Vec<usize> {
     (0..=max_value).map(collatz_stopping_time).collect()
 }
 
+/// Stopping time and peak value of n's trajectory, from one walk.
+///
+/// The peak includes n itself. A trajectory that climbs past u64 finishes
+/// its step count in `collatz_stopping_time_big` and reports its peak as
+/// `usize::MAX`.
+fn collatz_walk(n: usize) -> (usize, usize) {
+    if n <= 1 {
+        return (0, n);
+    }
+
+    let mut current = n as u64;
+    let mut peak = current;
+    let mut steps = 0;
+
+    while current != 1 {
+        if current.is_multiple_of(2) {
+            current /= 2;
+        } else {
+            match current.checked_mul(3).and_then(|c| c.checked_add(1)) {
+                Some(next) => {
+                    current = next;
+                    peak = peak.max(next);
+                }
+                None => {
+                    let rest = collatz_stopping_time_big(&BigUint::from(current));
+                    return (steps + rest, usize::MAX);
+                }
+            }
+        }
+        steps += 1;
+    }
+
+    (steps, usize::try_from(peak).unwrap_or(usize::MAX))
+}
+
+/// Record-setting starts, each paired with the value that set the record
+pub type CollatzRecords = Vec<(usize, usize)>;
+
+/// Delay and altitude records for the starting values `1..limit`, from a
+/// single trajectory walk per start.
+///
+/// A delay record is a start whose stopping time exceeds every smaller
+/// start's, listed as `(start, stopping time)`. An altitude record is a
+/// start whose trajectory peaks higher than every smaller start's, listed
+/// as `(start, peak)`.
+pub fn collatz_records(limit: usize) -> (CollatzRecords, CollatzRecords) {
+    let mut delay_records = Vec::new();
+    let mut altitude_records = Vec::new();
+
+    for n in 1..limit {
+        let (steps, peak) = collatz_walk(n);
+        if delay_records.last().is_none_or(|&(_, best)| steps > best) {
+            delay_records.push((n, steps));
+        }
+        if altitude_records.last().is_none_or(|&(_, best)| peak > best) {
+            altitude_records.push((n, peak));
+        }
+    }
+
+    (delay_records, altitude_records)
+}
+
+/// Starts below `limit` whose stopping time beats every smaller start's,
+/// as `(start, stopping time)`: 1, 2, 3, 6, 7, 9, 18, 25, 27, ...
+pub fn collatz_delay_records(limit: usize) -> CollatzRecords {
+    collatz_records(limit).0
+}
+
+/// Starts below `limit` whose trajectory peaks higher than every smaller
+/// start's, as `(start, peak)`: 1, 2, 3, 7, 15, 27, 255, ...
+pub fn collatz_altitude_records(limit: usize) -> CollatzRecords {
+    collatz_records(limit).1
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_collatz_step() {
+        assert_eq!(collatz_step(6), Some(3));
+        assert_eq!(collatz_step(3), Some(10));
+        assert_eq!(collatz_step(1), Some(4));
+        assert_eq!(collatz_step(0), Some(0));
+        assert_eq!(collatz_step(usize::MAX), None);
+        // Following the steps from 27 takes its stopping time to reach 1
+        let steps = std::iter::successors(Some(27), |&n| collatz_step(n))
+            .position(|n| n == 1)
+            .unwrap();
+        assert_eq!(steps, collatz_stopping_time(27));
+    }
+
+    #[test]
+    fn test_big_matches_usize() {
+        for n in 0..10_000 {
+            assert_eq!(
+                collatz_stopping_time_big(&BigUint::from(n)),
+                collatz_stopping_time(n),
+                "Failed for n={}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_big_large_starts() {
+        // 2^100 halves straight down to 1
+        assert_eq!(collatz_stopping_time_big(&(BigUint::one() << 100)), 100);
+        // 2^100 - 1 needs values far beyond u64 along the way
+        let start = (BigUint::one() << 100) - 1u32;
+        assert!(collatz_stopping_time_big(&start) > 100);
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_stopping_time_overflowing_trajectory() {
+        // Odd and above (u64::MAX - 1) / 3, so the first 3n+1 step overflows u64
+        let n = usize::MAX - 2;
+        assert_eq!(
+            collatz_stopping_time(n),
+            collatz_stopping_time_big(&BigUint::from(n))
+        );
+    }
+
     #[test]
     fn test_stopping_time() {
         assert_eq!(collatz_stopping_time(0), 0);
@@ -67,9 +256,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_with_progress_fires_per_term() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_ref = Arc::clone(&calls);
+        let times = generate_collatz_times_with_progress(
+            50,
+            Some(Arc::new(move |delta: usize| {
+                calls_ref.fetch_add(delta, Ordering::Relaxed);
+            })),
+        );
+        assert_eq!(times, generate_collatz_times(50));
+        assert_eq!(calls.load(Ordering::Relaxed), 50);
+    }
+
+    #[test]
+    fn test_altitude_records() {
+        let records = collatz_altitude_records(100);
+        assert_eq!(
+            records,
+            vec![(1, 1), (2, 2), (3, 16), (7, 52), (15, 160), (27, 9232)]
+        );
+        assert_eq!(collatz_altitude_records(300).last(), Some(&(255, 13120)));
+    }
+
+    #[test]
+    fn test_delay_records() {
+        let records = collatz_delay_records(100);
+        let starts: Vec<usize> = records.iter().map(|&(n, _)| n).collect();
+        assert_eq!(starts, vec![1, 2, 3, 6, 7, 9, 18, 25, 27, 54, 73, 97]);
+        for &(n, steps) in &records {
+            assert_eq!(steps, collatz_stopping_time(n));
+        }
+        assert!(collatz_delay_records(1).is_empty());
+    }
+
     #[test]
     fn test_generate_up_to() {
         assert_eq!(generate_collatz_times_up_to(0), vec![0]);
         assert_eq!(generate_collatz_times_up_to(4), vec![0, 0, 1, 7, 2]);
     }
+
+    #[test]
+    fn test_generate_up_to_bounds_starting_value() {
+        // Starts 0..=9; 7 and 9 take longer than max_value steps
+        let times = generate_collatz_times_up_to(9);
+        assert_eq!(times, vec![0, 0, 1, 7, 2, 5, 8, 16, 3, 19]);
+        assert_eq!(times, generate_collatz_times(10));
+        for (start, &steps) in times.iter().enumerate() {
+            assert_eq!(steps, collatz_stopping_time(start));
+        }
+    }
 }
@@ -0,0 +1,65 @@
+//! Exact factorials and binomial coefficients
+//!
+//! Results are `BigUint`, so there is no overflow ceiling.
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// n! = 1 · 2 · ... · n, with 0! = 1.
+pub fn factorial(n: u64) -> BigUint {
+    (2..=n).fold(BigUint::one(), |acc, i| acc * i)
+}
+
+/// C(n, k) = n! / (k! (n - k)!), or 0 when k > n.
+///
+/// Uses the multiplicative form C(n, i) = C(n, i - 1) · (n - i + 1) / i, where
+/// every intermediate value is itself a binomial coefficient, so each division
+/// is exact.
+pub fn binomial(n: u64, k: u64) -> BigUint {
+    if k > n {
+        return BigUint::zero();
+    }
+    let k = k.min(n - k);
+    (1..=k).fold(BigUint::one(), |acc, i| acc * (n - i + 1) / i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial(0), BigUint::from(1u32));
+        assert_eq!(factorial(1), BigUint::from(1u32));
+        assert_eq!(factorial(5), BigUint::from(120u32));
+        assert_eq!(factorial(20), BigUint::from(2_432_902_008_176_640_000u64));
+        // 25! overflows u64
+        let f25: BigUint = "15511210043330985984000000".parse().unwrap();
+        assert_eq!(factorial(25), f25);
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(5, 2), BigUint::from(10u32));
+        assert_eq!(binomial(5, 0), BigUint::from(1u32));
+        assert_eq!(binomial(5, 5), BigUint::from(1u32));
+        assert_eq!(binomial(5, 6), BigUint::zero());
+        assert_eq!(binomial(0, 0), BigUint::from(1u32));
+        assert_eq!(binomial(52, 5), BigUint::from(2_598_960u32));
+    }
+
+    #[test]
+    fn test_binomial_matches_factorials() {
+        for n in 0..30 {
+            for k in 0..=n {
+                assert_eq!(
+                    binomial(n, k),
+                    factorial(n) / (factorial(k) * factorial(n - k)),
+                    "C({}, {})",
+                    n,
+                    k
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Digit helpers for digit-based series
+//!
+//! Shared by happy numbers and other series defined in terms of the digits
+//! of n in some base. All functions panic if `base < 2`.
+
+/// Iterator over the digits of n in `base`, least significant first.
+/// Yields nothing for n = 0.
+struct Digits {
+    n: usize,
+    base: usize,
+}
+
+impl Iterator for Digits {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.n == 0 {
+            return None;
+        }
+        let digit = self.n % self.base;
+        self.n /= self.base;
+        Some(digit)
+    }
+}
+
+fn digits(n: usize, base: usize) -> Digits {
+    assert!(base >= 2, "base must be >= 2, got {}", base);
+    Digits { n, base }
+}
+
+/// Digits of n in `base`, most significant first. `to_digits(0, b)` is `[0]`.
+pub fn to_digits(n: usize, base: usize) -> Vec<usize> {
+    if n == 0 {
+        assert!(base >= 2, "base must be >= 2, got {}", base);
+        return vec![0];
+    }
+    let mut result: Vec<usize> = digits(n, base).collect();
+    result.reverse();
+    result
+}
+
+/// n written in `base` using 0-9 then a-z, so `base` may be at most 36.
+/// `to_base_string(0, b)` is `"0"`.
+pub fn to_base_string(n: usize, base: usize) -> String {
+    assert!(base <= 36, "base must be <= 36, got {}", base);
+    to_digits(n, base)
+        .into_iter()
+        .map(|d| char::from_digit(d as u32, base as u32).unwrap())
+        .collect()
+}
+
+/// Rebuild a number from digits in `base`, most significant first.
+///
+/// Leading zeros are allowed and ignored. Returns `None` on overflow.
+pub fn from_digits(digits: &[usize], base: usize) -> Option<usize> {
+    assert!(base >= 2, "base must be >= 2, got {}", base);
+    digits
+        .iter()
+        .try_fold(0usize, |acc, &d| acc.checked_mul(base)?.checked_add(d))
+}
+
+/// Reverse the digits of n in `base` (1230 -> 321). Returns `None` on overflow.
+pub fn reverse_digits(n: usize, base: usize) -> Option<usize> {
+    digits(n, base).try_fold(0usize, |acc, d| acc.checked_mul(base)?.checked_add(d))
+}
+
+/// Sum of the digits of n in `base`.
+pub fn sum_of_digits(n: usize, base: usize) -> usize {
+    digits(n, base).sum()
+}
+
+/// Sum of the squares of the digits of n in `base` (the happy-number map).
+pub fn sum_of_digit_squares(n: usize, base: usize) -> usize {
+    digits(n, base).map(|d| d * d).sum()
+}
+
+/// Digital root of n in `base`: repeated digit sums until a single digit remains.
+///
+/// Uses the closed form 1 + (n - 1) mod (base - 1), with dr(0) = 0.
+pub fn digital_root(n: usize, base: usize) -> usize {
+    assert!(base >= 2, "base must be >= 2, got {}", base);
+    if n == 0 {
+        0
+    } else {
+        1 + (n - 1) % (base - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_digits() {
+        assert_eq!(to_digits(0, 10), vec![0]);
+        assert_eq!(to_digits(12345, 10), vec![1, 2, 3, 4, 5]);
+        assert_eq!(to_digits(10, 2), vec![1, 0, 1, 0]);
+        assert_eq!(to_digits(255, 16), vec![15, 15]);
+    }
+
+    #[test]
+    fn test_to_base_string() {
+        assert_eq!(to_base_string(0, 2), "0");
+        assert_eq!(to_base_string(10, 2), "1010");
+        assert_eq!(to_base_string(12345, 10), "12345");
+        assert_eq!(to_base_string(255, 16), "ff");
+        assert_eq!(to_base_string(35, 36), "z");
+        assert_eq!(to_base_string(usize::MAX, 10), usize::MAX.to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be <= 36")]
+    fn test_to_base_string_invalid_base() {
+        to_base_string(10, 37);
+    }
+
+    #[test]
+    fn test_from_digits() {
+        assert_eq!(from_digits(&[], 10), Some(0));
+        assert_eq!(from_digits(&[1, 2, 3], 10), Some(123));
+        assert_eq!(from_digits(&[0, 1, 1], 10), Some(11));
+        assert_eq!(from_digits(&[1, 0, 1, 0], 2), Some(10));
+        assert_eq!(
+            from_digits(&to_digits(usize::MAX, 10), 10),
+            Some(usize::MAX)
+        );
+        assert_eq!(from_digits(&[9; 25], 10), None);
+    }
+
+    #[test]
+    fn test_reverse_digits() {
+        assert_eq!(reverse_digits(0, 10), Some(0));
+        assert_eq!(reverse_digits(13, 10), Some(31));
+        assert_eq!(reverse_digits(1230, 10), Some(321));
+        assert_eq!(reverse_digits(0b1101, 2), Some(0b1011));
+        // 18446744073709551615 reversed does not fit in 64 bits
+        assert_eq!(reverse_digits(usize::MAX, 10), None);
+    }
+
+    #[test]
+    fn test_sum_of_digits() {
+        assert_eq!(sum_of_digits(0, 10), 0);
+        assert_eq!(sum_of_digits(12345, 10), 15);
+        // Base-2 digit sum is the population count
+        for n in [0usize, 1, 2, 7, 8, 255, 1023, 0b1011_0110] {
+            assert_eq!(sum_of_digits(n, 2), n.count_ones() as usize);
+        }
+    }
+
+    #[test]
+    fn test_sum_of_digit_squares() {
+        assert_eq!(sum_of_digit_squares(7, 10), 49);
+        assert_eq!(sum_of_digit_squares(49, 10), 97);
+        assert_eq!(sum_of_digit_squares(0b101, 2), 2);
+    }
+
+    #[test]
+    fn test_digital_root() {
+        assert_eq!(digital_root(0, 10), 0);
+        assert_eq!(digital_root(9, 10), 9);
+        assert_eq!(digital_root(12345, 10), 6);
+        // Closed form agrees with repeated digit sums
+        for n in 0..2000usize {
+            let mut r = n;
+            while r >= 10 {
+                r = sum_of_digits(r, 10);
+            }
+            assert_eq!(digital_root(n, 10), r, "Failed for n={}", n);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be >= 2")]
+    fn test_invalid_base() {
+        sum_of_digits(10, 1);
+    }
+}
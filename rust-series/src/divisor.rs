@@ -0,0 +1,126 @@
+//! Divisor functions σₖ(n)
+//!
+//! σₖ(n) = Σ dᵏ over the divisors d of n; σ₀ counts divisors, σ₁ sums them.
+//! σ₀: 1, 2, 2, 3, 2, 4, 2, 4, 3, 4, ...
+//! σ₁: 1, 3, 4, 7, 6, 12, 8, 15, 13, 18, ...
+
+use primes::factorize;
+
+/// σₖ(n), saturating at `usize::MAX`. σₖ(0) is reported as 0.
+///
+/// Uses the multiplicative formula: for n = Π pᵉ,
+/// σₖ(n) = Π (1 + pᵏ + p²ᵏ + ... + pᵉᵏ).
+pub fn sigma_k(n: usize, k: u32) -> usize {
+    if n == 0 {
+        return 0;
+    }
+
+    factorize(n)
+        .into_iter()
+        .map(|(p, e)| {
+            let p_k = p.saturating_pow(k);
+            let mut term: usize = 1;
+            let mut power: usize = 1;
+            for _ in 0..e {
+                power = power.saturating_mul(p_k);
+                term = term.saturating_add(power);
+            }
+            term
+        })
+        .fold(1, usize::saturating_mul)
+}
+
+/// Sum of the divisors of n other than n itself, σ₁(n) - n.
+///
+/// Reported as 0 for n = 0 and n = 1.
+pub fn sum_proper_divisors(n: usize) -> usize {
+    sigma_k(n, 1).saturating_sub(n)
+}
+
+/// s(i) = [`sum_proper_divisors`]`(i)` for every i below `n`.
+///
+/// Adds each d to all of its proper multiples, O(n log n) in total, which is
+/// far cheaper than factorizing every i when whole ranges are needed.
+pub fn sum_proper_divisors_sieve(n: usize) -> Vec<usize> {
+    let mut sums = vec![0usize; n];
+    for d in 1..=n.saturating_sub(1) / 2 {
+        for multiple in (2 * d..n).step_by(d) {
+            sums[multiple] += d;
+        }
+    }
+    sums
+}
+
+/// σₖ(1), σₖ(2), ..., σₖ(count).
+pub fn generate_sigma_series(count: usize, k: u32) -> Vec<usize> {
+    (1..=count).map(|n| sigma_k(n, k)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sigma_known_values() {
+        assert_eq!(sigma_k(12, 0), 6);
+        assert_eq!(sigma_k(12, 1), 28);
+        assert_eq!(sigma_k(6, 2), 50);
+        assert_eq!(sigma_k(1, 5), 1);
+        assert_eq!(sigma_k(0, 1), 0);
+    }
+
+    #[test]
+    fn test_sigma_matches_divisor_sum() {
+        for k in 0..3 {
+            for n in 1..200usize {
+                let expected: usize = (1..=n)
+                    .filter(|d| n.is_multiple_of(*d))
+                    .map(|d| d.pow(k))
+                    .sum();
+                assert_eq!(sigma_k(n, k), expected, "sigma_{}({})", k, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_proper_divisors() {
+        assert_eq!(sum_proper_divisors(0), 0);
+        assert_eq!(sum_proper_divisors(1), 0);
+        assert_eq!(sum_proper_divisors(7), 1);
+        assert_eq!(sum_proper_divisors(12), 16);
+        assert_eq!(sum_proper_divisors(220), 284);
+        assert_eq!(sum_proper_divisors(284), 220);
+    }
+
+    #[test]
+    fn test_sum_proper_divisors_sieve() {
+        let sums = sum_proper_divisors_sieve(285);
+        assert_eq!(sums[220], 284);
+        assert_eq!(sums[284], 220);
+        for (i, &s) in sums.iter().enumerate() {
+            assert_eq!(s, sum_proper_divisors(i), "s({})", i);
+        }
+        assert!(sum_proper_divisors_sieve(0).is_empty());
+        assert_eq!(sum_proper_divisors_sieve(2), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_generate_series() {
+        assert!(generate_sigma_series(0, 1).is_empty());
+        assert_eq!(
+            generate_sigma_series(10, 0),
+            vec![1, 2, 2, 3, 2, 4, 2, 4, 3, 4]
+        );
+        assert_eq!(
+            generate_sigma_series(10, 1),
+            vec![1, 3, 4, 7, 6, 12, 8, 15, 13, 18]
+        );
+    }
+
+    #[test]
+    fn test_perfect_numbers() {
+        // σ₁(n) = 2n exactly for perfect numbers
+        let perfect: Vec<usize> = (1..10_000).filter(|&n| sigma_k(n, 1) == 2 * n).collect();
+        assert_eq!(perfect, vec![6, 28, 496, 8128]);
+    }
+}
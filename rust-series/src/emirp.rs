@@ -0,0 +1,61 @@
+//! Emirp generator
+//!
+//! An emirp is a prime whose base-10 digit reversal is a different prime.
+//! Palindromic primes (11, 101, ...) are excluded by the "different" rule.
+//! Sequence: 13, 17, 31, 37, 71, 73, 79, 97, 107, 113, 149, 157, ...
+
+use primes::is_prime;
+
+use crate::digits::reverse_digits;
+
+pub fn is_emirp(n: usize) -> bool {
+    if !is_prime(n) {
+        return false;
+    }
+    // A reversal too large for usize cannot be checked, so it doesn't qualify.
+    match reverse_digits(n, 10) {
+        Some(reversed) => reversed != n && is_prime(reversed),
+        None => false,
+    }
+}
+
+pub fn generate_emirps(count: usize) -> Vec<usize> {
+    (13..).filter(|&n| is_emirp(n)).take(count).collect()
+}
+
+pub fn generate_emirps_up_to(max_value: usize) -> Vec<usize> {
+    (2..=max_value).filter(|&n| is_emirp(n)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_emirp() {
+        for &n in &[13, 17, 31, 37, 71, 73, 79, 97, 107, 113] {
+            assert!(is_emirp(n), "{} should be an emirp", n);
+        }
+
+        // Palindromic primes, primes with composite reversals, and composites
+        for &n in &[0, 1, 2, 3, 5, 7, 11, 19, 23, 101, 39, 93] {
+            assert!(!is_emirp(n), "{} should NOT be an emirp", n);
+        }
+    }
+
+    #[test]
+    fn test_generate_small() {
+        assert_eq!(generate_emirps(0), Vec::<usize>::new());
+        assert_eq!(generate_emirps(4), vec![13, 17, 31, 37]);
+    }
+
+    #[test]
+    fn test_generate_up_to() {
+        assert_eq!(generate_emirps_up_to(12), Vec::<usize>::new());
+        assert_eq!(
+            generate_emirps_up_to(100),
+            vec![13, 17, 31, 37, 71, 73, 79, 97]
+        );
+        assert_eq!(generate_emirps_up_to(200), generate_emirps(15));
+    }
+}
@@ -2,27 +2,24 @@
 //!
 //! F₀=0, F₁=1, Fₙ=Fₙ₋₁+Fₙ₋₂
 
-pub fn generate_fibonacci(count: usize) -> Vec<usize> {
-    if count == 0 {
-        return Vec::new();
-    }
-    if count == 1 {
-        return vec![0];
-    }
-    if count == 2 {
-        return vec![0, 1];
-    }
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
 
-    let mut fibs: Vec<usize> = Vec::with_capacity(count);
-    fibs.push(0_usize);
-    fibs.push(1_usize);
+use crate::lucas_seq::lucas_terms_checked;
+use crate::math::{is_perfect_square, is_perfect_square_big};
 
-    for _ in 2..count {
-        let next = fibs[fibs.len() - 1].saturating_add(fibs[fibs.len() - 2]);
-        fibs.push(next);
-    }
+pub fn generate_fibonacci(count: usize) -> Vec<usize> {
+    generate_fibonacci_checked(count).0
+}
 
-    fibs
+/// The first `count` terms, plus the index of the first term too large for
+/// usize, or `None` if every term is exact.
+///
+/// From that index on every value saturates at `usize::MAX`, so callers can
+/// tell where the values stop being real Fibonacci numbers. Fibonacci is
+/// the Lucas sequence U(1, −1).
+pub fn generate_fibonacci_checked(count: usize) -> (Vec<usize>, Option<usize>) {
+    lucas_terms_checked(1, -1, 0, 1, count)
 }
 
 pub fn generate_fibonacci_up_to(max_value: usize) -> Vec<usize> {
@@ -35,9 +32,10 @@ pub fn generate_fibonacci_up_to(max_value: usize) -> Vec<usize> {
 
     let mut fibs: Vec<usize> = vec![0, 1];
 
-    loop {
-        let next = fibs[fibs.len() - 1].saturating_add(fibs[fibs.len() - 2]);
-        if next > max_value || next < fibs[fibs.len() - 1] {
+    // checked_add rather than saturating_add: a saturated usize::MAX would
+    // never exceed max_value = usize::MAX and the loop would not end.
+    while let Some(next) = fibs[fibs.len() - 1].checked_add(fibs[fibs.len() - 2]) {
+        if next > max_value {
             break;
         }
         fibs.push(next);
@@ -51,26 +49,75 @@ pub fn is_fibonacci(n: usize) -> bool {
         return true;
     }
 
-    let n_sq = n.saturating_mul(n);
-    let five_n_sq_plus_4 = n_sq.saturating_mul(5).saturating_add(4);
-    let five_n_sq_minus_4 = n_sq.saturating_mul(5).saturating_sub(4);
-
-    is_perfect_square(five_n_sq_plus_4) || is_perfect_square(five_n_sq_minus_4)
+    // n is Fibonacci iff 5n² + 4 or 5n² - 4 is a perfect square, checked
+    // with the exact integer square root. When 5n² + 4 does not fit in usize
+    // the same check runs on BigUint.
+    match n
+        .checked_mul(n)
+        .and_then(|n_sq| n_sq.checked_mul(5))
+        .and_then(|five_n_sq| five_n_sq.checked_add(4))
+    {
+        Some(five_n_sq_plus_4) => {
+            is_perfect_square(five_n_sq_plus_4) || is_perfect_square(five_n_sq_plus_4 - 8)
+        }
+        None => {
+            let five_n_sq = BigUint::from(n).pow(2) * 5u32;
+            is_perfect_square_big(&(&five_n_sq + 4u32))
+                || is_perfect_square_big(&(five_n_sq - 4u32))
+        }
+    }
 }
 
-fn is_perfect_square(n: usize) -> bool {
-    if n == 0 {
-        return true;
+/// (Fₙ, Fₙ₊₁) by fast doubling: F₂ₖ = Fₖ(2Fₖ₊₁ − Fₖ), F₂ₖ₊₁ = Fₖ² + Fₖ₊₁².
+pub(crate) fn fibonacci_pair(n: u64) -> (BigUint, BigUint) {
+    let mut a = BigUint::zero();
+    let mut b = BigUint::one();
+
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        let c = &a * (&b * 2u32 - &a);
+        let d = &a * &a + &b * &b;
+        if n >> bit & 1 == 1 {
+            a = d;
+            b = c + &a;
+        } else {
+            a = c;
+            b = d;
+        }
     }
 
-    let root = (n as f64).sqrt() as usize;
-    root.saturating_mul(root) == n || root.saturating_add(1).saturating_mul(root + 1) == n
+    (a, b)
+}
+
+/// The nth Fibonacci number Fₙ, computed exactly in O(log n) multiplications.
+pub fn nth_fibonacci(n: u64) -> BigUint {
+    fibonacci_pair(n).0
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_checked_overflow_index() {
+        let first_too_big = (0..)
+            .find(|&i| nth_fibonacci(i) > BigUint::from(usize::MAX))
+            .unwrap() as usize;
+        let (values, overflow_at) = generate_fibonacci_checked(first_too_big + 5);
+        assert_eq!(overflow_at, Some(first_too_big));
+        assert!(values[first_too_big..].iter().all(|&v| v == usize::MAX));
+        assert_eq!(generate_fibonacci_checked(first_too_big).1, None);
+        assert_eq!(generate_fibonacci_checked(1), (vec![0], None));
+    }
+
+    #[test]
+    fn test_nth_fibonacci() {
+        for (i, &f) in generate_fibonacci(94).iter().enumerate() {
+            assert_eq!(nth_fibonacci(i as u64), BigUint::from(f), "F{}", i);
+        }
+        // F₁₀₀ exceeds u64
+        assert_eq!(nth_fibonacci(100).to_string(), "354224848179261915075");
+    }
+
     #[test]
     fn test_generate_small() {
         assert_eq!(generate_fibonacci(0), Vec::<usize>::new());
@@ -93,6 +140,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_fibonacci_large() {
+        // Every Fibonacci number that fits in usize, including those where
+        // 5n² overflows, and its neighbours
+        for &f in generate_fibonacci_up_to(usize::MAX).iter().skip(5) {
+            assert!(is_fibonacci(f), "{} should be Fibonacci", f);
+            assert!(!is_fibonacci(f - 1), "{} should NOT be Fibonacci", f - 1);
+            assert!(!is_fibonacci(f + 1), "{} should NOT be Fibonacci", f + 1);
+        }
+        assert!(!is_fibonacci(usize::MAX));
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_is_fibonacci_past_f64_precision() {
+        // F₈₀: 5n² + 4 is a 113-bit square, which an f64 square root misses
+        let f80: usize = 23_416_728_348_467_685;
+        let five_n_sq_plus_4 = 5 * (f80 as u128) * (f80 as u128) + 4;
+        let f64_root = (five_n_sq_plus_4 as f64).sqrt() as u128;
+        assert_ne!(f64_root * f64_root, five_n_sq_plus_4);
+
+        assert!(is_fibonacci(f80));
+        assert!(!is_fibonacci(f80 - 1));
+        assert!(!is_fibonacci(f80 + 1));
+    }
+
     #[test]
     fn test_is_fibonacci() {
         for &n in &[0, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144] {
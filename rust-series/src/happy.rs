@@ -4,34 +4,88 @@
 //! eventually reaches 1. Unhappy numbers enter a cycle (4, 16, 37, 58, 89, 145, 42, 20, 4).
 //! Sequence: 1, 7, 10, 13, 19, 23, 28, 31, 32, 44, 49, 68, 70, 79, ...
 
-pub fn is_happy(mut n: usize) -> bool {
-    let mut seen = [false; 1000];
+use std::sync::{Arc, LazyLock};
+use std::thread;
 
-    while n != 1 && !seen[n % 1000] {
-        seen[n % 1000] = true;
-        n = sum_of_digit_squares(n);
+use crate::digits::sum_of_digit_squares;
+
+/// Candidates each worker checks per round of `generate_happy_parallel`.
+const PARALLEL_CHUNK_SIZE: usize = 10_000;
+
+/// Largest base-10 digit-square sum of any usize: 20 digits of 9 give
+/// 20 · 81. One reduction step takes every n into the table below.
+const HAPPY_TABLE_MAX: usize = 20 * 81;
+
+/// Steps to reach 1 for each of 0..=HAPPY_TABLE_MAX in base 10, or `None`
+/// when unhappy. Happiness is decided once by cycle detection, so only
+/// happy numbers are walked.
+static HAPPY_TABLE: LazyLock<Vec<Option<usize>>> = LazyLock::new(|| {
+    (0..=HAPPY_TABLE_MAX)
+        .map(|n| {
+            is_happy_base(n, 10).then(|| {
+                let mut steps = 0;
+                let mut m = n;
+                while m != 1 {
+                    m = sum_of_digit_squares(m, 10);
+                    steps += 1;
+                }
+                steps
+            })
+        })
+        .collect()
+});
+
+/// Digit-square-sum steps for n to reach 1 in base 10, or `None` when n is
+/// unhappy. A table lookup after at most one step.
+fn happy_steps(n: usize) -> Option<usize> {
+    match HAPPY_TABLE.get(n) {
+        Some(&steps) => steps,
+        None => HAPPY_TABLE[sum_of_digit_squares(n, 10)].map(|steps| steps + 1),
     }
+}
 
-    n == 1
+/// Whether n is happy in base 10.
+pub fn is_happy(n: usize) -> bool {
+    happy_steps(n).is_some()
 }
 
-fn sum_of_digit_squares(mut n: usize) -> usize {
-    let mut sum = 0;
-    while n > 0 {
-        let digit = n % 10;
-        sum += digit * digit;
-        n /= 10;
+/// Check whether n is happy in `base`: iterating the digit-square sum reaches 1.
+///
+/// Uses Floyd cycle detection, so no per-call table is needed in any base.
+pub fn is_happy_base(n: usize, base: usize) -> bool {
+    if n == 0 {
+        return false;
+    }
+
+    let mut slow = n;
+    let mut fast = sum_of_digit_squares(n, base);
+    while fast != 1 && slow != fast {
+        slow = sum_of_digit_squares(slow, base);
+        fast = sum_of_digit_squares(sum_of_digit_squares(fast, base), base);
     }
-    sum
+
+    fast == 1
 }
 
 pub fn generate_happy(count: usize) -> Vec<usize> {
+    generate_happy_with_progress(count, None)
+}
+
+/// Generate the first `count` happy numbers, invoking `progress` with a
+/// delta of 1 for each happy number found.
+pub fn generate_happy_with_progress(
+    count: usize,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Vec<usize> {
     let mut happy: Vec<usize> = Vec::with_capacity(count);
     let mut n = 1;
 
     while happy.len() < count {
         if is_happy(n) {
             happy.push(n);
+            if let Some(ref callback) = progress {
+                callback(1);
+            }
         }
         n += 1;
     }
@@ -39,10 +93,97 @@ pub fn generate_happy(count: usize) -> Vec<usize> {
     happy
 }
 
+/// Generate the first `count` happy numbers using `threads` worker threads
+/// (0 = all available).
+///
+/// Candidates are scanned in rounds of one contiguous chunk per worker, so
+/// the output is identical to `generate_happy` for any thread count.
+/// `progress` receives a delta per round with the number of terms added.
+/// If a worker panics, its panic payload is returned as the error.
+pub fn generate_happy_parallel(
+    count: usize,
+    threads: usize,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> thread::Result<Vec<usize>> {
+    let threads = if threads == 0 {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        threads
+    };
+
+    let mut happy: Vec<usize> = Vec::with_capacity(count);
+    let mut next = 1;
+
+    while happy.len() < count {
+        let round_start = next;
+        let chunks: Vec<Vec<usize>> = thread::scope(|s| {
+            let handles: Vec<_> = (0..threads)
+                .map(|i| {
+                    let start = round_start + i * PARALLEL_CHUNK_SIZE;
+                    s.spawn(move || {
+                        (start..start + PARALLEL_CHUNK_SIZE)
+                            .filter(|&n| is_happy(n))
+                            .collect::<Vec<usize>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join())
+                .collect::<thread::Result<_>>()
+        })?;
+        next = round_start + threads * PARALLEL_CHUNK_SIZE;
+
+        let before = happy.len();
+        for chunk in chunks {
+            let remaining = count - happy.len();
+            happy.extend(chunk.into_iter().take(remaining));
+        }
+        if let Some(ref callback) = progress {
+            callback(happy.len() - before);
+        }
+    }
+
+    Ok(happy)
+}
+
 pub fn generate_happy_up_to(max_value: usize) -> Vec<usize> {
     (1..=max_value).filter(|&n| is_happy(n)).collect()
 }
 
+/// Happy numbers `<= max_value` paired with the number of digit-square-sum
+/// steps each takes to reach 1 (0 for 1 itself).
+pub fn generate_happy_with_steps_up_to(max_value: usize) -> Vec<(usize, usize)> {
+    (1..=max_value)
+        .filter_map(|n| happy_steps(n).map(|steps| (n, steps)))
+        .collect()
+}
+
+/// Same result as [`generate_happy_up_to`], without a digit-square sum per
+/// number.
+///
+/// Numbers 10q..10q+9 share the sum s(q) of their leading digits, so each
+/// block of ten costs one sum and ten lookups of s(q) + d² in the table.
+pub fn generate_happy_up_to_fast(max_value: usize) -> Vec<usize> {
+    let table = &*HAPPY_TABLE;
+    let mut happy = Vec::new();
+
+    for q in 0..=max_value / 10 {
+        let leading = sum_of_digit_squares(q, 10);
+        for d in 0..10 {
+            let n = 10 * q + d;
+            if n == 0 || n > max_value {
+                continue;
+            }
+            if table[leading + d * d].is_some() {
+                happy.push(n);
+            }
+        }
+    }
+
+    happy
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,6 +203,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_happy_base() {
+        assert!(!is_happy_base(0, 10));
+        // 3010 -> 10 -> 1; a table keyed on n % 1000 would mistake 10 for a repeat
+        assert!(is_happy_base(3010, 10));
+        assert!(is_happy_base(9100, 10));
+        // Every positive number is happy in base 2 (and base 4)
+        for n in 1..200 {
+            assert!(is_happy_base(n, 2), "{} should be happy in base 2", n);
+            assert!(is_happy_base(n, 4), "{} should be happy in base 4", n);
+        }
+        // Base 3: 1, 3, 9 are happy (powers of the base), 2 cycles 2 -> 4 -> 2
+        assert!(is_happy_base(3, 3));
+        assert!(is_happy_base(9, 3));
+        assert!(!is_happy_base(2, 3));
+    }
+
     #[test]
     fn test_generate_small() {
         assert_eq!(generate_happy(0), Vec::<usize>::new());
@@ -73,10 +231,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_parallel_independent_of_threads() {
+        let expected = generate_happy(30_000);
+        for threads in [1, 2, 3, 8] {
+            assert_eq!(
+                generate_happy_parallel(30_000, threads, None).unwrap(),
+                expected,
+                "Failed for threads={}",
+                threads
+            );
+        }
+        assert_eq!(generate_happy_parallel(30_000, 0, None).unwrap(), expected);
+        assert_eq!(
+            generate_happy_parallel(0, 4, None).unwrap(),
+            Vec::<usize>::new()
+        );
+        assert_eq!(
+            generate_happy_parallel(5, 4, None).unwrap(),
+            vec![1, 7, 10, 13, 19]
+        );
+    }
+
+    #[test]
+    fn test_generate_with_progress_fires_per_term() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        for count in [0, 1, 10, 100] {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let calls_ref = Arc::clone(&calls);
+            let happy = generate_happy_with_progress(
+                count,
+                Some(Arc::new(move |delta: usize| {
+                    calls_ref.fetch_add(delta, Ordering::Relaxed);
+                })),
+            );
+            assert_eq!(happy, generate_happy(count));
+            assert_eq!(calls.load(Ordering::Relaxed), count);
+        }
+    }
+
     #[test]
     fn test_generate_up_to() {
         assert_eq!(generate_happy_up_to(0), Vec::<usize>::new());
         assert_eq!(generate_happy_up_to(10), vec![1, 7, 10]);
         assert_eq!(generate_happy_up_to(20), vec![1, 7, 10, 13, 19]);
     }
+
+    #[test]
+    fn test_happy_steps() {
+        // 7 → 49 → 97 → 130 → 10 → 1
+        assert_eq!(happy_steps(7), Some(5));
+        assert_eq!(happy_steps(1), Some(0));
+        assert_eq!(happy_steps(10), Some(1));
+        assert_eq!(happy_steps(0), None);
+        assert_eq!(happy_steps(4), None);
+        // Past the table: 1_000_000_000 → 1
+        assert_eq!(happy_steps(1_000_000_000), Some(1));
+    }
+
+    #[test]
+    fn test_generate_with_steps_up_to() {
+        assert_eq!(generate_happy_with_steps_up_to(0), Vec::new());
+        assert_eq!(
+            generate_happy_with_steps_up_to(13),
+            vec![(1, 0), (7, 5), (10, 1), (13, 2)]
+        );
+        let numbers: Vec<usize> = generate_happy_with_steps_up_to(10_000)
+            .into_iter()
+            .map(|(n, _)| n)
+            .collect();
+        assert_eq!(numbers, generate_happy_up_to(10_000));
+    }
+
+    #[test]
+    fn test_generate_up_to_fast_matches() {
+        let expected = generate_happy_up_to(100_000);
+        assert_eq!(generate_happy_up_to_fast(100_000), expected);
+        // Independent of the table: plain cycle detection
+        let floyd: Vec<usize> = (1..=100_000).filter(|&n| is_happy_base(n, 10)).collect();
+        assert_eq!(expected, floyd);
+
+        for max_value in [0, 1, 6, 7, 9, 10, 11, 19, 20] {
+            assert_eq!(
+                generate_happy_up_to_fast(max_value),
+                generate_happy_up_to(max_value),
+                "max_value={}",
+                max_value
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_happy_past_table() {
+        // Largest usize reduces to at most the table bound
+        assert_eq!(is_happy(usize::MAX), is_happy_base(usize::MAX, 10));
+        assert!(is_happy(1_000_000_000));
+        // 1621 → 42, which is on the unhappy cycle
+        assert!(!is_happy(HAPPY_TABLE_MAX + 1));
+    }
 }
@@ -0,0 +1,58 @@
+//! Harshad (Niven) number generator
+//!
+//! A Harshad number is divisible by the sum of its base-10 digits.
+//! Sequence: 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 18, 20, 21, 24, 27, 30, ...
+
+use crate::digits::sum_of_digits;
+
+pub fn is_harshad(n: usize) -> bool {
+    if n == 0 {
+        return false;
+    }
+    n.is_multiple_of(sum_of_digits(n, 10))
+}
+
+pub fn generate_harshad(count: usize) -> Vec<usize> {
+    (1..).filter(|&n| is_harshad(n)).take(count).collect()
+}
+
+pub fn generate_harshad_up_to(max_value: usize) -> Vec<usize> {
+    (1..=max_value).filter(|&n| is_harshad(n)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_harshad() {
+        assert!(!is_harshad(0));
+        for &n in &[1, 2, 9, 10, 12, 18, 20, 21, 24, 27, 30, 100, 111] {
+            assert!(is_harshad(n), "{} should be Harshad", n);
+        }
+
+        for &n in &[11, 13, 14, 15, 16, 17, 19, 22, 23, 25, 26, 28, 29] {
+            assert!(!is_harshad(n), "{} should NOT be Harshad", n);
+        }
+    }
+
+    #[test]
+    fn test_generate_small() {
+        assert_eq!(generate_harshad(0), Vec::<usize>::new());
+        assert_eq!(generate_harshad(1), vec![1]);
+        assert_eq!(
+            generate_harshad(15),
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 18, 20, 21, 24]
+        );
+    }
+
+    #[test]
+    fn test_generate_up_to() {
+        assert_eq!(generate_harshad_up_to(0), Vec::<usize>::new());
+        assert_eq!(
+            generate_harshad_up_to(12),
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12]
+        );
+        assert_eq!(generate_harshad_up_to(30), generate_harshad(17));
+    }
+}
@@ -2,25 +2,33 @@
 //!
 //! Hₙ = n(2n-1)
 //! Sequence: 1, 6, 15, 28, 45, 66, 91, 120, 153, 190, ...
+//!
+//! Every hexagonal number is triangular: Hₙ = T₂ₙ₋₁.
+
+use crate::triangular::triangular_index;
 
 pub fn generate_hexagonal(count: usize) -> Vec<usize> {
     (1..=count).map(|n| n * (2 * n - 1)).collect()
 }
 
+/// Hexagonal numbers `<= max_value`, including `max_value` itself when it
+/// is hexagonal.
 pub fn generate_hexagonal_up_to(max_value: usize) -> Vec<usize> {
     if max_value < 1 {
         return Vec::new();
     }
 
     let mut hex: Vec<usize> = Vec::new();
-    let mut n = 1;
+    let mut h: usize = 0;
+    let mut n: usize = 1;
 
-    loop {
-        let h = n * (2 * n - 1);
-        if h > max_value {
+    // Hₙ = Hₙ₋₁ + 4n - 3; stop rather than wrap when Hₙ leaves usize
+    while let Some(next) = (4 * n - 3).checked_add(h) {
+        if next > max_value {
             break;
         }
-        hex.push(h);
+        hex.push(next);
+        h = next;
         n += 1;
     }
 
@@ -28,26 +36,33 @@ pub fn generate_hexagonal_up_to(max_value: usize) -> Vec<usize> {
 }
 
 pub fn is_hexagonal(n: usize) -> bool {
-    if n < 1 {
-        return false;
-    }
-
-    let eight_n_plus_1 = n.saturating_mul(8).saturating_add(1);
-    if !is_perfect_square(eight_n_plus_1) {
-        return false;
-    }
-
-    let root = (eight_n_plus_1 as f64).sqrt() as usize;
-    (root + 1).is_multiple_of(4)
+    hexagonal_index(n).is_some()
 }
 
-fn is_perfect_square(n: usize) -> bool {
-    if n == 0 {
-        return true;
-    }
+/// The k >= 1 with Hₖ = n, if n is hexagonal.
+pub fn hexagonal_index(n: usize) -> Option<usize> {
+    // Hₖ = T₂ₖ₋₁, so n is hexagonal exactly when its triangular index is odd
+    triangular_index(n)
+        .filter(|t| t % 2 == 1)
+        .map(|t| t.div_ceil(2))
+}
 
-    let root = (n as f64).sqrt() as usize;
-    root.saturating_mul(root) == n || root.saturating_add(1).saturating_mul(root + 1) == n
+/// The triangular index of n when n is hexagonal: Hₖ maps to T₂ₖ₋₁.
+///
+/// Returns `None` for numbers that are triangular but not hexagonal, such
+/// as T₂ = 3.
+///
+/// # Examples
+///
+/// ```
+/// use series::hexagonal_to_triangular_index;
+///
+/// // H₃ = T₅ = 15
+/// assert_eq!(hexagonal_to_triangular_index(15), Some(5));
+/// assert_eq!(hexagonal_to_triangular_index(3), None);
+/// ```
+pub fn hexagonal_to_triangular_index(n: usize) -> Option<usize> {
+    hexagonal_index(n).map(|k| 2 * k - 1)
 }
 
 #[cfg(test)]
@@ -75,6 +90,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_up_to_boundary() {
+        assert_eq!(generate_hexagonal_up_to(1), vec![1]);
+        assert_eq!(generate_hexagonal_up_to(5), vec![1]);
+        assert_eq!(generate_hexagonal_up_to(6), vec![1, 6]);
+        assert_eq!(generate_hexagonal_up_to(190).last(), Some(&190));
+
+        let expected: Vec<usize> = (0..=10_000).filter(|&n| is_hexagonal(n)).collect();
+        assert_eq!(generate_hexagonal_up_to(10_000), expected);
+    }
+
+    #[test]
+    fn test_hexagonal_index() {
+        assert_eq!(hexagonal_index(0), None);
+        assert_eq!(hexagonal_index(1), Some(1));
+        assert_eq!(hexagonal_index(15), Some(3));
+        assert_eq!(hexagonal_index(3), None);
+        for (i, &h) in generate_hexagonal(1000).iter().enumerate() {
+            assert_eq!(hexagonal_index(h), Some(i + 1));
+        }
+    }
+
+    #[test]
+    fn test_hexagonal_to_triangular_index() {
+        // H₃ = 15 = T₅
+        assert_eq!(hexagonal_to_triangular_index(15), Some(5));
+        assert_eq!(triangular_index(15), Some(5));
+        assert_eq!(hexagonal_to_triangular_index(1), Some(1));
+        // Triangular but not hexagonal, and neither
+        assert_eq!(hexagonal_to_triangular_index(10), None);
+        assert_eq!(hexagonal_to_triangular_index(16), None);
+        for &h in &generate_hexagonal(1000) {
+            assert_eq!(hexagonal_to_triangular_index(h), triangular_index(h));
+        }
+    }
+
     #[test]
     fn test_is_hexagonal() {
         for &n in &[1, 6, 15, 28, 45, 66, 91, 120, 153, 190] {
@@ -3,27 +3,114 @@
 //! Provides generators for various infinite sequences:
 //! - Fibonacci: F₀=0, F₁=1, Fₙ=Fₙ₋₁+Fₙ₋₂
 //! - Lucas: L₀=2, L₁=1, Lₙ=Lₙ₋₁+Lₙ₋₂
+//! - Lucas sequences: Uₙ(P,Q) and Vₙ(P,Q), generalizing Fibonacci, Lucas, and Pell
+//! - Pell: P₀=0, P₁=1, Pₙ=2Pₙ₋₁+Pₙ₋₂
 //! - Triangular: Tₙ = n(n+1)/2
 //! - Collatz: Stopping times (steps to reach 1)
 //! - Powers of 2: 2ⁿ
 //! - Catalan: Cₙ = (2n)!/(n!(n+1)!)
 //! - Hexagonal: Hₙ = n(2n-1)
+//! - Centered polygonal: Cₖ,ₙ = k·n(n-1)/2 + 1, such as centered hexagonal
+//! - Pentagonal: Pₙ = n(3n-1)/2
+//! - Partitions: p(n) via Euler's pentagonal number theorem
 //! - Happy: Numbers where digit-square sum reaches 1
+//! - Harshad: Numbers divisible by their digit sum
+//! - Self numbers: n that are not m + (digit sum of m) for any m
+//! - Palindromes: Numbers that read the same reversed
+//! - Emirps: Primes whose reversal is a different prime
+//! - Circular primes: Primes that stay prime under every digit rotation
+//! - Stern's diatomic: a(2n)=a(n), a(2n+1)=a(n)+a(n+1)
+//! - Thue–Morse: parity of the number of 1 bits in n
+//! - Divisor functions: σₖ(n), the sum of k-th powers of the divisors of n
+//! - Aliquot sequences: iterate n → sum of proper divisors
+//! - Amicable numbers: pairs where each is the other's proper divisor sum
 
+pub mod aliquot;
+pub mod amicable;
 pub mod catalan;
+pub mod centered;
+pub mod circular;
+pub mod classify;
 pub mod collatz;
+pub mod combinatorics;
+pub mod digits;
+pub mod divisor;
+pub mod emirp;
 pub mod fibonacci;
 pub mod happy;
+pub mod harshad;
 pub mod hexagonal;
 pub mod lucas;
+pub mod lucas_seq;
+pub mod math;
+pub mod palindrome;
+pub mod partition;
+pub mod pell;
+pub mod pentagonal;
 pub mod powers;
+pub mod self_number;
+pub mod sequence;
+pub mod stern;
+pub mod thue_morse;
 pub mod triangular;
 
-pub use catalan::{generate_catalan, generate_catalan_up_to, is_catalan};
-pub use collatz::{collatz_stopping_time, generate_collatz_times, generate_collatz_times_up_to};
-pub use fibonacci::{generate_fibonacci, generate_fibonacci_up_to, is_fibonacci};
-pub use happy::{generate_happy, generate_happy_up_to, is_happy};
-pub use hexagonal::{generate_hexagonal, generate_hexagonal_up_to, is_hexagonal};
-pub use lucas::{generate_lucas, generate_lucas_up_to, is_lucas};
+pub use aliquot::{aliquot_sequence, classify_aliquot, AliquotClass};
+pub use amicable::{generate_amicable_pairs_up_to, generate_amicable_up_to, is_amicable};
+pub use catalan::{
+    generate_catalan, generate_catalan_big_up_to, generate_catalan_up_to, is_catalan, nth_catalan,
+};
+pub use centered::{
+    generate_centered_hexagonal, generate_centered_hexagonal_up_to, generate_centered_polygonal,
+    is_centered_hexagonal,
+};
+pub use circular::{generate_circular_primes_up_to, is_circular_prime};
+pub use classify::classify;
+pub use collatz::{
+    collatz_altitude_records, collatz_delay_records, collatz_records, collatz_step,
+    collatz_stopping_time, collatz_stopping_time_big, generate_collatz_times,
+    generate_collatz_times_up_to, generate_collatz_times_with_progress, CollatzRecords,
+};
+pub use combinatorics::{binomial, factorial};
+pub use digits::{
+    digital_root, from_digits, reverse_digits, sum_of_digit_squares, sum_of_digits, to_base_string,
+    to_digits,
+};
+pub use divisor::{generate_sigma_series, sigma_k, sum_proper_divisors, sum_proper_divisors_sieve};
+pub use emirp::{generate_emirps, generate_emirps_up_to, is_emirp};
+pub use fibonacci::{
+    generate_fibonacci, generate_fibonacci_checked, generate_fibonacci_up_to, is_fibonacci,
+    nth_fibonacci,
+};
+pub use happy::{
+    generate_happy, generate_happy_parallel, generate_happy_up_to, generate_happy_up_to_fast,
+    generate_happy_with_progress, generate_happy_with_steps_up_to, is_happy, is_happy_base,
+};
+pub use harshad::{generate_harshad, generate_harshad_up_to, is_harshad};
+pub use hexagonal::{
+    generate_hexagonal, generate_hexagonal_up_to, hexagonal_index, hexagonal_to_triangular_index,
+    is_hexagonal,
+};
+pub use lucas::{
+    generate_lucas, generate_lucas_checked, generate_lucas_up_to, is_lucas, nth_lucas,
+};
+pub use lucas_seq::{lucas_terms, lucas_u_sequence, lucas_v_sequence};
+pub use math::{is_perfect_square, isqrt};
+pub use palindrome::{
+    generate_dual_palindromes_up_to, generate_palindromes, generate_palindromes_up_to,
+    is_dual_palindrome, is_palindrome,
+};
+pub use partition::generate_partition;
+pub use pell::{generate_pell, generate_pell_checked};
+pub use pentagonal::{
+    generate_generalized_pentagonal_up_to, generate_pentagonal, generate_pentagonal_up_to,
+    is_pentagonal,
+};
 pub use powers::{generate_powers_of_2, generate_powers_of_2_up_to, is_power_of_2};
-pub use triangular::{generate_triangular, generate_triangular_up_to, is_triangular};
+pub use self_number::{generate_self_numbers_up_to, is_self_number};
+pub use sequence::{find_sequence, generate, nth, GenerateMode, Sequence, SEQUENCES};
+pub use stern::{generate_stern, stern};
+pub use thue_morse::{generate_thue_morse, generate_thue_morse_positions, is_thue_morse_prefix};
+pub use triangular::{
+    generate_triangular, generate_triangular_big, generate_triangular_up_to, is_triangular,
+    nth_triangular_big, triangular_index,
+};
@@ -3,42 +3,40 @@
 //! L₀=2, L₁=1, Lₙ=Lₙ₋₁+Lₙ₋₂
 //! Sequence: 2, 1, 3, 4, 7, 11, 18, 29, 47, 76, ...
 
-pub fn generate_lucas(count: usize) -> Vec<usize> {
-    if count == 0 {
-        return Vec::new();
-    }
-    if count == 1 {
-        return vec![2];
-    }
-    if count == 2 {
-        return vec![2, 1];
-    }
+use num_bigint::BigUint;
 
-    let mut lucas: Vec<usize> = Vec::with_capacity(count);
-    lucas.push(2_usize);
-    lucas.push(1_usize);
+use crate::fibonacci::fibonacci_pair;
+use crate::lucas_seq::lucas_terms_checked;
+use crate::math::is_perfect_square;
 
-    for _ in 2..count {
-        let next = lucas[lucas.len() - 1].saturating_add(lucas[lucas.len() - 2]);
-        lucas.push(next);
-    }
+pub fn generate_lucas(count: usize) -> Vec<usize> {
+    generate_lucas_checked(count).0
+}
 
-    lucas
+/// The first `count` terms, plus the index of the first term too large for
+/// usize, or `None` if every term is exact.
+///
+/// From that index on every value saturates at `usize::MAX`, so callers can
+/// tell where the values stop being real Lucas numbers. Lucas is the Lucas
+/// sequence V(1, −1).
+pub fn generate_lucas_checked(count: usize) -> (Vec<usize>, Option<usize>) {
+    lucas_terms_checked(1, -1, 2, 1, count)
 }
 
+/// Lucas numbers `<= max_value` in sequence order, including `max_value`
+/// itself when it is a Lucas number. L₀ = 2 is omitted for `max_value < 2`.
 pub fn generate_lucas_up_to(max_value: usize) -> Vec<usize> {
     if max_value < 1 {
-        return vec![2];
+        return Vec::new();
     }
     if max_value < 2 {
-        return vec![2, 1];
+        return vec![1];
     }
 
     let mut lucas: Vec<usize> = vec![2, 1];
 
-    loop {
-        let next = lucas[lucas.len() - 1].saturating_add(lucas[lucas.len() - 2]);
-        if next > max_value || next < lucas[lucas.len() - 1] {
+    while let Some(next) = lucas[lucas.len() - 1].checked_add(lucas[lucas.len() - 2]) {
+        if next > max_value {
             break;
         }
         lucas.push(next);
@@ -48,30 +46,65 @@ pub fn generate_lucas_up_to(max_value: usize) -> Vec<usize> {
 }
 
 pub fn is_lucas(n: usize) -> bool {
+    if n == 0 {
+        return false;
+    }
     if n == 1 || n == 2 {
         return true;
     }
 
-    let n_sq = n.saturating_mul(n);
-    let five_n_sq_plus_20 = n_sq.saturating_mul(5).saturating_add(20);
-    let five_n_sq_minus_20 = n_sq.saturating_mul(5).saturating_sub(20);
-
-    is_perfect_square(five_n_sq_plus_20) || is_perfect_square(five_n_sq_minus_20)
-}
-
-fn is_perfect_square(n: usize) -> bool {
-    if n == 0 {
-        return true;
+    // n is Lucas iff 5n² + 20 or 5n² - 20 is a perfect square. When 5n² + 20
+    // does not fit in usize, walk the sequence instead (at most ~93 terms).
+    match n
+        .checked_mul(n)
+        .and_then(|n_sq| n_sq.checked_mul(5))
+        .and_then(|five_n_sq| five_n_sq.checked_add(20))
+    {
+        Some(five_n_sq_plus_20) => {
+            is_perfect_square(five_n_sq_plus_20) || is_perfect_square(five_n_sq_plus_20 - 40)
+        }
+        None => generate_lucas_up_to(n).last() == Some(&n),
     }
+}
 
-    let root = (n as f64).sqrt() as usize;
-    root.saturating_mul(root) == n || root.saturating_add(1).saturating_mul(root + 1) == n
+/// The nth Lucas number Lₙ = 2Fₙ₊₁ − Fₙ, computed exactly.
+pub fn nth_lucas(n: u64) -> BigUint {
+    let (f_n, f_next) = fibonacci_pair(n);
+    f_next * 2u32 - f_n
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_checked_overflow_index() {
+        let first_too_big = (0..)
+            .find(|&i| nth_lucas(i) > BigUint::from(usize::MAX))
+            .unwrap() as usize;
+        let (values, overflow_at) = generate_lucas_checked(first_too_big + 5);
+        assert_eq!(overflow_at, Some(first_too_big));
+        assert_eq!(
+            BigUint::from(values[first_too_big - 1]),
+            nth_lucas(first_too_big as u64 - 1)
+        );
+        assert!(values[first_too_big..].iter().all(|&v| v == usize::MAX));
+
+        assert_eq!(
+            generate_lucas_checked(first_too_big),
+            (generate_lucas(first_too_big), None)
+        );
+        assert_eq!(generate_lucas_checked(0), (vec![], None));
+    }
+
+    #[test]
+    fn test_nth_lucas() {
+        for (i, &l) in generate_lucas(90).iter().enumerate() {
+            assert_eq!(nth_lucas(i as u64), BigUint::from(l), "L{}", i);
+        }
+        assert_eq!(nth_lucas(100).to_string(), "792070839848372253127");
+    }
+
     #[test]
     fn test_generate_small() {
         assert_eq!(generate_lucas(0), Vec::<usize>::new());
@@ -82,8 +115,10 @@ mod tests {
 
     #[test]
     fn test_generate_up_to() {
-        assert_eq!(generate_lucas_up_to(0), vec![2]);
+        assert_eq!(generate_lucas_up_to(0), Vec::<usize>::new());
+        assert_eq!(generate_lucas_up_to(1), vec![1]);
         assert_eq!(generate_lucas_up_to(2), vec![2, 1]);
+        assert_eq!(generate_lucas_up_to(3), vec![2, 1, 3]);
         assert_eq!(generate_lucas_up_to(10), vec![2, 1, 3, 4, 7]);
         assert_eq!(
             generate_lucas_up_to(50),
@@ -97,8 +132,20 @@ mod tests {
             assert!(is_lucas(n), "{} should be Lucas", n);
         }
 
-        for &n in &[5, 6, 8, 9, 10, 12, 13, 14, 15, 16] {
+        for &n in &[0, 5, 6, 8, 9, 10, 12, 13, 14, 15, 16] {
             assert!(!is_lucas(n), "{} should NOT be Lucas", n);
         }
     }
+
+    #[test]
+    fn test_is_lucas_large() {
+        // Every Lucas number that fits in usize, including those where 5n²
+        // overflows, and its neighbours
+        for &l in generate_lucas_up_to(usize::MAX).iter().skip(5) {
+            assert!(is_lucas(l), "{} should be Lucas", l);
+            assert!(!is_lucas(l - 1), "{} should NOT be Lucas", l - 1);
+            assert!(!is_lucas(l + 1), "{} should NOT be Lucas", l + 1);
+        }
+        assert!(!is_lucas(usize::MAX));
+    }
 }
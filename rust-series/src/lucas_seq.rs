@@ -0,0 +1,138 @@
+//! General Lucas sequences U(P, Q) and V(P, Q)
+//!
+//! Xₙ = P·Xₙ₋₁ − Q·Xₙ₋₂, with U₀=0, U₁=1 and V₀=2, V₁=P.
+//! Fibonacci is U(1, −1), Lucas is V(1, −1), Pell is U(2, −1).
+//!
+//! Every term is computed exactly by [`lucas_terms`]; the fixed-width
+//! generators here and in `fibonacci`, `lucas` and `pell` only decide what to
+//! do with a term that does not fit. For `i64` it ends the sequence, so the
+//! result may hold fewer than `count` values for large parameters.
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+/// Exact terms of Xₙ = P·Xₙ₋₁ − Q·Xₙ₋₂ from X₀ = `x0`, X₁ = `x1`, without end.
+pub fn lucas_terms(p: i64, q: i64, x0: i64, x1: i64) -> impl Iterator<Item = BigInt> {
+    let (p, q) = (BigInt::from(p), BigInt::from(q));
+    let mut pair = (BigInt::from(x0), BigInt::from(x1));
+    std::iter::from_fn(move || {
+        let next = &p * &pair.1 - &q * &pair.0;
+        let (term, curr) = std::mem::replace(&mut pair, (BigInt::default(), next));
+        pair.0 = curr;
+        Some(term)
+    })
+}
+
+pub fn lucas_u_sequence(p: i64, q: i64, count: usize) -> Vec<i64> {
+    lucas_terms(p, q, 0, 1)
+        .take(count)
+        .map_while(|term| term.to_i64())
+        .collect()
+}
+
+pub fn lucas_v_sequence(p: i64, q: i64, count: usize) -> Vec<i64> {
+    lucas_terms(p, q, 2, p)
+        .take(count)
+        .map_while(|term| term.to_i64())
+        .collect()
+}
+
+/// The first `count` terms of a non-negative Lucas-family sequence as
+/// usize, plus the index of the first term too large for usize, or `None`
+/// if every term is exact.
+///
+/// From that index on every value saturates at `usize::MAX`; no larger
+/// terms are computed, so a huge `count` stays cheap.
+pub(crate) fn lucas_terms_checked(
+    p: i64,
+    q: i64,
+    x0: i64,
+    x1: i64,
+    count: usize,
+) -> (Vec<usize>, Option<usize>) {
+    let mut terms: Vec<usize> = Vec::with_capacity(count);
+    terms.extend(
+        lucas_terms(p, q, x0, x1)
+            .take(count)
+            .map_while(|term| term.to_usize()),
+    );
+
+    let overflow_at = (terms.len() < count).then_some(terms.len());
+    terms.resize(count, usize::MAX);
+    (terms, overflow_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate_fibonacci, generate_lucas};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(lucas_u_sequence(1, -1, 0), Vec::<i64>::new());
+        assert_eq!(lucas_v_sequence(1, -1, 0), Vec::<i64>::new());
+        assert_eq!(lucas_u_sequence(1, -1, 1), vec![0]);
+        assert_eq!(lucas_v_sequence(1, -1, 1), vec![2]);
+    }
+
+    #[test]
+    fn test_fibonacci_and_lucas() {
+        let fib: Vec<i64> = generate_fibonacci(40)
+            .into_iter()
+            .map(|n| n as i64)
+            .collect();
+        assert_eq!(lucas_u_sequence(1, -1, 40), fib);
+
+        let lucas: Vec<i64> = generate_lucas(40).into_iter().map(|n| n as i64).collect();
+        assert_eq!(lucas_v_sequence(1, -1, 40), lucas);
+    }
+
+    #[test]
+    fn test_pell() {
+        assert_eq!(
+            lucas_u_sequence(2, -1, 10),
+            vec![0, 1, 2, 5, 12, 29, 70, 169, 408, 985]
+        );
+        // Pell-Lucas (companion Pell) numbers
+        assert_eq!(
+            lucas_v_sequence(2, -1, 8),
+            vec![2, 2, 6, 14, 34, 82, 198, 478]
+        );
+    }
+
+    #[test]
+    fn test_lucas_terms_exact_past_i64() {
+        let f100 = lucas_terms(1, -1, 0, 1).nth(100).unwrap();
+        assert_eq!(f100.to_string(), "354224848179261915075");
+        // V(1, 1) never grows, so the big engine agrees with i64 forever
+        let v: Vec<i64> = lucas_terms(1, 1, 2, 1)
+            .take(12)
+            .map(|t| t.to_i64().unwrap())
+            .collect();
+        assert_eq!(v, lucas_v_sequence(1, 1, 12));
+    }
+
+    #[test]
+    fn test_checked_saturates_from_first_overflow() {
+        let (fib, overflow_at) = lucas_terms_checked(1, -1, 0, 1, 200);
+        let first_too_big = fib.iter().position(|&f| f == usize::MAX).unwrap();
+        assert_eq!(overflow_at, Some(first_too_big));
+        assert!(fib[first_too_big..].iter().all(|&f| f == usize::MAX));
+        assert_eq!(lucas_terms_checked(1, -1, 0, 1, 10).1, None);
+        assert_eq!(lucas_terms_checked(1, -1, 0, 1, 0), (vec![], None));
+    }
+
+    #[test]
+    fn test_negative_terms() {
+        // U(1, 1) cycles with period 6
+        assert_eq!(lucas_u_sequence(1, 1, 8), vec![0, 1, 1, 0, -1, -1, 0, 1]);
+    }
+
+    #[test]
+    fn test_overflow_stops_sequence() {
+        let fib = lucas_u_sequence(1, -1, 200);
+        // F₉₂ is the largest Fibonacci number that fits in i64
+        assert_eq!(fib.len(), 93);
+        assert_eq!(*fib.last().unwrap(), 7_540_113_804_746_346_429);
+    }
+}
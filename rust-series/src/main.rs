@@ -1,11 +1,14 @@
-use clap::{Parser, ValueEnum};
-use std::io::{self, BufWriter, Write};
-
-mod progress;
-use series::{
-    collatz_stopping_time, generate_catalan, generate_collatz_times, generate_fibonacci,
-    generate_happy, generate_hexagonal, generate_lucas, generate_powers_of_2, generate_triangular,
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use cli_progress::ProgressBar;
+use num_bigint::BigUint;
+use series::sequence::{
+    CATALAN, COLLATZ, EMIRP, FIBONACCI, HAPPY, HARSHAD, HEXAGONAL, LUCAS, PALINDROME, POWERS_OF_2,
+    TRIANGULAR,
 };
+use series::{generate_happy_parallel, to_base_string, Sequence};
+use std::io::{self, BufWriter, Write};
+use std::process;
+use std::sync::Arc;
 
 #[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
 enum Series {
@@ -17,6 +20,28 @@ enum Series {
     Catalan,
     Hex,
     Happy,
+    Harshad,
+    Palindrome,
+    Emirp,
+}
+
+impl Series {
+    /// The library generator for this series.
+    fn as_sequence(self) -> &'static dyn Sequence {
+        match self {
+            Series::Fib => &FIBONACCI,
+            Series::Lucas => &LUCAS,
+            Series::Tri => &TRIANGULAR,
+            Series::Collatz => &COLLATZ,
+            Series::Pow2 => &POWERS_OF_2,
+            Series::Catalan => &CATALAN,
+            Series::Hex => &HEXAGONAL,
+            Series::Happy => &HAPPY,
+            Series::Harshad => &HARSHAD,
+            Series::Palindrome => &PALINDROME,
+            Series::Emirp => &EMIRP,
+        }
+    }
 }
 
 impl std::fmt::Display for Series {
@@ -30,6 +55,9 @@ impl std::fmt::Display for Series {
             Series::Catalan => write!(f, "catalan"),
             Series::Hex => write!(f, "hexagonal"),
             Series::Happy => write!(f, "happy"),
+            Series::Harshad => write!(f, "harshad"),
+            Series::Palindrome => write!(f, "palindrome"),
+            Series::Emirp => write!(f, "emirp"),
         }
     }
 }
@@ -37,176 +65,145 @@ impl std::fmt::Display for Series {
 #[derive(Parser)]
 #[command(name = "series_cli")]
 #[command(about = "Infinite series generators", long_about = None)]
-struct Args {
-    #[arg(short, long, default_value = "10")]
-    count: usize,
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
 
-    #[arg(short = 's', long, value_enum, default_value = "fib")]
+    #[command(flatten)]
+    legacy: LegacyArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the first terms of a series
+    Generate(GenerateArgs),
+    /// Exit with 0 if N is a term of the series, 1 if not
+    Member { series: Series, n: usize },
+    /// Summarize the first terms of a series
+    Stats {
+        series: Series,
+        #[arg(short, long, default_value = "10")]
+        count: usize,
+    },
+    /// Print the term at INDEX, counting from 0 like `generate`
+    Nth { series: Series, index: u64 },
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    #[arg(value_enum, default_value = "fib")]
     series: Series,
 
+    #[arg(short, long, default_value = "10")]
+    count: usize,
+
     #[arg(short, long)]
     quiet: bool,
 
     #[arg(short = 'P', long)]
     progress: bool,
+
+    /// Worker threads for happy-number generation (0 = all available)
+    #[arg(short = 't', long, default_value = "0")]
+    threads: usize,
+
+    /// Base to print values in (2-36)
+    #[arg(short = 'b', long, default_value = "10", value_parser = clap::value_parser!(u32).range(2..=36))]
+    base: u32,
 }
 
-fn main() {
-    let args = Args::parse();
-
-    let values: Vec<usize> = match args.series {
-        Series::Fib => {
-            if args.progress {
-                let mut current: usize = 0;
-                let mut next: usize = 1;
-                let mut result = Vec::with_capacity(args.count);
-
-                let mut bar = progress::ProgressBar::new(args.count);
-                for i in 0..args.count {
-                    if i == 0 {
-                        result.push(0);
-                    } else if i == 1 {
-                        result.push(1);
-                    } else {
-                        let new_next = current.saturating_add(next);
-                        current = next;
-                        next = new_next;
-                        result.push(new_next);
-                    }
-                    bar.inc(1);
-                }
-                bar.finish();
-                result
-            } else {
-                generate_fibonacci(args.count)
-            }
-        }
-        Series::Lucas => {
-            if args.progress {
-                let mut prev: usize = 2;
-                let mut curr: usize = 1;
-                let mut result = Vec::with_capacity(args.count);
-
-                let mut bar = progress::ProgressBar::new(args.count);
-                for i in 0..args.count {
-                    if i == 0 {
-                        result.push(2);
-                    } else if i == 1 {
-                        result.push(1);
-                    } else {
-                        let next = prev.saturating_add(curr);
-                        prev = curr;
-                        curr = next;
-                        result.push(next);
-                    }
-                    bar.inc(1);
-                }
-                bar.finish();
-                result
-            } else {
-                generate_lucas(args.count)
-            }
-        }
-        Series::Tri => {
-            if args.progress {
-                let mut bar = progress::ProgressBar::new(args.count);
-                let result: Vec<usize> = (0..args.count)
-                    .map(|n| {
-                        bar.inc(1);
-                        n * (n + 1) / 2
-                    })
-                    .collect();
-                bar.finish();
-                result
-            } else {
-                generate_triangular(args.count)
-            }
-        }
-        Series::Collatz => {
-            if args.progress {
-                let mut bar = progress::ProgressBar::new(args.count);
-                let result: Vec<usize> = (0..args.count)
-                    .map(|n| {
-                        bar.inc(1);
-                        collatz_stopping_time(n)
-                    })
-                    .collect();
-                bar.finish();
-                result
-            } else {
-                generate_collatz_times(args.count)
-            }
-        }
-        Series::Pow2 => {
-            if args.progress {
-                let mut current: usize = 1;
-                let mut result = Vec::with_capacity(args.count);
-
-                let mut bar = progress::ProgressBar::new(args.count);
-                for _ in 0..args.count {
-                    result.push(current);
-                    current = current.saturating_mul(2);
-                    bar.inc(1);
-                }
-                bar.finish();
-                result
-            } else {
-                generate_powers_of_2(args.count)
-            }
+/// The flat flags from before subcommands existed, still accepted as a
+/// deprecated spelling of `generate`.
+#[derive(Args)]
+struct LegacyArgs {
+    #[arg(short, long, hide = true)]
+    count: Option<usize>,
+
+    #[arg(short = 's', long, value_enum, hide = true)]
+    series: Option<Series>,
+
+    #[arg(short, long, hide = true)]
+    quiet: bool,
+
+    #[arg(short = 'P', long, hide = true)]
+    progress: bool,
+
+    #[arg(short = 't', long, hide = true)]
+    threads: Option<usize>,
+
+    #[arg(short = 'b', long, hide = true, value_parser = clap::value_parser!(u32).range(2..=36))]
+    base: Option<u32>,
+}
+
+impl LegacyArgs {
+    fn is_used(&self) -> bool {
+        self.count.is_some()
+            || self.series.is_some()
+            || self.quiet
+            || self.progress
+            || self.threads.is_some()
+            || self.base.is_some()
+    }
+
+    fn into_generate(self) -> GenerateArgs {
+        GenerateArgs {
+            series: self.series.unwrap_or(Series::Fib),
+            count: self.count.unwrap_or(10),
+            quiet: self.quiet,
+            progress: self.progress,
+            threads: self.threads.unwrap_or(0),
+            base: self.base.unwrap_or(10),
         }
-        Series::Catalan => {
-            if args.progress {
-                let mut result = Vec::with_capacity(args.count);
-                let mut c = 1usize;
-
-                let mut bar = progress::ProgressBar::new(args.count);
-                for i in 0..args.count {
-                    result.push(c);
-                    if i > 0 {
-                        c = c.saturating_mul(2 * (2 * i - 1)) / (i + 1);
-                    }
-                    bar.inc(1);
-                }
-                bar.finish();
-                result
-            } else {
-                generate_catalan(args.count)
-            }
+    }
+}
+
+/// Minimum count before happy-number generation is spread across threads
+const HAPPY_PARALLEL_THRESHOLD: usize = 10_000;
+
+fn main() {
+    let cli = Cli::parse();
+
+    let command = cli.command.unwrap_or_else(|| {
+        if cli.legacy.is_used() {
+            eprintln!(
+                "warning: top-level flags are deprecated; use `series_cli generate <SERIES> -c <COUNT>`"
+            );
         }
-        Series::Hex => {
-            if args.progress {
-                let mut bar = progress::ProgressBar::new(args.count);
-                let result: Vec<usize> = (1..=args.count)
-                    .map(|n| {
-                        bar.inc(1);
-                        n * (2 * n - 1)
-                    })
-                    .collect();
-                bar.finish();
-                result
-            } else {
-                generate_hexagonal(args.count)
+        Command::Generate(cli.legacy.into_generate())
+    });
+
+    match command {
+        Command::Generate(args) => run_generate(&args),
+        Command::Member { series, n } => {
+            let member = is_member(series, n);
+            println!("{}", member);
+            if !member {
+                process::exit(1);
             }
         }
-        Series::Happy => {
-            if args.progress {
-                let mut result = Vec::with_capacity(args.count);
-                let mut n = 1;
-
-                let mut bar = progress::ProgressBar::new(args.count);
-                while result.len() < args.count {
-                    if series::is_happy(n) {
-                        result.push(n);
-                        bar.inc(1);
-                    }
-                    n += 1;
-                }
-                bar.finish();
-                result
-            } else {
-                generate_happy(args.count)
+        Command::Stats { series, count } => {
+            let values = generate(series, count, 0, None);
+            for line in stats_lines(&values) {
+                println!("{}", line);
             }
         }
+        Command::Nth { series, index } => println!("{}", nth_term(series, index)),
+    }
+}
+
+fn run_generate(args: &GenerateArgs) {
+    let values: Vec<usize> = if args.progress {
+        let bar = Arc::new(ProgressBar::new(args.count, "Progress"));
+        let bar_ref = Arc::clone(&bar);
+        let callback: Arc<dyn Fn(usize) + Send + Sync> =
+            Arc::new(move |delta: usize| bar_ref.inc(delta));
+
+        let values = generate(args.series, args.count, args.threads, Some(callback));
+        bar.finish();
+        values
+    } else {
+        generate(args.series, args.count, args.threads, None)
     };
 
     let stdout = io::stdout();
@@ -215,10 +212,129 @@ fn main() {
     if args.quiet {
         writeln!(writer, "{}", values.len()).unwrap();
     } else {
-        for val in &values {
-            writeln!(writer, "{}", val).unwrap();
-        }
+        write_values(&mut writer, &values, args.base as usize).unwrap();
     }
 
     writer.flush().unwrap();
 }
+
+/// Whether `n` is a term of `series`, by its membership test when it has
+/// one and otherwise by generating the terms up to `n`.
+fn is_member(series: Series, n: usize) -> bool {
+    let sequence = series.as_sequence();
+    sequence
+        .contains_up_to(n, n)
+        .unwrap_or_else(|| sequence.generate_up_to(n).contains(&n))
+}
+
+/// Count, min, max, sum and mean of `values`, one `name: value` per line.
+fn stats_lines(values: &[usize]) -> Vec<String> {
+    let sum: u128 = values.iter().map(|&v| v as u128).sum();
+    let mut lines = vec![format!("count: {}", values.len())];
+    if let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) {
+        lines.push(format!("min: {}", min));
+        lines.push(format!("max: {}", max));
+        lines.push(format!("sum: {}", sum));
+        lines.push(format!("mean: {:.3}", sum as f64 / values.len() as f64));
+    }
+    lines
+}
+
+/// The term at `index`: exact from the series' direct formula when it has
+/// one, otherwise the last of `index + 1` generated terms.
+fn nth_term(series: Series, index: u64) -> BigUint {
+    let sequence = series.as_sequence();
+    series::nth(sequence, index).unwrap_or_else(|| {
+        let terms = sequence.generate(index as usize + 1);
+        BigUint::from(terms.last().copied().unwrap_or_default())
+    })
+}
+
+/// Generate `count` terms of `series`.
+///
+/// Progress granularity comes from the series' `Sequence` implementation.
+/// Only happy numbers use `threads`, and only for large counts.
+fn generate(
+    series: Series,
+    count: usize,
+    threads: usize,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Vec<usize> {
+    if series == Series::Happy && threads != 1 && count >= HAPPY_PARALLEL_THRESHOLD {
+        return generate_happy_parallel(count, threads, progress).unwrap_or_else(|payload| {
+            let msg = payload
+                .downcast_ref::<&str>()
+                .map(|msg| msg.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            eprintln!("error: worker thread panicked: {}", msg);
+            process::exit(1);
+        });
+    }
+    series.as_sequence().generate_with_progress(count, progress)
+}
+
+/// Write each value in `base`, one per line, formatting as it goes so
+/// large outputs stream instead of being built up in memory.
+fn write_values(writer: &mut impl Write, values: &[usize], base: usize) -> io::Result<()> {
+    for &value in values {
+        writeln!(writer, "{}", to_base_string(value, base))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_every_series_maps_to_matching_sequence() {
+        for series in Series::value_variants() {
+            assert_eq!(series.as_sequence().name(), series.to_string());
+        }
+    }
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_is_member() {
+        assert!(is_member(Series::Fib, 13));
+        assert!(!is_member(Series::Fib, 14));
+        // No closed form, so decided by generating
+        assert!(is_member(Series::Happy, 7));
+        assert!(!is_member(Series::Happy, 4));
+    }
+
+    #[test]
+    fn test_stats_lines() {
+        let values = generate(Series::Fib, 10, 0, None);
+        assert_eq!(
+            stats_lines(&values),
+            vec!["count: 10", "min: 0", "max: 34", "sum: 88", "mean: 8.800"]
+        );
+        assert_eq!(stats_lines(&[]), vec!["count: 0"]);
+    }
+
+    #[test]
+    fn test_nth_term() {
+        assert_eq!(nth_term(Series::Fib, 10), BigUint::from(55u32));
+        // Harshad has no direct formula: 1..=10 are all Harshad, then 12
+        assert_eq!(nth_term(Series::Harshad, 10), BigUint::from(12u32));
+    }
+
+    #[test]
+    fn test_powers_of_2_in_base_2() {
+        let values = generate(Series::Pow2, 4, 0, None);
+        let written = |base| {
+            let mut out = Vec::new();
+            write_values(&mut out, &values, base).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(written(2), "1\n10\n100\n1000\n");
+        assert_eq!(written(10), "1\n2\n4\n8\n");
+    }
+}
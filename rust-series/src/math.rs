@@ -0,0 +1,94 @@
+//! Exact integer helpers shared by the series membership tests
+//!
+//! Casting to `f64` loses precision above 2⁵³, so these stay in integer
+//! arithmetic throughout.
+
+use num_bigint::BigUint;
+
+/// Integer square root: the largest r with r² <= n.
+///
+/// Newton's method from an initial guess of 2^ceil(bits/2), which is always
+/// at or above the root, so iterates decrease monotonically to the answer.
+pub fn isqrt(n: usize) -> usize {
+    if n < 2 {
+        return n;
+    }
+
+    let bits = usize::BITS - n.leading_zeros();
+    let mut x: usize = 1 << bits.div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+pub fn is_perfect_square(n: usize) -> bool {
+    let root = isqrt(n);
+    root * root == n
+}
+
+/// [`is_perfect_square`] for values past `usize`.
+pub(crate) fn is_perfect_square_big(n: &BigUint) -> bool {
+    let root = n.sqrt();
+    &root * &root == *n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isqrt_small() {
+        let expected = [0, 1, 1, 1, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 4];
+        for (n, &root) in expected.iter().enumerate() {
+            assert_eq!(isqrt(n), root, "isqrt({})", n);
+        }
+    }
+
+    #[test]
+    fn test_isqrt_matches_definition() {
+        for n in 0..10_000 {
+            let r = isqrt(n);
+            assert!(r * r <= n && (r + 1) * (r + 1) > n, "isqrt({}) = {}", n, r);
+        }
+    }
+
+    #[test]
+    fn test_isqrt_max() {
+        assert_eq!(isqrt(usize::MAX), (1 << (usize::BITS / 2)) - 1);
+    }
+
+    #[test]
+    fn test_is_perfect_square_small() {
+        for n in [0, 1, 4, 9, 16, 25, 144, 10_000] {
+            assert!(is_perfect_square(n), "{} should be a square", n);
+        }
+        for n in [2, 3, 5, 8, 15, 24, 143, 9_999] {
+            assert!(!is_perfect_square(n), "{} should NOT be a square", n);
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_is_perfect_square_near_2_pow_63() {
+        // 3037000499² is the largest square below 2⁶³; f64 cannot tell it
+        // apart from its neighbours.
+        let r: usize = 3_037_000_499;
+        let square = r * r;
+        assert_eq!(square as f64, (square + 1) as f64);
+        assert!(is_perfect_square(square));
+        assert!(!is_perfect_square(square - 1));
+        assert!(!is_perfect_square(square + 1));
+        assert_eq!(isqrt(square - 1), r - 1);
+        assert_eq!(isqrt(square + 1), r);
+        assert_eq!(isqrt(1 << 63), r);
+
+        let r_max: usize = u32::MAX as usize;
+        assert!(is_perfect_square(r_max * r_max));
+        assert!(!is_perfect_square(r_max * r_max + 1));
+        assert!(!is_perfect_square(usize::MAX));
+    }
+}
@@ -0,0 +1,113 @@
+//! Palindromic number generator
+//!
+//! A palindromic number reads the same with its digits reversed.
+//! Sequence (base 10): 0, 1, 2, ..., 9, 11, 22, 33, ..., 99, 101, 111, 121, ...
+//! Palindromic in both bases 10 and 2: 0, 1, 3, 5, 7, 9, 33, 99, 313, 585, ...
+
+use crate::digits::to_digits;
+
+pub fn is_palindrome(n: usize, base: usize) -> bool {
+    let digits = to_digits(n, base);
+    digits.iter().eq(digits.iter().rev())
+}
+
+/// Whether n is a palindrome in both `base_a` and `base_b`.
+pub fn is_dual_palindrome(n: usize, base_a: usize, base_b: usize) -> bool {
+    is_palindrome(n, base_a) && is_palindrome(n, base_b)
+}
+
+pub fn generate_dual_palindromes_up_to(
+    max_value: usize,
+    base_a: usize,
+    base_b: usize,
+) -> Vec<usize> {
+    (0..=max_value)
+        .filter(|&n| is_dual_palindrome(n, base_a, base_b))
+        .collect()
+}
+
+pub fn generate_palindromes(count: usize) -> Vec<usize> {
+    (0..)
+        .filter(|&n| is_palindrome(n, 10))
+        .take(count)
+        .collect()
+}
+
+pub fn generate_palindromes_up_to(max_value: usize) -> Vec<usize> {
+    (0..=max_value).filter(|&n| is_palindrome(n, 10)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_palindrome() {
+        for &n in &[0, 7, 11, 121, 1331, 12321] {
+            assert!(is_palindrome(n, 10), "{} should be a palindrome", n);
+        }
+
+        for &n in &[10, 12, 123, 1231, 1000] {
+            assert!(!is_palindrome(n, 10), "{} should NOT be a palindrome", n);
+        }
+    }
+
+    #[test]
+    fn test_is_palindrome_base_2() {
+        assert!(is_palindrome(0b1001, 2));
+        assert!(is_palindrome(585, 2)); // 1001001001
+        assert!(!is_palindrome(0b1011, 2));
+        // Even numbers end in 0 in binary, so none above 0 are palindromes
+        assert!(!is_palindrome(6, 2));
+    }
+
+    #[test]
+    fn test_is_dual_palindrome() {
+        assert!(is_dual_palindrome(0, 10, 2));
+        // 585 is 1001001001 in binary
+        assert!(is_dual_palindrome(585, 10, 2));
+        // Odd single digits only: even numbers above 0 end in 0 in binary
+        for n in [1, 3, 5, 7, 9] {
+            assert!(is_dual_palindrome(n, 10, 2), "{} should qualify", n);
+        }
+        for n in [2, 4, 6, 8, 10] {
+            assert!(!is_dual_palindrome(n, 10, 2), "{} should NOT qualify", n);
+        }
+        // The order of the bases doesn't matter
+        assert!(is_dual_palindrome(585, 2, 10));
+    }
+
+    #[test]
+    fn test_generate_dual_up_to() {
+        assert_eq!(
+            generate_dual_palindromes_up_to(1000, 10, 2),
+            vec![0, 1, 3, 5, 7, 9, 33, 99, 313, 585, 717]
+        );
+        // Pairing a base with itself is just the single-base test
+        assert_eq!(
+            generate_dual_palindromes_up_to(1000, 10, 10),
+            generate_palindromes_up_to(1000)
+        );
+    }
+
+    #[test]
+    fn test_generate_small() {
+        assert_eq!(generate_palindromes(0), Vec::<usize>::new());
+        assert_eq!(generate_palindromes(1), vec![0]);
+        assert_eq!(
+            generate_palindromes(13),
+            vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 11, 22, 33]
+        );
+    }
+
+    #[test]
+    fn test_generate_up_to() {
+        assert_eq!(generate_palindromes_up_to(0), vec![0]);
+        assert_eq!(
+            generate_palindromes_up_to(50),
+            vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 11, 22, 33, 44]
+        );
+        // 0-9, then 9 two-digit and 90 three-digit palindromes
+        assert_eq!(generate_palindromes_up_to(999).len(), 10 + 9 + 90);
+    }
+}
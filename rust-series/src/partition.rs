@@ -0,0 +1,63 @@
+//! Integer partition function p(n)
+//!
+//! p(n) counts the ways to write n as a sum of positive integers, ignoring
+//! order. Sequence: 1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42, ...
+//!
+//! Euler's pentagonal number theorem gives the recurrence
+//! p(n) = Σₖ (-1)^(k-1) [p(n - k(3k-1)/2) + p(n - k(3k+1)/2)]
+//! over the generalized pentagonal numbers, with signs + + - - + + ...
+
+use crate::pentagonal::generate_generalized_pentagonal_up_to;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// p(0), p(1), ..., p(count - 1). The values outgrow u64 from p(417).
+pub fn generate_partition(count: usize) -> Vec<BigUint> {
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let pentagonals = generate_generalized_pentagonal_up_to(count - 1);
+    let mut partitions: Vec<BigUint> = Vec::with_capacity(count);
+    partitions.push(BigUint::one());
+
+    for n in 1..count {
+        // Sum the two signs separately; the total is always positive
+        let mut plus = BigUint::zero();
+        let mut minus = BigUint::zero();
+
+        for (i, &g) in pentagonals.iter().take_while(|&&g| g <= n).enumerate() {
+            if (i / 2) % 2 == 0 {
+                plus += &partitions[n - g];
+            } else {
+                minus += &partitions[n - g];
+            }
+        }
+
+        partitions.push(plus - minus);
+    }
+
+    partitions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_small() {
+        assert!(generate_partition(0).is_empty());
+        let expected: Vec<BigUint> = [1u32, 1, 2, 3, 5, 7, 11, 15, 22, 30]
+            .iter()
+            .map(|&v| BigUint::from(v))
+            .collect();
+        assert_eq!(generate_partition(10), expected);
+    }
+
+    #[test]
+    fn test_known_large_values() {
+        let partitions = generate_partition(201);
+        assert_eq!(partitions[100], BigUint::from(190_569_292u64));
+        assert_eq!(partitions[200], "3972999029388".parse::<BigUint>().unwrap());
+    }
+}
@@ -0,0 +1,46 @@
+//! Pell number generator
+//!
+//! P₀=0, P₁=1, Pₙ=2Pₙ₋₁+Pₙ₋₂, the Lucas sequence U(2, −1)
+//! Sequence: 0, 1, 2, 5, 12, 29, 70, 169, 408, 985, ...
+
+use crate::lucas_seq::lucas_terms_checked;
+
+pub fn generate_pell(count: usize) -> Vec<usize> {
+    generate_pell_checked(count).0
+}
+
+/// The first `count` terms, plus the index of the first term too large for
+/// usize, or `None` if every term is exact.
+///
+/// From that index on every value saturates at `usize::MAX`.
+pub fn generate_pell_checked(count: usize) -> (Vec<usize>, Option<usize>) {
+    lucas_terms_checked(2, -1, 0, 1, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lucas_seq::lucas_terms;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_generate_small() {
+        assert_eq!(generate_pell(0), Vec::<usize>::new());
+        assert_eq!(generate_pell(1), vec![0]);
+        assert_eq!(
+            generate_pell(10),
+            vec![0, 1, 2, 5, 12, 29, 70, 169, 408, 985]
+        );
+    }
+
+    #[test]
+    fn test_checked_overflow_index() {
+        let first_too_big = lucas_terms(2, -1, 0, 1)
+            .position(|p| p > BigInt::from(usize::MAX))
+            .unwrap();
+        let (values, overflow_at) = generate_pell_checked(first_too_big + 3);
+        assert_eq!(overflow_at, Some(first_too_big));
+        assert!(values[first_too_big..].iter().all(|&v| v == usize::MAX));
+        assert_eq!(generate_pell_checked(first_too_big).1, None);
+    }
+}
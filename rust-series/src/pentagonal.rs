@@ -0,0 +1,137 @@
+//! Pentagonal number generator
+//!
+//! Pₙ = n(3n-1)/2
+//! Sequence: 1, 5, 12, 22, 35, 51, 70, 92, 117, 145, ...
+//!
+//! Generalized pentagonal numbers also take n = -1, -2, ...:
+//! 1, 2, 5, 7, 12, 15, 22, 26, 35, 40, ...
+
+use num_bigint::BigUint;
+
+use crate::math::{is_perfect_square, isqrt};
+
+pub fn generate_pentagonal(count: usize) -> Vec<usize> {
+    (1..=count).map(|n| n * (3 * n - 1) / 2).collect()
+}
+
+/// Pentagonal numbers `<= max_value`, including `max_value` itself when it
+/// is pentagonal.
+pub fn generate_pentagonal_up_to(max_value: usize) -> Vec<usize> {
+    let mut pent: Vec<usize> = Vec::new();
+    let mut p: usize = 0;
+    let mut n: usize = 1;
+
+    // Pₙ = Pₙ₋₁ + 3n - 2; stop rather than wrap when Pₙ leaves usize
+    while let Some(next) = (3 * n - 2).checked_add(p) {
+        if next > max_value {
+            break;
+        }
+        pent.push(next);
+        p = next;
+        n += 1;
+    }
+
+    pent
+}
+
+/// Generalized pentagonal numbers `<= max_value` in increasing order,
+/// k(3k-1)/2 for k = 1, -1, 2, -2, ...
+pub fn generate_generalized_pentagonal_up_to(max_value: usize) -> Vec<usize> {
+    let mut pent: Vec<usize> = Vec::new();
+
+    for k in 1usize.. {
+        // k(3k-1)/2 < k(3k+1)/2, and both grow with k
+        let Some(lower) = k.checked_mul(3 * k - 1).map(|v| v / 2) else {
+            break;
+        };
+        if lower > max_value {
+            break;
+        }
+        pent.push(lower);
+
+        match k.checked_mul(3 * k + 1).map(|v| v / 2) {
+            Some(upper) if upper <= max_value => pent.push(upper),
+            _ => break,
+        }
+    }
+
+    pent
+}
+
+pub fn is_pentagonal(n: usize) -> bool {
+    if n < 1 {
+        return false;
+    }
+
+    // n = k(3k-1)/2 for k >= 1 iff 24n + 1 is a square r² with r ≡ 5 (mod 6).
+    // When 24n + 1 does not fit in usize the same check runs on BigUint.
+    let Some(twenty_four_n_plus_1) = n.checked_mul(24).and_then(|v| v.checked_add(1)) else {
+        let big = BigUint::from(n) * 24u32 + 1u32;
+        let root = big.sqrt();
+        return &root * &root == big && root % 6u32 == BigUint::from(5u32);
+    };
+    if !is_perfect_square(twenty_four_n_plus_1) {
+        return false;
+    }
+
+    isqrt(twenty_four_n_plus_1) % 6 == 5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_small() {
+        assert_eq!(generate_pentagonal(0), Vec::<usize>::new());
+        assert_eq!(generate_pentagonal(1), vec![1]);
+        assert_eq!(
+            generate_pentagonal(10),
+            vec![1, 5, 12, 22, 35, 51, 70, 92, 117, 145]
+        );
+    }
+
+    #[test]
+    fn test_generate_up_to() {
+        assert_eq!(generate_pentagonal_up_to(0), Vec::<usize>::new());
+        assert_eq!(generate_pentagonal_up_to(11), vec![1, 5]);
+        assert_eq!(generate_pentagonal_up_to(12), vec![1, 5, 12]);
+    }
+
+    #[test]
+    fn test_generalized() {
+        assert_eq!(
+            generate_generalized_pentagonal_up_to(0),
+            Vec::<usize>::new()
+        );
+        assert_eq!(
+            generate_generalized_pentagonal_up_to(40),
+            vec![1, 2, 5, 7, 12, 15, 22, 26, 35, 40]
+        );
+    }
+
+    #[test]
+    fn test_is_pentagonal() {
+        for &n in &[1, 5, 12, 22, 35, 51, 70, 92, 117, 145] {
+            assert!(is_pentagonal(n), "{} should be pentagonal", n);
+        }
+
+        // Generalized-only values (k < 0) are not pentagonal
+        for &n in &[0, 2, 3, 4, 6, 7, 15, 26, 40] {
+            assert!(!is_pentagonal(n), "{} should NOT be pentagonal", n);
+        }
+    }
+
+    #[test]
+    fn test_is_pentagonal_near_usize_max() {
+        // 24n + 1 overflows usize for all of these
+        let k = 1usize << (usize::BITS / 2 - 1);
+        let p = k * (3 * k - 1) / 2;
+        assert!(p > usize::MAX / 24);
+        assert!(is_pentagonal(p));
+        assert!(!is_pentagonal(p - 1));
+        assert!(!is_pentagonal(p + 1));
+        assert!(!is_pentagonal(usize::MAX / 20));
+        assert!(!is_pentagonal(usize::MAX));
+    }
+}
@@ -21,6 +21,8 @@ pub fn generate_powers_of_2(count: usize) -> Vec<usize> {
     powers
 }
 
+/// Powers of 2 `<= max_value`; `max_value` itself is included when it is a
+/// power of 2, e.g. `generate_powers_of_2_up_to(8)` is `[1, 2, 4, 8]`.
 pub fn generate_powers_of_2_up_to(max_value: usize) -> Vec<usize> {
     if max_value < 1 {
         return Vec::new();
@@ -64,12 +66,21 @@ mod tests {
         assert_eq!(generate_powers_of_2_up_to(0), Vec::<usize>::new());
         assert_eq!(generate_powers_of_2_up_to(1), vec![1]);
         assert_eq!(generate_powers_of_2_up_to(10), vec![1, 2, 4, 8]);
+        assert_eq!(generate_powers_of_2_up_to(7), vec![1, 2, 4]);
+        assert_eq!(generate_powers_of_2_up_to(8), vec![1, 2, 4, 8]);
         assert_eq!(
             generate_powers_of_2_up_to(100),
             vec![1, 2, 4, 8, 16, 32, 64]
         );
     }
 
+    #[test]
+    fn test_generate_up_to_max() {
+        let powers = generate_powers_of_2_up_to(usize::MAX);
+        assert_eq!(powers.len(), usize::BITS as usize);
+        assert_eq!(*powers.last().unwrap(), 1 << (usize::BITS - 1));
+    }
+
     #[test]
     fn test_is_power_of_2() {
         for &n in &[1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024] {
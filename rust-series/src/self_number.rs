@@ -0,0 +1,75 @@
+//! Self (Colombian) number generator
+//!
+//! A self number cannot be written as m + (sum of m's base-10 digits) for
+//! any m. Sequence: 1, 3, 5, 7, 9, 20, 31, 42, 53, 64, 75, 86, 97, 108, ...
+
+use crate::digits::{sum_of_digits, to_digits};
+
+pub fn is_self_number(n: usize) -> bool {
+    if n == 0 {
+        // 0 = 0 + 0
+        return false;
+    }
+
+    // A generator m < n adds at most 9 per digit, so it lies within
+    // 9·(digits of n) below n
+    let reach = 9 * to_digits(n, 10).len();
+    (n.saturating_sub(reach)..n).all(|m| m.checked_add(sum_of_digits(m, 10)) != Some(n))
+}
+
+/// Self numbers `<= max_value`.
+///
+/// Sieves rather than testing each n: every m <= `max_value` marks
+/// m + digitsum(m), and the unmarked values are the self numbers.
+pub fn generate_self_numbers_up_to(max_value: usize) -> Vec<usize> {
+    let mut generated = vec![false; max_value.saturating_add(1)];
+    for m in 0..=max_value {
+        if let Some(slot) = generated.get_mut(m + sum_of_digits(m, 10)) {
+            *slot = true;
+        }
+    }
+
+    generated
+        .iter()
+        .enumerate()
+        .filter_map(|(n, &marked)| (!marked).then_some(n))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_numbers_below_60() {
+        assert_eq!(
+            generate_self_numbers_up_to(59),
+            vec![1, 3, 5, 7, 9, 20, 31, 42, 53]
+        );
+        for &n in &[1, 3, 5, 7, 9, 20, 31, 42, 53] {
+            assert!(is_self_number(n), "{} should be a self number", n);
+        }
+    }
+
+    #[test]
+    fn test_is_self_number_matches_sieve() {
+        let sieve = generate_self_numbers_up_to(10_000);
+        for n in 0..=10_000 {
+            assert_eq!(
+                is_self_number(n),
+                sieve.binary_search(&n).is_ok(),
+                "Failed for n={}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_up_to_small() {
+        assert_eq!(generate_self_numbers_up_to(0), Vec::<usize>::new());
+        assert_eq!(generate_self_numbers_up_to(1), vec![1]);
+        // 2 = 1 + 1, 4 = 2 + 2
+        assert!(!is_self_number(2));
+        assert!(!is_self_number(4));
+    }
+}
@@ -0,0 +1,507 @@
+//! Uniform access to the count-based series generators
+//!
+//! Every series with both a count-based and an up-to generator is exposed as
+//! a [`Sequence`] so callers (such as the CLI) can pick a generator at runtime
+//! instead of matching on each series.
+
+use std::sync::Arc;
+
+use num_bigint::BigUint;
+use num_traits::One;
+
+use crate::{
+    catalan, centered, collatz, emirp, fibonacci, happy, harshad, hexagonal, lucas, palindrome,
+    pentagonal, powers, triangular,
+};
+
+/// A named integer series.
+pub trait Sequence: Send + Sync {
+    /// Lowercase display name, e.g. "fibonacci".
+    fn name(&self) -> &'static str;
+
+    /// The first `count` terms.
+    fn generate(&self, count: usize) -> Vec<usize>;
+
+    /// All terms `<= max_value`, in sequence order. The bound is inclusive:
+    /// a term equal to `max_value` is returned. Collatz is indexed rather
+    /// than valued, yielding the stopping times of `0..=max_value`.
+    fn generate_up_to(&self, max_value: usize) -> Vec<usize>;
+
+    /// Whether `n` is one of the terms `<= max_value`, decided without
+    /// generating them. `None` when the series has no direct membership
+    /// test, in which case callers should search `generate_up_to` instead.
+    fn contains_up_to(&self, _n: usize, _max_value: usize) -> Option<bool> {
+        None
+    }
+
+    /// The term at `index`, indexed like `generate` so that `nth(i)` is
+    /// `generate(i + 1)[i]`, but exact and computed without the terms before
+    /// it. `None` when the series has no direct formula.
+    fn nth(&self, _index: u64) -> Option<BigUint> {
+        None
+    }
+
+    /// The first `count` terms, reporting progress deltas that sum to the
+    /// number of terms generated.
+    ///
+    /// The default reports once when done, which suits series that are
+    /// effectively instantaneous.
+    fn generate_with_progress(
+        &self,
+        count: usize,
+        progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    ) -> Vec<usize> {
+        report_once(self.generate(count), progress)
+    }
+}
+
+fn report_once(
+    values: Vec<usize>,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+) -> Vec<usize> {
+    if let Some(callback) = progress {
+        callback(values.len());
+    }
+    values
+}
+
+type ProgressGenerator = fn(usize, Option<Arc<dyn Fn(usize) + Send + Sync>>) -> Vec<usize>;
+
+/// A [`Sequence`] backed by a series module's free functions.
+pub struct FnSequence {
+    name: &'static str,
+    generate: fn(usize) -> Vec<usize>,
+    generate_up_to: fn(usize) -> Vec<usize>,
+    /// Closed-form membership predicate, if the series has one
+    is_member: Option<fn(usize) -> bool>,
+    /// Exact term at an index, if the series has a direct formula
+    nth: Option<fn(u64) -> BigUint>,
+    /// Per-term progress reporting, for series slow enough to need it
+    generate_with_progress: Option<ProgressGenerator>,
+}
+
+impl Sequence for FnSequence {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn generate(&self, count: usize) -> Vec<usize> {
+        (self.generate)(count)
+    }
+
+    fn generate_up_to(&self, max_value: usize) -> Vec<usize> {
+        (self.generate_up_to)(max_value)
+    }
+
+    fn contains_up_to(&self, n: usize, max_value: usize) -> Option<bool> {
+        self.is_member
+            .map(|is_member| n <= max_value && is_member(n))
+    }
+
+    fn nth(&self, index: u64) -> Option<BigUint> {
+        self.nth.map(|nth| nth(index))
+    }
+
+    fn generate_with_progress(
+        &self,
+        count: usize,
+        progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    ) -> Vec<usize> {
+        match self.generate_with_progress {
+            Some(generate) => generate(count, progress),
+            None => report_once(self.generate(count), progress),
+        }
+    }
+}
+
+/// Which terms of a series [`generate`] returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerateMode {
+    /// The first `count` terms, as [`Sequence::generate`].
+    Count(usize),
+    /// All terms `<= max_value`, as [`Sequence::generate_up_to`].
+    UpTo(usize),
+    /// The terms of `UpTo(end)` that `UpTo(start - 1)` leaves out, i.e. the
+    /// terms in `start..=end`. Indexed like `UpTo` for Collatz.
+    Range(usize, usize),
+}
+
+/// The terms of `series` selected by `mode`.
+///
+/// # Examples
+///
+/// ```
+/// use series::{generate, sequence::FIBONACCI, GenerateMode};
+///
+/// assert_eq!(generate(&FIBONACCI, GenerateMode::Count(5)), vec![0, 1, 1, 2, 3]);
+/// assert_eq!(generate(&FIBONACCI, GenerateMode::Range(5, 30)), vec![5, 8, 13, 21]);
+/// ```
+pub fn generate(series: &dyn Sequence, mode: GenerateMode) -> Vec<usize> {
+    match mode {
+        GenerateMode::Count(count) => series.generate(count),
+        GenerateMode::UpTo(max_value) => series.generate_up_to(max_value),
+        GenerateMode::Range(start, end) => {
+            if start > end {
+                return Vec::new();
+            }
+            // Terms come in sequence order, so those below start are a prefix
+            let below = match start.checked_sub(1) {
+                Some(max_below) => series.generate_up_to(max_below).len(),
+                None => 0,
+            };
+            let mut terms = series.generate_up_to(end);
+            terms.drain(..below.min(terms.len()));
+            terms
+        }
+    }
+}
+
+/// The term at `index` of `series`, exact and without generating the terms
+/// before it, or `None` when the series has no direct formula.
+///
+/// # Examples
+///
+/// ```
+/// use series::{nth, sequence::FIBONACCI};
+///
+/// assert_eq!(nth(&FIBONACCI, 100).unwrap().to_string(), "354224848179261915075");
+/// ```
+pub fn nth(series: &dyn Sequence, index: u64) -> Option<BigUint> {
+    series.nth(index)
+}
+
+// Closed forms for the polygonal series and powers of 2. Hexagonal,
+// centered hexagonal and pentagonal `generate` start at n = 1, so index i
+// is the (i + 1)th term.
+
+fn nth_triangular(index: u64) -> BigUint {
+    triangular::nth_triangular_big(&BigUint::from(index))
+}
+
+fn nth_power_of_2(index: u64) -> BigUint {
+    BigUint::one() << index
+}
+
+fn nth_hexagonal(index: u64) -> BigUint {
+    let n = BigUint::from(index) + 1u32;
+    &n * (&n * 2u32 - 1u32)
+}
+
+fn nth_centered_hexagonal(index: u64) -> BigUint {
+    let n = BigUint::from(index);
+    &n * (&n + 1u32) * 3u32 + 1u32
+}
+
+fn nth_pentagonal(index: u64) -> BigUint {
+    let n = BigUint::from(index) + 1u32;
+    &n * (&n * 3u32 - 1u32) / 2u32
+}
+
+pub static FIBONACCI: FnSequence = FnSequence {
+    name: "fibonacci",
+    generate: fibonacci::generate_fibonacci,
+    generate_up_to: fibonacci::generate_fibonacci_up_to,
+    is_member: Some(fibonacci::is_fibonacci),
+    nth: Some(fibonacci::nth_fibonacci),
+    generate_with_progress: None,
+};
+
+pub static LUCAS: FnSequence = FnSequence {
+    name: "lucas",
+    generate: lucas::generate_lucas,
+    generate_up_to: lucas::generate_lucas_up_to,
+    is_member: Some(lucas::is_lucas),
+    nth: Some(lucas::nth_lucas),
+    generate_with_progress: None,
+};
+
+pub static TRIANGULAR: FnSequence = FnSequence {
+    name: "triangular",
+    generate: triangular::generate_triangular,
+    generate_up_to: triangular::generate_triangular_up_to,
+    is_member: Some(triangular::is_triangular),
+    nth: Some(nth_triangular),
+    generate_with_progress: None,
+};
+
+pub static COLLATZ: FnSequence = FnSequence {
+    name: "collatz",
+    generate: collatz::generate_collatz_times,
+    generate_up_to: collatz::generate_collatz_times_up_to,
+    is_member: None,
+    nth: None,
+    generate_with_progress: Some(collatz::generate_collatz_times_with_progress),
+};
+
+pub static POWERS_OF_2: FnSequence = FnSequence {
+    name: "powers-of-2",
+    generate: powers::generate_powers_of_2,
+    generate_up_to: powers::generate_powers_of_2_up_to,
+    is_member: Some(powers::is_power_of_2),
+    nth: Some(nth_power_of_2),
+    generate_with_progress: None,
+};
+
+pub static CATALAN: FnSequence = FnSequence {
+    name: "catalan",
+    generate: catalan::generate_catalan,
+    generate_up_to: catalan::generate_catalan_up_to,
+    is_member: Some(catalan::is_catalan),
+    nth: Some(catalan::nth_catalan),
+    generate_with_progress: None,
+};
+
+pub static HEXAGONAL: FnSequence = FnSequence {
+    name: "hexagonal",
+    generate: hexagonal::generate_hexagonal,
+    generate_up_to: hexagonal::generate_hexagonal_up_to,
+    is_member: Some(hexagonal::is_hexagonal),
+    nth: Some(nth_hexagonal),
+    generate_with_progress: None,
+};
+
+pub static CENTERED_HEXAGONAL: FnSequence = FnSequence {
+    name: "centered-hexagonal",
+    generate: centered::generate_centered_hexagonal,
+    generate_up_to: centered::generate_centered_hexagonal_up_to,
+    is_member: Some(centered::is_centered_hexagonal),
+    nth: Some(nth_centered_hexagonal),
+    generate_with_progress: None,
+};
+
+pub static PENTAGONAL: FnSequence = FnSequence {
+    name: "pentagonal",
+    generate: pentagonal::generate_pentagonal,
+    generate_up_to: pentagonal::generate_pentagonal_up_to,
+    is_member: Some(pentagonal::is_pentagonal),
+    nth: Some(nth_pentagonal),
+    generate_with_progress: None,
+};
+
+pub static HAPPY: FnSequence = FnSequence {
+    name: "happy",
+    generate: happy::generate_happy,
+    generate_up_to: happy::generate_happy_up_to,
+    is_member: None,
+    nth: None,
+    generate_with_progress: Some(happy::generate_happy_with_progress),
+};
+
+pub static HARSHAD: FnSequence = FnSequence {
+    name: "harshad",
+    generate: harshad::generate_harshad,
+    generate_up_to: harshad::generate_harshad_up_to,
+    is_member: None,
+    nth: None,
+    generate_with_progress: None,
+};
+
+pub static PALINDROME: FnSequence = FnSequence {
+    name: "palindrome",
+    generate: palindrome::generate_palindromes,
+    generate_up_to: palindrome::generate_palindromes_up_to,
+    is_member: None,
+    nth: None,
+    generate_with_progress: None,
+};
+
+pub static EMIRP: FnSequence = FnSequence {
+    name: "emirp",
+    generate: emirp::generate_emirps,
+    generate_up_to: emirp::generate_emirps_up_to,
+    is_member: None,
+    nth: None,
+    generate_with_progress: None,
+};
+
+/// Every registered sequence.
+pub static SEQUENCES: &[&FnSequence] = &[
+    &FIBONACCI,
+    &LUCAS,
+    &TRIANGULAR,
+    &COLLATZ,
+    &POWERS_OF_2,
+    &CATALAN,
+    &HEXAGONAL,
+    &CENTERED_HEXAGONAL,
+    &PENTAGONAL,
+    &HAPPY,
+    &HARSHAD,
+    &PALINDROME,
+    &EMIRP,
+];
+
+/// Look up a sequence by its `name`.
+pub fn find_sequence(name: &str) -> Option<&'static dyn Sequence> {
+    SEQUENCES
+        .iter()
+        .find(|seq| seq.name == name)
+        .map(|&seq| seq as &'static dyn Sequence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_names_unique() {
+        for (i, a) in SEQUENCES.iter().enumerate() {
+            for b in &SEQUENCES[i + 1..] {
+                assert_ne!(a.name(), b.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_modes_fibonacci() {
+        assert_eq!(
+            generate(&FIBONACCI, GenerateMode::Count(8)),
+            vec![0, 1, 1, 2, 3, 5, 8, 13]
+        );
+        assert_eq!(generate(&FIBONACCI, GenerateMode::Count(0)), vec![]);
+        assert_eq!(
+            generate(&FIBONACCI, GenerateMode::UpTo(21)),
+            vec![0, 1, 1, 2, 3, 5, 8, 13, 21]
+        );
+        assert_eq!(
+            generate(&FIBONACCI, GenerateMode::Range(2, 21)),
+            vec![2, 3, 5, 8, 13, 21]
+        );
+        // Both 1s lie in the range, and start = 0 includes F₀
+        assert_eq!(
+            generate(&FIBONACCI, GenerateMode::Range(1, 4)),
+            vec![1, 1, 2, 3]
+        );
+        assert_eq!(
+            generate(&FIBONACCI, GenerateMode::Range(0, 1)),
+            vec![0, 1, 1]
+        );
+        assert_eq!(generate(&FIBONACCI, GenerateMode::Range(6, 7)), vec![]);
+        assert_eq!(generate(&FIBONACCI, GenerateMode::Range(10, 5)), vec![]);
+    }
+
+    #[test]
+    fn test_generate_range_is_suffix_of_up_to() {
+        for seq in SEQUENCES {
+            let up_to = generate(*seq, GenerateMode::UpTo(200));
+            let range = generate(*seq, GenerateMode::Range(50, 200));
+            assert!(up_to.ends_with(&range), "{}", seq.name());
+            if seq.name() != "collatz" {
+                assert!(
+                    range.iter().all(|v| (50..=200).contains(v)),
+                    "{}",
+                    seq.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_sequence() {
+        assert_eq!(
+            find_sequence("fibonacci").unwrap().generate(5),
+            vec![0, 1, 1, 2, 3]
+        );
+        assert_eq!(
+            find_sequence("happy").unwrap().generate_up_to(10),
+            vec![1, 7, 10]
+        );
+        assert!(find_sequence("nope").is_none());
+    }
+
+    #[test]
+    fn test_contains_up_to_matches_generated_set() {
+        for seq in SEQUENCES {
+            for max_value in [0, 1, 2, 10, 100, 1_000] {
+                let terms: std::collections::HashSet<usize> =
+                    seq.generate_up_to(max_value).into_iter().collect();
+                for n in 0..=max_value + 10 {
+                    if let Some(contained) = seq.contains_up_to(n, max_value) {
+                        assert_eq!(
+                            contained,
+                            terms.contains(&n),
+                            "{} n={} max={}",
+                            seq.name(),
+                            n,
+                            max_value
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_closed_form_series_have_membership() {
+        for seq in [
+            &FIBONACCI,
+            &LUCAS,
+            &TRIANGULAR,
+            &POWERS_OF_2,
+            &CATALAN,
+            &HEXAGONAL,
+            &CENTERED_HEXAGONAL,
+            &PENTAGONAL,
+        ] {
+            assert!(seq.contains_up_to(1, 1).is_some(), "{}", seq.name());
+        }
+        assert!(COLLATZ.contains_up_to(1, 1).is_none());
+    }
+
+    #[test]
+    fn test_nth_matches_generate() {
+        for seq in SEQUENCES {
+            let terms = seq.generate(30);
+            for (i, &term) in terms.iter().enumerate() {
+                if let Some(value) = nth(*seq, i as u64) {
+                    assert_eq!(value, BigUint::from(term), "{} index {}", seq.name(), i);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nth_coverage() {
+        for seq in [
+            &FIBONACCI,
+            &LUCAS,
+            &TRIANGULAR,
+            &POWERS_OF_2,
+            &CATALAN,
+            &HEXAGONAL,
+            &CENTERED_HEXAGONAL,
+            &PENTAGONAL,
+        ] {
+            assert!(seq.nth(0).is_some(), "{}", seq.name());
+        }
+        assert!(nth(&HAPPY, 0).is_none());
+        // Exact past usize
+        assert_eq!(nth(&POWERS_OF_2, 100).unwrap(), BigUint::one() << 100u32);
+        assert_eq!(
+            nth(&TRIANGULAR, u64::MAX).unwrap(),
+            BigUint::from(u64::MAX) * (BigUint::from(u64::MAX) + 1u32) / 2u32
+        );
+    }
+
+    #[test]
+    fn test_progress_sums_to_len() {
+        for seq in SEQUENCES {
+            let total = Arc::new(AtomicUsize::new(0));
+            let total_ref = Arc::clone(&total);
+            let values = seq.generate_with_progress(
+                20,
+                Some(Arc::new(move |delta: usize| {
+                    total_ref.fetch_add(delta, Ordering::Relaxed);
+                })),
+            );
+            assert_eq!(values, seq.generate(20), "{}", seq.name());
+            assert_eq!(
+                total.load(Ordering::Relaxed),
+                values.len(),
+                "{}",
+                seq.name()
+            );
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Stern's diatomic sequence (Stern–Brocot sequence)
+//!
+//! a(0)=0, a(1)=1, a(2n)=a(n), a(2n+1)=a(n)+a(n+1)
+//! Sequence: 0, 1, 1, 2, 1, 3, 2, 3, 1, 4, 3, 5, 2, 5, 3, 4, ...
+//!
+//! Consecutive terms a(n)/a(n+1) enumerate every positive rational exactly
+//! once, in lowest terms.
+
+pub fn generate_stern(count: usize) -> Vec<usize> {
+    let mut stern: Vec<usize> = Vec::with_capacity(count);
+
+    for n in 0..count {
+        let term = match n {
+            0 | 1 => n,
+            _ if n.is_multiple_of(2) => stern[n / 2],
+            _ => stern[n / 2] + stern[n / 2 + 1],
+        };
+        stern.push(term);
+    }
+
+    stern
+}
+
+/// The single term a(n), computed from the binary digits of n in O(log n)
+/// without generating the preceding terms.
+pub fn stern(n: usize) -> usize {
+    // Invariant: a(n) = a * a(m) + b * a(m + 1) as m walks n's bits downward
+    let (mut a, mut b) = (1usize, 0usize);
+    let mut m = n;
+
+    while m > 0 {
+        if m.is_multiple_of(2) {
+            a += b;
+        } else {
+            b += a;
+        }
+        m /= 2;
+    }
+
+    b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_small() {
+        assert_eq!(generate_stern(0), Vec::<usize>::new());
+        assert_eq!(generate_stern(1), vec![0]);
+        assert_eq!(generate_stern(10), vec![0, 1, 1, 2, 1, 3, 2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn test_single_term_matches_generated() {
+        for (n, &term) in generate_stern(1_000).iter().enumerate() {
+            assert_eq!(stern(n), term, "a({})", n);
+        }
+    }
+
+    #[test]
+    fn test_powers_of_2_are_one() {
+        for k in 0..usize::BITS {
+            assert_eq!(stern(1 << k), 1);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Thue–Morse sequence
+//!
+//! tₙ = parity of the number of 1 bits in n
+//! Sequence: 0, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0, ...
+//!
+//! Each block of 2ᵏ terms is followed by its complement, so the sequence is
+//! self-similar and contains no three identical consecutive blocks.
+
+pub fn generate_thue_morse(count: usize) -> Vec<u8> {
+    (0..count).map(|n| (n.count_ones() & 1) as u8).collect()
+}
+
+/// Whether `terms` matches the start of the Thue–Morse sequence.
+pub fn is_thue_morse_prefix(terms: &[u8]) -> bool {
+    terms
+        .iter()
+        .enumerate()
+        .all(|(n, &t)| t == (n.count_ones() & 1) as u8)
+}
+
+/// Indices n <= `max_value` where tₙ = 1 (the odious numbers: 1, 2, 4, 7,
+/// 8, 11, 13, 14, ...), for highlighting the sequence as a set.
+pub fn generate_thue_morse_positions(max_value: usize) -> Vec<usize> {
+    (0..=max_value)
+        .filter(|&n| n.count_ones() & 1 == 1)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_small() {
+        assert_eq!(generate_thue_morse(0), Vec::<u8>::new());
+        assert_eq!(
+            generate_thue_morse(16),
+            vec![0, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_blocks_are_followed_by_complement() {
+        let terms = generate_thue_morse(1 << 10);
+        for k in 0..10 {
+            let half = 1 << k;
+            for i in 0..half {
+                assert_eq!(terms[half + i], 1 - terms[i], "block {} index {}", k, i);
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_prefix() {
+        assert!(is_thue_morse_prefix(&[]));
+        assert!(is_thue_morse_prefix(&[0, 1, 1, 0, 1]));
+        assert!(is_thue_morse_prefix(&generate_thue_morse(100)));
+        assert!(!is_thue_morse_prefix(&[1]));
+        assert!(!is_thue_morse_prefix(&[0, 1, 1, 0, 0]));
+        assert!(!is_thue_morse_prefix(&[0, 1, 2]));
+    }
+
+    #[test]
+    fn test_positions() {
+        assert_eq!(generate_thue_morse_positions(0), Vec::<usize>::new());
+        assert_eq!(
+            generate_thue_morse_positions(14),
+            vec![1, 2, 4, 7, 8, 11, 13, 14]
+        );
+    }
+}
@@ -3,24 +3,48 @@
 //! Tₙ = n(n+1)/2
 //! Sequence: 0, 1, 3, 6, 10, 15, 21, 28, 36, 45, ...
 
+use num_bigint::BigUint;
+
 pub fn generate_triangular(count: usize) -> Vec<usize> {
     (0..count).map(|n| n * (n + 1) / 2).collect()
 }
 
+/// Tₙ computed exactly, for indices whose n(n+1) would overflow usize.
+pub fn nth_triangular_big(n: &BigUint) -> BigUint {
+    n * (n + 1u32) / 2u32
+}
+
+/// The first `count` triangular numbers as `BigUint`, matching
+/// [`generate_triangular`] term for term without overflowing.
+pub fn generate_triangular_big(count: usize) -> Vec<BigUint> {
+    let mut triangular = Vec::with_capacity(count);
+    let mut t = BigUint::ZERO;
+    for n in 0..count {
+        // Tₙ = Tₙ₋₁ + n
+        t += n;
+        triangular.push(t.clone());
+    }
+    triangular
+}
+
+/// Triangular numbers `<= max_value`, including `max_value` itself when it
+/// is triangular.
 pub fn generate_triangular_up_to(max_value: usize) -> Vec<usize> {
     if max_value == 0 {
         return vec![0];
     }
 
-    let mut triangular: Vec<usize> = Vec::new();
-    let mut n = 0;
+    let mut triangular: Vec<usize> = vec![0];
+    let mut t: usize = 0;
+    let mut n: usize = 1;
 
-    loop {
-        let t = n * (n + 1) / 2;
-        if t > max_value {
+    // Tₙ = Tₙ₋₁ + n; stop rather than wrap when Tₙ leaves usize
+    while let Some(next) = t.checked_add(n) {
+        if next > max_value {
             break;
         }
-        triangular.push(t);
+        triangular.push(next);
+        t = next;
         n += 1;
     }
 
@@ -28,26 +52,18 @@ pub fn generate_triangular_up_to(max_value: usize) -> Vec<usize> {
 }
 
 pub fn is_triangular(n: usize) -> bool {
-    if n == 0 {
-        return true;
-    }
-
-    let eight_n_plus_1 = n.saturating_mul(8).saturating_add(1);
-    if !is_perfect_square(eight_n_plus_1) {
-        return false;
-    }
-
-    let root = (eight_n_plus_1 as f64).sqrt() as usize;
-    (root - 1).is_multiple_of(2)
+    triangular_index(n).is_some()
 }
 
-fn is_perfect_square(n: usize) -> bool {
-    if n == 0 {
-        return true;
-    }
-
-    let root = (n as f64).sqrt() as usize;
-    root.saturating_mul(root) == n || root.saturating_add(1).saturating_mul(root + 1) == n
+/// The k with Tₖ = n, if n is triangular (T₀ = 0).
+pub fn triangular_index(n: usize) -> Option<usize> {
+    // Tₖ = n gives k = floor(sqrt(2n)). Work in u128 so 2n cannot overflow;
+    // the f64 root is within one of k, so check its neighbours exactly.
+    let n = n as u128;
+    let estimate = ((2 * n) as f64).sqrt() as u128;
+    (estimate.saturating_sub(1)..=estimate + 1)
+        .find(|&k| k * (k + 1) / 2 == n)
+        .map(|k| k as usize)
 }
 
 #[cfg(test)]
@@ -65,6 +81,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_nth_triangular_big() {
+        assert_eq!(
+            nth_triangular_big(&BigUint::from(1_000_000u32)),
+            BigUint::from(500_000_500_000u64)
+        );
+        assert_eq!(nth_triangular_big(&BigUint::ZERO), BigUint::ZERO);
+
+        // Past usize: T(2^64) = 2^63 (2^64 + 1)
+        let n = BigUint::from(1u32) << 64;
+        let expected = (BigUint::from(1u32) << 63) * (&n + 1u32);
+        assert_eq!(nth_triangular_big(&n), expected);
+    }
+
+    #[test]
+    fn test_generate_big_matches_usize() {
+        let big = generate_triangular_big(1_000);
+        let small: Vec<BigUint> = generate_triangular(1_000)
+            .into_iter()
+            .map(BigUint::from)
+            .collect();
+        assert_eq!(big, small);
+        assert!(generate_triangular_big(0).is_empty());
+        for (n, t) in big.iter().enumerate() {
+            assert_eq!(t, &nth_triangular_big(&BigUint::from(n)));
+        }
+    }
+
     #[test]
     fn test_generate_up_to() {
         assert_eq!(generate_triangular_up_to(0), vec![0]);
@@ -72,6 +116,38 @@ mod tests {
         assert_eq!(generate_triangular_up_to(20), vec![0, 1, 3, 6, 10, 15]);
     }
 
+    #[test]
+    fn test_triangular_index() {
+        assert_eq!(triangular_index(0), Some(0));
+        assert_eq!(triangular_index(1), Some(1));
+        assert_eq!(triangular_index(15), Some(5));
+        assert_eq!(triangular_index(14), None);
+        for (k, &t) in generate_triangular(1000).iter().enumerate() {
+            assert_eq!(triangular_index(t), Some(k));
+        }
+    }
+
+    #[test]
+    fn test_is_triangular_large() {
+        // Above usize::MAX / 8, where 8n + 1 no longer fits in usize
+        let k: usize = 6_000_000_000;
+        let t = k / 2 * (k + 1); // k is even, so this is exact
+        assert_eq!(triangular_index(t), Some(k));
+        assert!(!is_triangular(t - 1));
+        assert!(!is_triangular(t + 1));
+        assert!(!is_triangular(usize::MAX));
+    }
+
+    #[test]
+    fn test_generate_up_to_boundary() {
+        assert_eq!(generate_triangular_up_to(1), vec![0, 1]);
+        assert_eq!(generate_triangular_up_to(14), vec![0, 1, 3, 6, 10]);
+        assert_eq!(generate_triangular_up_to(15), vec![0, 1, 3, 6, 10, 15]);
+
+        let expected: Vec<usize> = (0..=10_000).filter(|&n| is_triangular(n)).collect();
+        assert_eq!(generate_triangular_up_to(10_000), expected);
+    }
+
     #[test]
     fn test_is_triangular() {
         for &n in &[0, 1, 3, 6, 10, 15, 21, 28, 36, 45, 55] {
@@ -0,0 +1,68 @@
+//! Integration tests for series_cli binary
+//!
+//! Run with: cargo test --test cli_integration
+
+use assert_cmd::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_member_exit_codes() {
+    let mut cmd = cargo_bin_cmd!("series_cli");
+    cmd.args(["member", "fib", "13"])
+        .assert()
+        .code(0)
+        .stdout("true\n");
+
+    let mut cmd = cargo_bin_cmd!("series_cli");
+    cmd.args(["member", "fib", "14"])
+        .assert()
+        .code(1)
+        .stdout("false\n");
+}
+
+#[test]
+fn test_generate_subcommand() {
+    let mut cmd = cargo_bin_cmd!("series_cli");
+    cmd.args(["generate", "pow2", "-c", "4", "-b", "2"])
+        .assert()
+        .success()
+        .stdout("1\n10\n100\n1000\n");
+}
+
+#[test]
+fn test_nth_subcommand_is_exact() {
+    let mut cmd = cargo_bin_cmd!("series_cli");
+    cmd.args(["nth", "fib", "100"])
+        .assert()
+        .success()
+        .stdout("354224848179261915075\n");
+}
+
+#[test]
+fn test_stats_subcommand() {
+    let mut cmd = cargo_bin_cmd!("series_cli");
+    cmd.args(["stats", "tri", "-c", "5"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("count: 5"))
+        .stdout(predicate::str::contains("sum: 20"));
+}
+
+#[test]
+fn test_legacy_flags_still_generate_with_warning() {
+    let mut cmd = cargo_bin_cmd!("series_cli");
+    cmd.args(["-c", "5", "-s", "lucas"])
+        .assert()
+        .success()
+        .stdout("2\n1\n3\n4\n7\n")
+        .stderr(predicate::str::contains("deprecated"));
+}
+
+#[test]
+fn test_no_arguments_generates_fibonacci_silently() {
+    let mut cmd = cargo_bin_cmd!("series_cli");
+    cmd.assert()
+        .success()
+        .stdout("0\n1\n1\n2\n3\n5\n8\n13\n21\n34\n")
+        .stderr("");
+}